@@ -39,6 +39,7 @@ fn main() {
                 matches.get_one::<String>("category").map(String::as_str),
                 matches.get_one::<String>("type").map(String::as_str),
                 matches.contains_id("msrv"),
+                matches.get_one::<String>("from-example").map(String::as_str),
             ) {
                 Ok(_) => update_lints::update(update_lints::UpdateMode::Change),
                 Err(e) => eprintln!("Unable to create lint: {}", e),
@@ -191,6 +192,11 @@ fn get_clap_config() -> ArgMatches {
                         .takes_value(true)
                         .required(false),
                     Arg::new("msrv").long("msrv").help("Add MSRV config code to the lint"),
+                    Arg::new("from-example")
+                        .long("from-example")
+                        .help("Generate the lint and its test from an example marked with `#[clippy::author]`")
+                        .takes_value(true)
+                        .conflicts_with("type"),
                 ]),
             Command::new("setup")
                 .about("Support for setting up your personal development environment")
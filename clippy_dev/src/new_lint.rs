@@ -5,6 +5,7 @@ use std::fs::{self, OpenOptions};
 use std::io::prelude::*;
 use std::io::{self, ErrorKind};
 use std::path::{Path, PathBuf};
+use std::process::Command;
 
 struct LintData<'a> {
     pass: &'a str,
@@ -30,17 +31,43 @@ impl<T> Context for io::Result<T> {
     }
 }
 
+/// An example given to `--from-example`, with the code generated for it by the `author` lint
+struct Example {
+    /// The example without its `#[clippy::author]` attributes
+    code: String,
+    matchers: Vec<Matcher>,
+}
+
+/// An `if_chain!` generated by the `author` lint
+struct Matcher {
+    /// The binding of the node the attribute is on: `expr`, `stmt` or `arm`
+    root: &'static str,
+    code: String,
+}
+
+/// The lint pass methods the matchers go in, by the binding of their root node
+const MATCHER_METHODS: [(&str, &str, &str); 3] = [
+    ("expr", "check_expr", "Expr"),
+    ("stmt", "check_stmt", "Stmt"),
+    ("arm", "check_arm", "Arm"),
+];
+
 /// Creates the files required to implement and test a new lint and runs `update_lints`.
 ///
+/// With `from_example`, the lint matches the code marked with `#[clippy::author]` in the example,
+/// which also becomes the test.
+///
 /// # Errors
 ///
-/// This function errors out if the files couldn't be created or written to.
+/// This function errors out if the files couldn't be created or written to, or if the `author`
+/// lint couldn't be run on the example.
 pub fn create(
     pass: Option<&String>,
     lint_name: Option<&String>,
     category: Option<&str>,
     mut ty: Option<&str>,
     msrv: bool,
+    from_example: Option<&str>,
 ) -> io::Result<()> {
     if category == Some("cargo") && ty.is_none() {
         // `cargo` is a special category, these lints should always be in `clippy_lints/src/cargo`
@@ -55,8 +82,19 @@ pub fn create(
         project_root: clippy_project_root(),
     };
 
-    create_lint(&lint, msrv).context("Unable to create lint implementation")?;
-    create_test(&lint).context("Unable to create a test for the new lint")?;
+    let example = match from_example {
+        Some(_) if lint.pass != "late" || lint.ty.is_some() => {
+            return Err(io::Error::new(
+                ErrorKind::Other,
+                "`--from-example` can only be used with `--pass late`, for lints of their own file",
+            ));
+        },
+        Some(path) => Some(read_example(Path::new(path), &lint).context("Unable to run the author lint")?),
+        None => None,
+    };
+
+    create_lint(&lint, msrv, example.as_ref()).context("Unable to create lint implementation")?;
+    create_test(&lint, example.as_ref()).context("Unable to create a test for the new lint")?;
 
     if lint.ty.is_none() {
         add_lint(&lint, msrv).context("Unable to add lint to clippy_lints/src/lib.rs")?;
@@ -65,11 +103,106 @@ pub fn create(
     Ok(())
 }
 
-fn create_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
+/// Runs the `author` lint on the example at `path`
+fn read_example(path: &Path, lint: &LintData<'_>) -> io::Result<Example> {
+    let example = fs::read_to_string(path).context(format!("reading {}", path.display()))?;
+    if !example.contains("clippy::author") {
+        return Err(io::Error::new(
+            ErrorKind::Other,
+            format!(
+                "{} has no code marked with `#[clippy::author]` to generate the lint from",
+                path.display()
+            ),
+        ));
+    }
+
+    // the same command as `cargo dev lint` on a file
+    let output = Command::new("cargo")
+        .args(["run", "--bin", "clippy-driver", "--"])
+        .args(["-L", "./target/debug"])
+        .args(["-Z", "no-codegen"])
+        .args(["--edition", "2021"])
+        .arg(fs::canonicalize(path)?)
+        .current_dir(&lint.project_root)
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::new(
+            ErrorKind::Other,
+            format!(
+                "clippy failed on {}:\n{}",
+                path.display(),
+                String::from_utf8_lossy(&output.stderr)
+            ),
+        ));
+    }
+
+    let matchers = parse_matchers(&String::from_utf8_lossy(&output.stdout), &lint.name.to_uppercase());
+    if matchers.is_empty() {
+        return Err(io::Error::new(
+            ErrorKind::Other,
+            format!("the author lint generated no code for {}", path.display()),
+        ));
+    }
+
+    Ok(Example {
+        code: strip_author_attributes(&example),
+        matchers,
+    })
+}
+
+/// Extracts the `if_chain!`s from the output of the `author` lint, and replaces their
+/// `// report your lint here` comment with a call to `span_lint`
+fn parse_matchers(output: &str, name_upper: &str) -> Vec<Matcher> {
+    let mut matchers = Vec::new();
+    let mut lines = output.lines();
+    while lines.any(|line| line == "if_chain! {") {
+        let body: Vec<&str> = lines.by_ref().take_while(|&line| line != "}").collect();
+        // the first condition is on the root node, e.g. `if let StmtKind::Local(local) = stmt.kind;`
+        let value = body
+            .first()
+            .and_then(|line| line.rsplit_once(" = "))
+            .map_or("", |(_, value)| value);
+        let root = MATCHER_METHODS
+            .iter()
+            .map(|&(root, ..)| root)
+            .find(|root| value.starts_with(&format!("{root}.")))
+            .unwrap_or("expr");
+
+        let mut code = String::from("if_chain! {\n");
+        for line in body {
+            if line.trim() == "// report your lint here" {
+                let _ = writeln!(
+                    code,
+                    "        span_lint(cx, {name_upper}, {root}.span, \"default lint message\");"
+                );
+            } else {
+                let _ = writeln!(code, "{line}");
+            }
+        }
+        code.push_str("}\n");
+        matchers.push(Matcher { root, code });
+    }
+    matchers
+}
+
+fn strip_author_attributes(example: &str) -> String {
+    example
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("#![clippy::author"))
+        .filter_map(|line| {
+            let stripped = line.replace("#[clippy::author] ", "").replace("#[clippy::author]", "");
+            // drop the lines that only had the attribute
+            (stripped.trim().is_empty() == line.trim().is_empty()).then_some(stripped + "\n")
+        })
+        .collect()
+}
+
+fn create_lint(lint: &LintData<'_>, enable_msrv: bool, example: Option<&Example>) -> io::Result<()> {
     if let Some(ty) = lint.ty {
         create_lint_for_ty(lint, enable_msrv, ty)
     } else {
-        let lint_contents = get_lint_file_contents(lint, enable_msrv);
+        let matchers = example.map_or(&[][..], |example| &example.matchers);
+        let lint_contents = get_lint_file_contents(lint, enable_msrv, matchers);
         let lint_path = format!("clippy_lints/src/{}.rs", lint.name);
         write_file(lint.project_root.join(&lint_path), lint_contents.as_bytes())?;
         println!("Generated lint file: `{}`", lint_path);
@@ -78,7 +211,7 @@ fn create_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
     }
 }
 
-fn create_test(lint: &LintData<'_>) -> io::Result<()> {
+fn create_test(lint: &LintData<'_>, example: Option<&Example>) -> io::Result<()> {
     fn create_project_layout<P: Into<PathBuf>>(lint_name: &str, location: P, case: &str, hint: &str) -> io::Result<()> {
         let mut path = location.into().join(case);
         fs::create_dir(&path)?;
@@ -103,7 +236,10 @@ fn create_test(lint: &LintData<'_>) -> io::Result<()> {
         println!("Generated test directories: `{relative_test_dir}/pass`, `{relative_test_dir}/fail`");
     } else {
         let test_path = format!("tests/ui/{}.rs", lint.name);
-        let test_contents = get_test_file_contents(lint.name, None);
+        let test_contents = match example {
+            Some(example) => get_example_test_contents(lint.name, &example.code),
+            None => get_test_file_contents(lint.name, None),
+        };
         write_file(lint.project_root.join(&test_path), test_contents)?;
 
         println!("Generated test file: `{}`", test_path);
@@ -203,6 +339,10 @@ fn get_test_file_contents(lint_name: &str, header_commands: Option<&str>) -> Str
     contents
 }
 
+fn get_example_test_contents(lint_name: &str, example: &str) -> String {
+    format!("#![allow(unused)]\n#![warn(clippy::{})]\n\n{}", lint_name, example)
+}
+
 fn get_manifest_contents(lint_name: &str, hint: &str) -> String {
     format!(
         indoc! {r#"
@@ -219,7 +359,7 @@ fn get_manifest_contents(lint_name: &str, hint: &str) -> String {
     )
 }
 
-fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
+fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool, matchers: &[Matcher]) -> String {
     let mut result = String::new();
 
     let (pass_type, pass_lifetimes, pass_import, context_import) = match lint.pass {
@@ -235,7 +375,16 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
     let name_camel = to_camel_case(lint.name);
     let name_upper = lint_name.to_uppercase();
 
-    result.push_str(&if enable_msrv {
+    let pass_impl = get_pass_impl(
+        pass_type,
+        pass_lifetimes,
+        context_import,
+        &name_camel,
+        enable_msrv,
+        matchers,
+    );
+
+    let imports = if enable_msrv {
         format!(
             indoc! {"
                 use clippy_utils::msrvs;
@@ -261,7 +410,19 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
             pass_type = pass_type,
             context_import = context_import
         )
-    });
+    };
+    if matchers.is_empty() {
+        result.push_str(&imports);
+    } else {
+        let mut imports: Vec<String> = imports
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .chain(get_matcher_imports(matchers))
+            .collect();
+        imports.sort();
+        let _ = writeln!(result, "{}\n", imports.join("\n"));
+    }
 
     let _ = write!(result, "{}", get_lint_declaration(&name_upper, category));
 
@@ -281,29 +442,22 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
 
                 impl_lint_pass!({name_camel} => [{name_upper}]);
 
-                impl {pass_type}{pass_lifetimes} for {name_camel} {{
-                    extract_msrv_attr!({context_import});
-                }}
-
+                {pass_impl}
                 // TODO: Add MSRV level to `clippy_utils/src/msrvs.rs` if needed.
                 // TODO: Add MSRV test to `tests/ui/min_rust_version_attr.rs`.
                 // TODO: Update msrv config comment in `clippy_lints/src/utils/conf.rs`
             "},
-            pass_type = pass_type,
-            pass_lifetimes = pass_lifetimes,
+            pass_impl = pass_impl,
             name_upper = name_upper,
             name_camel = name_camel,
-            context_import = context_import,
         )
     } else {
         format!(
             indoc! {"
                 declare_lint_pass!({name_camel} => [{name_upper}]);
 
-                impl {pass_type}{pass_lifetimes} for {name_camel} {{}}
-            "},
-            pass_type = pass_type,
-            pass_lifetimes = pass_lifetimes,
+                {pass_impl}"},
+            pass_impl = pass_impl,
             name_upper = name_upper,
             name_camel = name_camel,
         )
@@ -312,6 +466,89 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
     result
 }
 
+/// The implementation of the lint pass trait, with the `check_*` methods the matchers go in
+fn get_pass_impl(
+    pass_type: &str,
+    pass_lifetimes: &str,
+    context_import: &str,
+    name_camel: &str,
+    enable_msrv: bool,
+    matchers: &[Matcher],
+) -> String {
+    let mut pass_items = Vec::new();
+    if enable_msrv {
+        pass_items.push(format!("extract_msrv_attr!({context_import});\n"));
+    }
+    for (root, method, ty) in MATCHER_METHODS {
+        let mut body = String::new();
+        for matcher in matchers.iter().filter(|matcher| matcher.root == root) {
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            body.push_str(&indent(&matcher.code, 4));
+        }
+        if !body.is_empty() {
+            pass_items.push(format!(
+                "fn {method}(&mut self, cx: &LateContext<'tcx>, {root}: &'tcx {ty}<'_>) {{\n{body}}}\n"
+            ));
+        }
+    }
+    if pass_items.is_empty() {
+        format!("impl {pass_type}{pass_lifetimes} for {name_camel} {{}}\n")
+    } else {
+        // the matchers need to name the lifetime of the nodes they check
+        let (impl_lifetimes, pass_lifetimes) = if matchers.is_empty() {
+            ("", pass_lifetimes)
+        } else {
+            ("<'tcx>", "<'tcx>")
+        };
+        let items: Vec<String> = pass_items.iter().map(|item| indent(item, 4)).collect();
+        format!(
+            "impl{impl_lifetimes} {pass_type}{pass_lifetimes} for {name_camel} {{\n{}}}\n",
+            items.join("\n")
+        )
+    }
+}
+
+/// The imports of the items the matchers use, other than the `rustc_hir` ones
+fn get_matcher_imports(matchers: &[Matcher]) -> Vec<String> {
+    let uses = |name: &str| matchers.iter().any(|matcher| matcher.code.contains(name));
+
+    let mut imports = vec![
+        String::from("use clippy_utils::diagnostics::span_lint;"),
+        String::from("use if_chain::if_chain;"),
+    ];
+    if uses("higher::") {
+        imports.push(String::from("use clippy_utils::higher;"));
+    }
+    if uses("match_qpath(") {
+        imports.push(String::from("use clippy_utils::match_qpath;"));
+    }
+    let ast_items: Vec<&str> = ["FloatTy", "IntTy", "LitFloatType", "LitIntType", "LitKind", "UintTy"]
+        .into_iter()
+        .filter(|item| uses(&format!("{item}::")))
+        .collect();
+    match ast_items[..] {
+        [] => {},
+        [item] => imports.push(format!("use rustc_ast::ast::{item};")),
+        _ => imports.push(format!("use rustc_ast::ast::{{{}}};", ast_items.join(", "))),
+    }
+    imports
+}
+
+/// Indents the non empty lines of `code` by `width` spaces
+fn indent(code: &str, width: usize) -> String {
+    code.lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::from("\n")
+            } else {
+                format!("{:width$}{line}\n", "")
+            }
+        })
+        .collect()
+}
+
 fn get_lint_declaration(name_upper: &str, category: &str) -> String {
     format!(
         indoc! {r#"
@@ -574,3 +811,52 @@ fn test_camel_case() {
     let name4 = to_camel_case(name3);
     assert_eq!(name4, "LintName");
 }
+
+#[test]
+fn test_example() {
+    let example = indoc! {r#"
+        fn main() {
+            #[clippy::author]
+            let _ = ::std::cmp::min(3, 4);
+            let x = #[clippy::author] foo();
+        }
+    "#};
+    assert_eq!(
+        strip_author_attributes(example),
+        indoc! {"
+            fn main() {
+                let _ = ::std::cmp::min(3, 4);
+                let x = foo();
+            }
+        "}
+    );
+
+    let output = indoc! {"
+        if_chain! {
+            if let StmtKind::Local(local) = stmt.kind;
+            if let PatKind::Wild = local.pat.kind;
+            then {
+                // report your lint here
+            }
+        }
+        if_chain! {
+            if let ExprKind::Call(func, args) = expr.kind;
+            then {
+                // report your lint here
+            }
+        }
+    "};
+    let matchers = parse_matchers(output, "FOO");
+    assert_eq!(matchers.iter().map(|m| m.root).collect::<Vec<_>>(), ["stmt", "expr"]);
+    assert_eq!(
+        matchers[1].code,
+        indoc! {r#"
+            if_chain! {
+                if let ExprKind::Call(func, args) = expr.kind;
+                then {
+                    span_lint(cx, FOO, expr.span, "default lint message");
+                }
+            }
+        "#}
+    );
+}
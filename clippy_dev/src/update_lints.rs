@@ -124,6 +124,12 @@ fn generate_lint_files(
     let content = gen_lint_group_list("all", all_group_lints);
     process_file("clippy_lints/src/lib.register_all.rs", update_mode, &content);
 
+    process_file(
+        "clippy_lints/src/lib.pass_lints.rs",
+        update_mode,
+        &gen_pass_lints(&gather_lint_passes()),
+    );
+
     update_docs(update_mode, &usable_lints);
 
     for (lint_group, lints) in Lint::by_lint_group(usable_lints.into_iter().chain(internal_lints)) {
@@ -143,9 +149,25 @@ fn generate_lint_files(
 }
 
 fn update_docs(update_mode: UpdateMode, usable_lints: &[Lint]) {
+    let config_keys = gather_config_keys();
     replace_region_in_file(update_mode, Path::new("src/docs.rs"), "docs! {\n", "\n}\n", |res| {
-        for name in usable_lints.iter().map(|lint| lint.name.clone()).sorted() {
-            writeln!(res, r#"    "{name}","#).unwrap();
+        for lint in usable_lints.iter().sorted_by(|a, b| a.name.cmp(&b.name)) {
+            let keys = config_keys
+                .get(&lint.name)
+                .map(|keys| keys.iter().map(|key| format!("\"{key}\"")).join(", "))
+                .unwrap_or_default();
+            // the description keeps the escapes of its literal, but raw strings can contain quotes
+            let desc = lint.desc.replace("\\\"", "\"").replace('"', "\\\"");
+            writeln!(
+                res,
+                "    (\"{}\", {}, {}, [{}], \"{}\"),",
+                lint.name,
+                lint.group,
+                lint.version.as_deref().unwrap_or("\"Unknown\""),
+                keys,
+                desc
+            )
+            .unwrap();
         }
     });
 
@@ -186,6 +208,30 @@ fn update_docs(update_mode: UpdateMode, usable_lints: &[Lint]) {
     }
 }
 
+/// Returns the keys of the configuration file that configure each lint, read from the `Lint:` lines
+/// of the documentation of the configuration values.
+fn gather_config_keys() -> HashMap<String, Vec<String>> {
+    let path = Path::new("clippy_lints/src/utils/conf.rs");
+    let contents = fs::read_to_string(path).unwrap_or_else(|e| panic_file(e, path, "read"));
+    let mut config_keys = HashMap::<_, Vec<_>>::new();
+    let mut lints = Vec::new();
+    for line in contents.lines().map(str::trim) {
+        if let Some(names) = line.strip_prefix("/// Lint: ") {
+            lints = names
+                .trim_end_matches('.')
+                .split(',')
+                .map(|name| name.trim().to_lowercase())
+                .collect();
+        } else if let Some((key, _)) = line.strip_prefix('(').and_then(|field| field.split_once(':')) {
+            let key = key.replace('_', "-");
+            for lint in lints.drain(..) {
+                config_keys.entry(lint).or_default().push(key.clone());
+            }
+        }
+    }
+    config_keys
+}
+
 fn print_lint_names(header: &str, lints: &BTreeSet<String>) -> bool {
     if lints.is_empty() {
         return false;
@@ -648,6 +694,8 @@ struct Lint {
     module: String,
     declaration_range: Range<usize>,
     documentation: String,
+    /// The `#[clippy::version]` literal, with its quotes.
+    version: Option<String>,
 }
 
 impl Lint {
@@ -667,6 +715,7 @@ impl Lint {
             module: module.into(),
             declaration_range,
             documentation,
+            version: None,
         }
     }
 
@@ -764,6 +813,20 @@ fn gen_deprecated(lints: &[DeprecatedLint]) -> String {
     output
 }
 
+/// Generates the list of the lints of each lint pass, sorted by the name of the pass
+#[must_use]
+fn gen_pass_lints(passes: &[(String, Vec<String>)]) -> String {
+    let mut output = GENERATED_FILE_COMMENT.to_string();
+    output.push_str("&[\n");
+    for (pass, lints) in passes {
+        let lints = lints.iter().map(|lint| format!("\"{}\"", lint)).join(", ");
+        let _ = writeln!(output, "    (\"{}\", &[{}]),", pass, lints);
+    }
+    output.push_str("]\n");
+
+    output
+}
+
 /// Generates the code for registering lints
 #[must_use]
 fn gen_register_lint_list<'a>(
@@ -865,6 +928,21 @@ fn gather_all() -> (Vec<Lint>, Vec<DeprecatedLint>, Vec<RenamedLint>) {
     (lints, deprecated_lints, renamed_lints)
 }
 
+/// Gathers the lint passes declared in `clippy_lints/src` with the names of their lints, sorted by
+/// the name of the pass
+fn gather_lint_passes() -> Vec<(String, Vec<String>)> {
+    let mut passes = Vec::with_capacity(500);
+    for (_, file) in clippy_lints_src_files() {
+        let path = file.path();
+        let contents =
+            fs::read_to_string(path).unwrap_or_else(|e| panic!("Cannot read from `{}`: {}", path.display(), e));
+        parse_lint_passes(&contents, &mut passes);
+    }
+    passes.sort_unstable();
+    passes.dedup();
+    passes
+}
+
 fn clippy_lints_src_files() -> impl Iterator<Item = (PathBuf, DirEntry)> {
     let root_path = clippy_project_root().join("clippy_lints/src");
     let iter = WalkDir::new(&root_path).into_iter();
@@ -924,6 +1002,7 @@ fn parse_contents(contents: &str, module: &str, lints: &mut Vec<Lint>) {
         // matches `!{`
         match_tokens!(iter, Bang OpenBrace);
         let mut in_code = false;
+        let mut version = None;
         while let Some(t) = iter.next() {
             match t.token_kind {
                 TokenKind::LineComment { .. } => {
@@ -938,7 +1017,12 @@ fn parse_contents(contents: &str, module: &str, lints: &mut Vec<Lint>) {
                     }
                 },
                 TokenKind::Pound => {
-                    match_tokens!(iter, OpenBracket Ident Colon Colon Ident Eq Literal{..} CloseBracket Ident);
+                    let (literal,) = match_tokens!(
+                        iter,
+                        // `[clippy::version = "..."] pub`
+                        OpenBracket Ident Colon Colon Ident Eq Literal{..}(literal) CloseBracket Ident
+                    );
+                    version = Some(literal.to_string());
                     break;
                 },
                 TokenKind::Ident => {
@@ -965,11 +1049,70 @@ fn parse_contents(contents: &str, module: &str, lints: &mut Vec<Lint>) {
             ..
         }) = iter.next()
         {
-            lints.push(Lint::new(name, group, desc, module, start..range.end, docs));
+            let mut lint = Lint::new(name, group, desc, module, start..range.end, docs);
+            lint.version = version;
+            lints.push(lint);
         }
     }
 }
 
+/// Parse a source file looking for `declare_lint_pass` and `impl_lint_pass` macro invocations. The
+/// name of a pass is written as `LintPass::name` returns it.
+fn parse_lint_passes(contents: &str, passes: &mut Vec<(String, Vec<String>)>) {
+    let mut iter = tokenize(contents)
+        .scan(0usize, |offset, t| {
+            let content = &contents[*offset..*offset + t.len as usize];
+            *offset += t.len as usize;
+            Some((t.kind, content))
+        })
+        .filter(|(kind, _)| {
+            !matches!(
+                kind,
+                TokenKind::Whitespace | TokenKind::LineComment { .. } | TokenKind::BlockComment { .. }
+            )
+        });
+
+    while iter
+        .any(|(kind, content)| kind == TokenKind::Ident && matches!(content, "declare_lint_pass" | "impl_lint_pass"))
+    {
+        if !matches!(iter.next(), Some((TokenKind::Bang, _))) || !matches!(iter.next(), Some((TokenKind::OpenParen, _)))
+        {
+            continue;
+        }
+        // the attributes of `declare_lint_pass`, then the type up to `=>`
+        let mut name = String::new();
+        let mut in_attr = false;
+        while let Some((kind, content)) = iter.next() {
+            match kind {
+                TokenKind::Pound if name.is_empty() => in_attr = true,
+                TokenKind::CloseBracket if in_attr => in_attr = false,
+                TokenKind::Eq if !in_attr && matches!(iter.next(), Some((TokenKind::Gt, _))) => break,
+                _ if !in_attr => name.push_str(content),
+                _ => {},
+            }
+        }
+        // the lints, possibly written as paths
+        let mut lints = Vec::new();
+        let mut lint = "";
+        for (kind, content) in iter.by_ref().skip_while(|&(kind, _)| kind == TokenKind::OpenBracket) {
+            match kind {
+                TokenKind::Ident => lint = content,
+                TokenKind::Comma | TokenKind::CloseBracket => {
+                    if !lint.is_empty() {
+                        lints.push(lint.to_lowercase());
+                        lint = "";
+                    }
+                    if kind == TokenKind::CloseBracket {
+                        break;
+                    }
+                },
+                _ => {},
+            }
+        }
+        passes.push((name, lints));
+    }
+}
+
 /// Parse a source file looking for `declare_deprecated_lint` macro invocations.
 fn parse_deprecated_contents(contents: &str, lints: &mut Vec<DeprecatedLint>) {
     let mut offset = 0usize;
@@ -1044,10 +1187,17 @@ fn parse_renamed_contents(contents: &str, lints: &mut Vec<RenamedLint>) {
 
 /// Removes the line splices and surrounding quotes from a string literal
 fn remove_line_splices(s: &str) -> String {
+    // raw strings have no escapes to remove
+    if let Some(raw) = s.strip_prefix('r') {
+        let raw = raw
+            .trim_matches('#')
+            .strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'));
+        return raw
+            .unwrap_or_else(|| panic!("expected quoted string, found `{}`", s))
+            .to_string();
+    }
     let s = s
-        .strip_prefix('r')
-        .unwrap_or(s)
-        .trim_matches('#')
         .strip_prefix('"')
         .and_then(|s| s.strip_suffix('"'))
         .unwrap_or_else(|| panic!("expected quoted string, found `{}`", s));
@@ -1184,8 +1334,11 @@ mod tests {
         "#;
         let mut result = Vec::new();
         parse_contents(CONTENTS, "module_name", &mut result);
+        let versions: Vec<_> = result.iter().map(|r| r.version.as_deref()).collect();
+        assert_eq!(versions, [Some("\"Hello Clippy!\""), Some("\"Test version\"")]);
         for r in &mut result {
             r.declaration_range = Range::default();
+            r.version = None;
         }
 
         let expected = vec![
@@ -1234,6 +1387,37 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn test_parse_lint_passes() {
+        static PASSES_CONTENTS: &str = r#"
+            declare_lint_pass!(
+                /// some doc comment
+                #[derive(Copy, Clone)]
+                Attributes => [INLINE_ALWAYS, attrs::DEPRECATED_SEMVER]
+            );
+
+            impl_lint_pass!(Functions<'_> => [
+                TOO_MANY_ARGUMENTS,
+                TOO_MANY_LINES,
+            ]);
+        "#;
+
+        let mut result = Vec::new();
+        parse_lint_passes(PASSES_CONTENTS, &mut result);
+
+        let expected = vec![
+            (
+                "Attributes".to_string(),
+                vec!["inline_always".to_string(), "deprecated_semver".to_string()],
+            ),
+            (
+                "Functions<'_>".to_string(),
+                vec!["too_many_arguments".to_string(), "too_many_lines".to_string()],
+            ),
+        ];
+        assert_eq!(expected, result);
+    }
+
     #[test]
     fn test_usable_lints() {
         let lints = vec![
@@ -0,0 +1,278 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::macro_backtrace;
+use clippy_utils::str_utils::glob_match;
+use clippy_utils::{def_path_with_crate, used_def, DefUse};
+
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, HirId, Item, ItemKind, Path};
+use rustc_lint::{LateContext, LateLintPass, Lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{ExpnId, Span};
+
+use crate::utils::conf;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Denies the use of anything from the crates configured in clippy.toml.
+    ///
+    /// Note: Even though this lint is warn-by-default, it will only trigger if
+    /// crates are defined in the clippy.toml file.
+    ///
+    /// ### Why is this bad?
+    /// Some crates are undesirable in certain parts of a project, like an
+    /// async runtime in the crate holding the domain logic.
+    ///
+    /// ### Example
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-crates = [
+    ///     # Can use a string as the name of the disallowed crate.
+    ///     "tokio",
+    ///     # Can also use an inline table with a `path` key, and add a `reason`
+    ///     # for why the crate is disallowed. The name can be a glob pattern.
+    ///     { path = "reqwest*", reason = "the domain doesn't do any I/O" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// // Example code where clippy issues a warning
+    /// tokio::spawn(async { /* .. */ });
+    /// let client = reqwest::Client::new();
+    /// ```
+    #[clippy::version = "1.65.0"]
+    pub DISALLOWED_CRATES,
+    style,
+    "use of an item from a disallowed crate"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Denies the use of anything from the modules configured in clippy.toml,
+    /// except in the modules themselves. Items from other crates are matched
+    /// by the shortest path they are exported at, like `tokio::spawn` rather
+    /// than `tokio::task::spawn`.
+    ///
+    /// Note: Even though this lint is warn-by-default, it will only trigger if
+    /// modules are defined in the clippy.toml file.
+    ///
+    /// ### Why is this bad?
+    /// Some modules are undesirable in certain contexts, like the internals
+    /// of another layer of the project.
+    ///
+    /// ### Example
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-modules = [
+    ///     # Can use a string as the path of the disallowed module.
+    ///     "std::process",
+    ///     # Can also use an inline table with a `path` key, and add a `reason`
+    ///     # for why the module is disallowed. The path can be a glob pattern.
+    ///     { path = "my_crate::*::internal", reason = "use the public API" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// // Example code where clippy issues a warning
+    /// std::process::exit(1);
+    /// my_crate::storage::internal::flush();
+    /// ```
+    #[clippy::version = "1.65.0"]
+    pub DISALLOWED_MODULES,
+    style,
+    "use of an item from a disallowed module"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Denies the trait implementations configured in clippy.toml.
+    ///
+    /// Note: Even though this lint is warn-by-default, it will only trigger if
+    /// trait implementations are defined in the clippy.toml file.
+    ///
+    /// ### Why is this bad?
+    /// Some traits must not be implemented for some types, like serialization
+    /// traits for the types holding secrets.
+    ///
+    /// ### Example
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-trait-impls = [
+    ///     # The paths can be glob patterns, `**` matches any number of segments.
+    ///     { trait = "serde::Serialize", for = "my_crate::secrets::**", reason = "secrets must not be written" },
+    ///     # Without `for`, the trait must not be implemented at all.
+    ///     { trait = "std::ops::Deref" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// // Example code where clippy issues a warning, in the `secrets` module
+    /// #[derive(serde::Serialize)]
+    /// pub struct ApiKey(String);
+    /// ```
+    #[clippy::version = "1.65.0"]
+    pub DISALLOWED_TRAIT_IMPLS,
+    style,
+    "implementation of a disallowed trait"
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedDependencies {
+    conf_crates: Vec<conf::DisallowedCrate>,
+    conf_modules: Vec<conf::DisallowedModule>,
+    conf_trait_impls: Vec<conf::DisallowedTraitImpl>,
+    /// The expansions of macros already checked, so that a macro call is only linted once even
+    /// though all the paths it expands to are checked.
+    seen: FxHashSet<ExpnId>,
+}
+
+impl DisallowedDependencies {
+    pub fn new(
+        conf_crates: Vec<conf::DisallowedCrate>,
+        conf_modules: Vec<conf::DisallowedModule>,
+        conf_trait_impls: Vec<conf::DisallowedTraitImpl>,
+    ) -> Self {
+        Self {
+            conf_crates,
+            conf_modules,
+            conf_trait_impls,
+            seen: FxHashSet::default(),
+        }
+    }
+
+    fn check_def(&self, cx: &LateContext<'_>, def_id: DefId, hir_id: HirId, span: Span) {
+        if !def_id.is_local() {
+            let krate = cx.tcx.crate_name(def_id.krate);
+            if let Some(conf) = self
+                .conf_crates
+                .iter()
+                .find(|conf| glob_match(&conf.path().replace('-', "_"), krate.as_str(), "::"))
+            {
+                let msg = format!(
+                    "use of `{}` from the disallowed crate `{}`",
+                    def_path_with_crate(cx, def_id),
+                    krate
+                );
+                emit(cx, DISALLOWED_CRATES, span, &msg, conf.reason());
+                return;
+            }
+        }
+
+        if self.conf_modules.is_empty() {
+            return;
+        }
+        let path = def_path_with_crate(cx, def_id);
+        let segments: Vec<_> = path.split("::").collect();
+        // the module is the longest prefix of the path that is disallowed, it can be the path itself
+        // for a `use` of the module
+        let disallowed = (1..=segments.len()).rev().find_map(|len| {
+            let module = segments[..len].join("::");
+            let conf = self
+                .conf_modules
+                .iter()
+                .find(|conf| glob_match(conf.path(), &module, "::"))?;
+            Some((module, conf))
+        });
+        if let Some((module, conf)) = disallowed {
+            // the items of a module can use each other
+            let user = def_path_with_crate(cx, cx.tcx.parent_module(hir_id).to_def_id());
+            if user == module || user.starts_with(&format!("{}::", module)) {
+                return;
+            }
+            let msg = format!("use of `{}` from the disallowed module `{}`", path, module);
+            emit(cx, DISALLOWED_MODULES, span, &msg, conf.reason());
+        }
+    }
+
+    /// Checks the macros `span` was expanded from, since the paths in a macro call aren't resolved
+    /// in the HIR.
+    fn check_macro_calls(&mut self, cx: &LateContext<'_>, hir_id: HirId, span: Span) {
+        for mac in macro_backtrace(span) {
+            if self.seen.insert(mac.expn) {
+                self.check_def(cx, mac.def_id, hir_id, mac.span.source_callsite());
+            }
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedDependencies => [DISALLOWED_CRATES, DISALLOWED_MODULES, DISALLOWED_TRAIT_IMPLS]);
+
+impl<'tcx> LateLintPass<'tcx> for DisallowedDependencies {
+    fn check_path(&mut self, cx: &LateContext<'tcx>, path: &'tcx Path<'tcx>, hir_id: HirId) {
+        if self.conf_crates.is_empty() && self.conf_modules.is_empty() {
+            return;
+        }
+        if path.span.from_expansion() {
+            self.check_macro_calls(cx, hir_id, path.span);
+        }
+        if let Some((def_id, span)) = used_def(cx, DefUse::Path(path)) {
+            self.check_def(cx, def_id, hir_id, span);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if self.conf_crates.is_empty() && self.conf_modules.is_empty() {
+            return;
+        }
+        if let Some((def_id, span)) = used_def(cx, DefUse::MethodCall(expr)) {
+            self.check_def(cx, def_id, expr.hir_id, span);
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if self.conf_trait_impls.is_empty() {
+            return;
+        }
+        let ItemKind::Impl(imp) = &item.kind else {
+            return;
+        };
+        let Some(trait_ref) = &imp.of_trait else {
+            return;
+        };
+        let Some(trait_id) = trait_ref.trait_def_id() else {
+            return;
+        };
+        let trait_path = def_path_with_crate(cx, trait_id);
+        let self_ty = cx
+            .tcx
+            .type_of(item.def_id)
+            .ty_adt_def()
+            .map(|adt| def_path_with_crate(cx, adt.did()));
+        let disallowed = self.conf_trait_impls.iter().find(|conf| {
+            glob_match(&conf.trait_path, &trait_path, "::")
+                && conf.self_ty.as_ref().map_or(true, |pattern| {
+                    self_ty
+                        .as_ref()
+                        .map_or(false, |self_ty| glob_match(pattern, self_ty, "::"))
+                })
+        });
+        if let Some(conf) = disallowed {
+            let msg = match &self_ty {
+                Some(self_ty) => format!(
+                    "implementation of the disallowed trait `{}` for `{}`",
+                    trait_path, self_ty
+                ),
+                None => format!("implementation of the disallowed trait `{}`", trait_path),
+            };
+            // for a derive, this is the path of the derive macro
+            emit(
+                cx,
+                DISALLOWED_TRAIT_IMPLS,
+                trait_ref.path.span.source_callsite(),
+                &msg,
+                conf.reason.as_deref(),
+            );
+        }
+    }
+}
+
+fn emit(cx: &LateContext<'_>, lint: &'static Lint, span: Span, msg: &str, reason: Option<&str>) {
+    span_lint_and_then(cx, lint, span, msg, |diag| {
+        if let Some(reason) = reason {
+            diag.note(&format!("{} (from clippy.toml)", reason));
+        }
+    });
+}
@@ -0,0 +1,148 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::macro_backtrace;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefIdMap;
+use rustc_hir::{Expr, ForeignItem, ImplItem, Item, Pat, Stmt, TraitItem, Ty};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{ExpnId, Span};
+
+use crate::utils::conf;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Denies the configured macros in clippy.toml
+    ///
+    /// Note: Even though this lint is warn-by-default, it will only trigger if
+    /// macros are defined in the clippy.toml file.
+    ///
+    /// ### Why is this bad?
+    /// Some macros are undesirable in certain contexts, and it's beneficial to
+    /// lint for them as needed.
+    ///
+    /// When a disallowed macro is called by another macro, the lint is emitted
+    /// at the call site of the outermost macro, in the code being linted.
+    ///
+    /// ### Example
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-macros = [
+    ///     # Can use a string as the path of the disallowed macro.
+    ///     "std::print",
+    ///     # Can also use an inline table with a `path` key.
+    ///     { path = "std::println" },
+    ///     # When using an inline table, can add a `reason` for why the macro
+    ///     # is disallowed.
+    ///     { path = "log::trace", reason = "too verbose, use `log::debug!` instead" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// // Example code where clippy issues a warning
+    /// println!("connected"); // `std::println` is disallowed in the config.
+    ///
+    /// log::trace!("connected"); // `log::trace` is disallowed in the config.
+    /// // The diagnostic contains the message "too verbose, use `log::debug!` instead".
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// // Example code which does not raise clippy warning
+    /// log::debug!("connected"); // `log::debug` is _not_ disallowed in the config.
+    /// ```
+    #[clippy::version = "1.65.0"]
+    pub DISALLOWED_MACROS,
+    style,
+    "use of a disallowed macro"
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedMacros {
+    conf_disallowed: Vec<conf::DisallowedMacro>,
+    disallowed: DefIdMap<usize>,
+    /// The expansions of disallowed macros already linted, so that a macro call is only linted
+    /// once even though several of the nodes it expands to are checked.
+    seen: FxHashSet<ExpnId>,
+}
+
+impl DisallowedMacros {
+    pub fn new(conf_disallowed: Vec<conf::DisallowedMacro>) -> Self {
+        Self {
+            conf_disallowed,
+            disallowed: DefIdMap::default(),
+            seen: FxHashSet::default(),
+        }
+    }
+
+    fn check(&mut self, cx: &LateContext<'_>, span: Span) {
+        if self.disallowed.is_empty() {
+            return;
+        }
+        for mac in macro_backtrace(span) {
+            let Some(&index) = self.disallowed.get(&mac.def_id) else {
+                continue;
+            };
+            if !self.seen.insert(mac.expn) {
+                continue;
+            }
+            let conf = &self.conf_disallowed[index];
+            let msg = format!("use of a disallowed macro `{}`", conf.path());
+            // a macro called by another macro is reported where the outermost one is called
+            span_lint_and_then(cx, DISALLOWED_MACROS, mac.span.source_callsite(), &msg, |diag| {
+                if let conf::DisallowedMacro::WithReason {
+                    reason: Some(reason), ..
+                } = conf
+                {
+                    diag.note(&format!("{} (from clippy.toml)", reason));
+                }
+            });
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedMacros => [DISALLOWED_MACROS]);
+
+impl<'tcx> LateLintPass<'tcx> for DisallowedMacros {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for (index, conf) in self.conf_disallowed.iter().enumerate() {
+            let segs: Vec<_> = conf.path().split("::").collect();
+            if let Res::Def(DefKind::Macro(_), id) = clippy_utils::def_path_res(cx, &segs) {
+                self.disallowed.insert(id, index);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        self.check(cx, expr.span);
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        self.check(cx, stmt.span);
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'tcx>, ty: &'tcx Ty<'_>) {
+        self.check(cx, ty.span);
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
+        self.check(cx, pat.span);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        self.check(cx, item.span);
+    }
+
+    fn check_foreign_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ForeignItem<'_>) {
+        self.check(cx, item.span);
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        self.check(cx, item.span);
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        self.check(cx, item.span);
+    }
+}
@@ -1,8 +1,14 @@
+use clippy_utils::consts::{constant, Constant, FullInt};
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::str_utils::wildcard_match;
 use clippy_utils::{fn_def_id, get_parent_expr, path_def_id};
 
-use rustc_hir::{def::Res, def_id::DefIdMap, Expr, ExprKind};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, DefIdMap};
+use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::DefIdTree;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 
 use crate::utils::conf;
@@ -30,6 +36,14 @@ declare_clippy_lint! {
     ///     # When using an inline table, can add a `reason` for why the method
     ///     # is disallowed.
     ///     { path = "std::vec::Vec::leak", reason = "no leaking memory" },
+    ///     # Can disallow a method only when some of its arguments, counting
+    ///     # the receiver of a method as the first one, are literals or
+    ///     # constants equal to a `value`, or name the constant or enum variant
+    ///     # at a `path`. A string value can contain `*` and `?` wildcards.
+    ///     { path = "std::process::Command::new", args = [{ index = 0, value = "sh" }] },
+    ///     { path = "std::sync::atomic::AtomicBool::compare_exchange", args = [
+    ///         { index = 3, path = "std::sync::atomic::Ordering::Relaxed" },
+    ///     ] },
     /// ]
     /// ```
     ///
@@ -42,6 +56,8 @@ declare_clippy_lint! {
     /// let _now = Instant::now(); // Instant::now is disallowed in the config.
     ///
     /// let _box = Box::new(3); // Box::new is disallowed in the config.
+    ///
+    /// let _shell = Command::new("sh"); // Command::new("sh") is disallowed in the config.
     /// ```
     ///
     /// Use instead:
@@ -49,6 +65,7 @@ declare_clippy_lint! {
     /// // Example code which does not raise clippy warning
     /// let mut xs = Vec::new(); // Vec::new is _not_ disallowed in the config.
     /// xs.push(123); // Vec::push is _not_ disallowed in the config.
+    /// let _git = Command::new("git"); // Command::new("git") is _not_ disallowed in the config.
     /// ```
     #[clippy::version = "1.49.0"]
     pub DISALLOWED_METHODS,
@@ -59,7 +76,11 @@ declare_clippy_lint! {
 #[derive(Clone, Debug)]
 pub struct DisallowedMethods {
     conf_disallowed: Vec<conf::DisallowedMethod>,
-    disallowed: DefIdMap<usize>,
+    /// The indices of the entries of each method, which can be disallowed by several entries with
+    /// different `args`.
+    disallowed: DefIdMap<Vec<usize>>,
+    /// The resolved paths of the `args`.
+    arg_paths: FxHashMap<String, DefId>,
 }
 
 impl DisallowedMethods {
@@ -67,8 +88,33 @@ impl DisallowedMethods {
         Self {
             conf_disallowed,
             disallowed: DefIdMap::default(),
+            arg_paths: FxHashMap::default(),
         }
     }
+
+    /// Returns whether the `args` of `conf` match the arguments of the call `expr`.
+    fn args_match(&self, cx: &LateContext<'_>, conf: &conf::DisallowedMethod, expr: &Expr<'_>) -> bool {
+        if conf.args().is_empty() {
+            return true;
+        }
+        let args: Vec<_> = match expr.kind {
+            ExprKind::Call(_, args) => args.iter().collect(),
+            ExprKind::MethodCall(_, receiver, args, _) => std::iter::once(receiver).chain(args).collect(),
+            // a function used as a value could be called with any argument
+            _ => return false,
+        };
+        conf.args().iter().all(|conf_arg| {
+            let Some(arg) = args.get(conf_arg.index()) else {
+                return false;
+            };
+            match conf_arg {
+                conf::DisallowedArg::Value { value, .. } => value_matches(cx, arg, value),
+                conf::DisallowedArg::Path { path, .. } => self.arg_paths.get(path).map_or(false, |&id| {
+                    path_def_id(cx, *arg).map(|arg_id| variant_def_id(cx, arg_id)) == Some(id)
+                }),
+            }
+        })
+    }
 }
 
 impl_lint_pass!(DisallowedMethods => [DISALLOWED_METHODS]);
@@ -78,7 +124,15 @@ impl<'tcx> LateLintPass<'tcx> for DisallowedMethods {
         for (index, conf) in self.conf_disallowed.iter().enumerate() {
             let segs: Vec<_> = conf.path().split("::").collect();
             if let Res::Def(_, id) = clippy_utils::def_path_res(cx, &segs) {
-                self.disallowed.insert(id, index);
+                self.disallowed.entry(id).or_default().push(index);
+            }
+            for arg in conf.args() {
+                if let conf::DisallowedArg::Path { path, .. } = arg {
+                    let segs: Vec<_> = path.split("::").collect();
+                    if let Res::Def(_, id) = clippy_utils::def_path_res(cx, &segs) {
+                        self.arg_paths.insert(path.clone(), variant_def_id(cx, id));
+                    }
+                }
             }
         }
     }
@@ -96,8 +150,13 @@ impl<'tcx> LateLintPass<'tcx> for DisallowedMethods {
             Some(def_id) => def_id,
             None => return,
         };
-        let conf = match self.disallowed.get(&def_id) {
-            Some(&index) => &self.conf_disallowed[index],
+        let conf = match self.disallowed.get(&def_id).and_then(|indices| {
+            indices
+                .iter()
+                .map(|&index| &self.conf_disallowed[index])
+                .find(|conf| self.args_match(cx, conf, expr))
+        }) {
+            Some(conf) => conf,
             None => return,
         };
         let msg = format!("use of a disallowed method `{}`", conf.path());
@@ -111,3 +170,33 @@ impl<'tcx> LateLintPass<'tcx> for DisallowedMethods {
         });
     }
 }
+
+/// Returns the `DefId` of the variant of a constructor, which is the `DefId` the path of a unit
+/// variant resolves to in the configuration.
+fn variant_def_id(cx: &LateContext<'_>, def_id: DefId) -> DefId {
+    if let DefKind::Ctor(..) = cx.tcx.def_kind(def_id) {
+        cx.tcx.parent(def_id)
+    } else {
+        def_id
+    }
+}
+
+fn value_matches(cx: &LateContext<'_>, arg: &Expr<'_>, value: &conf::ArgValue) -> bool {
+    let typeck_results = cx.typeck_results();
+    let Some((mut constant, _)) = constant(cx, typeck_results, arg) else {
+        return false;
+    };
+    while let Constant::Ref(inner) = constant {
+        constant = *inner;
+    }
+    match (value, &constant) {
+        (conf::ArgValue::Bool(value), Constant::Bool(constant)) => value == constant,
+        (conf::ArgValue::Int(value), Constant::Int(_)) => {
+            let ty = typeck_results.expr_ty(arg).peel_refs();
+            constant.int_value(cx, ty) == Some(FullInt::S(i128::from(*value)))
+        },
+        (conf::ArgValue::Str(pattern), Constant::Str(constant)) => wildcard_match(pattern, constant),
+        (conf::ArgValue::Str(pattern), Constant::Char(constant)) => wildcard_match(pattern, &constant.to_string()),
+        _ => false,
+    }
+}
@@ -0,0 +1,143 @@
+use clippy_utils::diagnostics::span_lint;
+use clippy_utils::{in_constant, meets_msrv};
+use rustc_attr::StabilityLevel;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, HirId, ItemKind, Node, Path, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_semver::RustcVersion;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for uses of items of the standard library that were stabilized in a Rust version
+    /// newer than the minimum supported Rust version (MSRV) of the crate, and for uses in a
+    /// constant context of functions that became `const` in a newer version.
+    ///
+    /// The MSRV is read from the `msrv` configuration value, the `#![clippy::msrv]` attribute or
+    /// the `rust-version` field of `Cargo.toml`. The lint does nothing if no MSRV is set.
+    ///
+    /// ### Why is this bad?
+    /// The crate doesn't compile with the Rust versions it claims to support.
+    ///
+    /// ### Known problems
+    /// Code that is only compiled with newer Rust versions, for example with the `rustversion`
+    /// crate or a `cfg` set by a build script, is linted as well.
+    ///
+    /// ### Example
+    /// ```rust
+    /// #![feature(custom_inner_attributes)]
+    /// #![clippy::msrv = "1.50"]
+    ///
+    /// fn distance(a: u32, b: u32) -> u32 {
+    ///     a.abs_diff(b) // stable since 1.60.0
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #![feature(custom_inner_attributes)]
+    /// #![clippy::msrv = "1.50"]
+    ///
+    /// fn distance(a: u32, b: u32) -> u32 {
+    ///     a.max(b) - a.min(b)
+    /// }
+    /// ```
+    #[clippy::version = "1.65.0"]
+    pub INCOMPATIBLE_MSRV,
+    pedantic,
+    "using standard library items that are newer than the minimum supported Rust version"
+}
+
+pub struct IncompatibleMsrv {
+    msrv: Option<RustcVersion>,
+}
+
+impl IncompatibleMsrv {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+
+    fn check_item_use(&self, cx: &LateContext<'_>, def_id: DefId, hir_id: HirId, span: Span) {
+        let Some(msrv) = self.msrv else { return };
+        if (span.from_expansion() && in_external_macro(cx.sess(), span))
+            || !matches!(cx.tcx.crate_name(def_id.krate), sym::std | sym::core | sym::alloc)
+        {
+            return;
+        }
+
+        if let Some(since) = cx.tcx.lookup_stability(def_id).and_then(|stab| stable_since(stab.level))
+            && !meets_msrv(Some(msrv), since)
+        {
+            span_lint(
+                cx,
+                INCOMPATIBLE_MSRV,
+                span,
+                &format!(
+                    "current MSRV (minimum supported Rust version) is `{}` but this item is stable since `{}`",
+                    msrv, since
+                ),
+            );
+        } else if matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            && in_constant(cx, hir_id)
+            && let Some(since) = cx.tcx.lookup_const_stability(def_id).and_then(|stab| stable_since(stab.level))
+            && !meets_msrv(Some(msrv), since)
+        {
+            span_lint(
+                cx,
+                INCOMPATIBLE_MSRV,
+                span,
+                &format!(
+                    "current MSRV (minimum supported Rust version) is `{}` but this function is stable as a `const fn` since `{}`",
+                    msrv, since
+                ),
+            );
+        }
+    }
+}
+
+impl_lint_pass!(IncompatibleMsrv => [INCOMPATIBLE_MSRV]);
+
+impl<'tcx> LateLintPass<'tcx> for IncompatibleMsrv {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // resolved paths are checked by `check_path`
+        match expr.kind {
+            ExprKind::MethodCall(segment, ..) => {
+                if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                    self.check_item_use(cx, def_id, expr.hir_id, segment.ident.span);
+                }
+            },
+            ExprKind::Path(ref qpath @ QPath::TypeRelative(_, segment)) => {
+                if let Res::Def(_, def_id) = cx.qpath_res(qpath, expr.hir_id) {
+                    self.check_item_use(cx, def_id, expr.hir_id, segment.ident.span);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'tcx>, path: &'tcx Path<'tcx>, hir_id: HirId) {
+        // the uses of the imported items are checked instead
+        if let Some(Node::Item(item)) = cx.tcx.hir().find(hir_id)
+            && let ItemKind::Use(..) = item.kind
+        {
+            return;
+        }
+        if let Res::Def(_, def_id) = path.res {
+            self.check_item_use(cx, def_id, hir_id, path.span);
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Returns the version an item was stabilized in, if it is stable.
+fn stable_since(level: StabilityLevel) -> Option<RustcVersion> {
+    match level {
+        // `since` can also be a placeholder for the version being developed
+        StabilityLevel::Stable { since, .. } => RustcVersion::parse(since.as_str()).ok(),
+        StabilityLevel::Unstable { .. } => None,
+    }
+}
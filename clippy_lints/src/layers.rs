@@ -0,0 +1,146 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::str_utils::glob_match;
+use clippy_utils::{def_path_with_crate, used_def, DefUse};
+
+use rustc_hir::def_id::{DefId, DefIdMap};
+use rustc_hir::{Expr, HirId, Path};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+use crate::utils::conf;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the uses of an item from a layer above the one using it,
+    /// with the layers of the project configured in clippy.toml.
+    ///
+    /// The layers are listed from the top one to the bottom one, each with
+    /// the paths of its crates and modules. The layer of an item is the one
+    /// of the longest path matching its path, items from other crates are
+    /// matched by the shortest path they are exported at.
+    ///
+    /// Note: Even though this lint is warn-by-default, it will only trigger if
+    /// layers are defined in the clippy.toml file.
+    ///
+    /// ### Why is this bad?
+    /// A layer using the layers above it ties the lower parts of the project
+    /// to the higher ones, which defeats the purpose of the layering.
+    ///
+    /// ### Example
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// [[layers]]
+    /// name = "api"
+    /// paths = ["my_api", "my_crate::api"]
+    ///
+    /// [[layers]]
+    /// name = "service"
+    /// paths = ["my_crate::service"]
+    ///
+    /// [[layers]]
+    /// name = "storage"
+    /// # The paths can be glob patterns, `**` matches any number of segments.
+    /// paths = ["my_crate::**::storage", "my_storage_*"]
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// // Example code where clippy issues a warning, in `my_crate::storage`
+    /// fn save(request: &crate::api::Request) { /* .. */ }
+    /// ```
+    #[clippy::version = "1.65.0"]
+    pub LAYER_VIOLATION,
+    style,
+    "use of an item from a layer above the one using it"
+}
+
+#[derive(Clone, Debug)]
+pub struct Layers {
+    layers: Vec<conf::Layer>,
+    /// The layer of each item checked, if any.
+    cache: DefIdMap<Option<usize>>,
+}
+
+impl Layers {
+    pub fn new(layers: Vec<conf::Layer>) -> Self {
+        Self {
+            layers,
+            cache: DefIdMap::default(),
+        }
+    }
+
+    fn layer_of(&mut self, cx: &LateContext<'_>, def_id: DefId) -> Option<usize> {
+        if let Some(&layer) = self.cache.get(&def_id) {
+            return layer;
+        }
+        let layer = self.layer_of_path(&def_path_with_crate(cx, def_id));
+        self.cache.insert(def_id, layer);
+        layer
+    }
+
+    /// Returns the layer of the longest prefix of `path` matching the paths of a layer, the first
+    /// layer matching it if there are several.
+    fn layer_of_path(&self, path: &str) -> Option<usize> {
+        let segments: Vec<_> = path.split("::").collect();
+        (1..=segments.len()).rev().find_map(|len| {
+            let prefix = segments[..len].join("::");
+            self.layers
+                .iter()
+                .position(|layer| layer.paths.iter().any(|pattern| glob_match(pattern, &prefix, "::")))
+        })
+    }
+
+    fn check_def(&mut self, cx: &LateContext<'_>, def_id: DefId, hir_id: HirId, span: Span) {
+        let Some(item_layer) = self.layer_of(cx, def_id) else {
+            return;
+        };
+        let module = cx.tcx.parent_module(hir_id).to_def_id();
+        let Some(module_layer) = self.layer_of(cx, module) else {
+            return;
+        };
+        if item_layer >= module_layer {
+            return;
+        }
+
+        let item = def_path_with_crate(cx, def_id);
+        let msg = format!(
+            "use of `{}` from the `{}` layer in the `{}` layer",
+            item, self.layers[item_layer].name, self.layers[module_layer].name
+        );
+        span_lint_and_then(cx, LAYER_VIOLATION, span, &msg, |diag| {
+            diag.note(&format!(
+                "the dependency `{}` -> `{}` goes from the `{}` layer up to the `{}` layer",
+                def_path_with_crate(cx, module),
+                item,
+                self.layers[module_layer].name,
+                self.layers[item_layer].name
+            ));
+            let order: Vec<_> = self.layers.iter().map(|layer| format!("`{}`", layer.name)).collect();
+            diag.help(&format!(
+                "a layer can only use the layers below it: {} (from clippy.toml)",
+                order.join(" -> ")
+            ));
+        });
+    }
+}
+
+impl_lint_pass!(Layers => [LAYER_VIOLATION]);
+
+impl<'tcx> LateLintPass<'tcx> for Layers {
+    fn check_path(&mut self, cx: &LateContext<'tcx>, path: &'tcx Path<'tcx>, hir_id: HirId) {
+        if !self.layers.is_empty()
+            && let Some((def_id, span)) = used_def(cx, DefUse::Path(path))
+        {
+            self.check_def(cx, def_id, hir_id, span);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !self.layers.is_empty()
+            && let Some((def_id, span)) = used_def(cx, DefUse::MethodCall(expr))
+        {
+            self.check_def(cx, def_id, expr.hir_id, span);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+// This file was generated by `cargo dev update_lints`.
+// Use that command to update this file and do not edit by hand.
+// Manual edits will be overwritten.
+
+&[
+    ("AlmostCompleteLetterRange", &["almost_complete_letter_range"]),
+    ("ApproxConstant", &["approx_constant"]),
+    ("ArithmeticSideEffects", &["arithmetic_side_effects"]),
+    ("AsConversions", &["as_conversions"]),
+    ("AssertionsOnConstants", &["assertions_on_constants"]),
+    ("AssertionsOnResultStates", &["assertions_on_result_states"]),
+    ("AsyncYieldsAsync", &["async_yields_async"]),
+    ("Attributes", &["allow_attributes_without_reason", "inline_always", "deprecated_semver", "useless_attribute", "blanket_clippy_restriction_lints"]),
+    ("Author", &["lint_author"]),
+    ("AwaitHolding", &["await_holding_lock", "await_holding_refcell_ref", "await_holding_invalid_type"]),
+    ("BlocksInIfConditions", &["blocks_in_if_conditions"]),
+    ("BoolAssertComparison", &["bool_assert_comparison"]),
+    ("BoolComparison", &["bool_comparison"]),
+    ("BoolToIntWithIf", &["bool_to_int_with_if"]),
+    ("BorrowDerefRef", &["borrow_deref_ref"]),
+    ("BoxedLocal", &["boxed_local"]),
+    ("Cargo", &["cargo_common_metadata", "redundant_feature_names", "negative_feature_names", "multiple_crate_versions", "wildcard_dependencies"]),
+    ("Casts", &["cast_precision_loss", "cast_sign_loss", "cast_possible_truncation", "cast_possible_wrap", "cast_lossless", "cast_ref_to_mut", "cast_ptr_alignment", "cast_slice_different_sizes", "unnecessary_cast", "fn_to_numeric_cast_any", "fn_to_numeric_cast", "fn_to_numeric_cast_with_truncation", "char_lit_as_u8", "ptr_as_ptr", "cast_enum_truncation", "cast_enum_constructor", "cast_abs_to_unsigned", "as_underscore", "borrow_as_ptr", "cast_slice_from_raw_parts"]),
+    ("CheckedConversions", &["checked_conversions"]),
+    ("ClippyLintsInternal", &["clippy_lints_internal"]),
+    ("CognitiveComplexity", &["cognitive_complexity"]),
+    ("CollapsibleCalls", &["collapsible_span_lint_calls"]),
+    ("CollapsibleIf", &["collapsible_if", "collapsible_else_if"]),
+    ("ComparisonChain", &["comparison_chain"]),
+    ("CompilerLintFunctions", &["compiler_lint_functions"]),
+    ("CopyAndPaste", &["ifs_same_cond", "same_functions_in_if_condition", "if_same_then_else", "branches_sharing_code"]),
+    ("CopyIterator", &["copy_iterator"]),
+    ("CrateInMacroDef", &["crate_in_macro_def"]),
+    ("CreateDir", &["create_dir"]),
+    ("DbgMacro", &["dbg_macro"]),
+    ("DebugAssertWithMutCall", &["debug_assert_with_mut_call"]),
+    ("DecimalLiteralRepresentation", &["decimal_literal_representation"]),
+    ("Default", &["default_trait_access", "field_reassign_with_default"]),
+    ("DefaultIterEmpty", &["default_instead_of_iter_empty"]),
+    ("DefaultNumericFallback", &["default_numeric_fallback"]),
+    ("DefaultUnionRepresentation", &["default_union_representation"]),
+    ("DerefAddrOf", &["deref_addrof"]),
+    ("Dereferencing", &["explicit_deref_methods", "needless_borrow", "ref_binding_to_reference", "explicit_auto_deref"]),
+    ("DerivableImpls", &["derivable_impls"]),
+    ("Derive", &["expl_impl_clone_on_copy", "derive_hash_xor_eq", "derive_ord_xor_partial_ord", "unsafe_derive_deserialize", "derive_partial_eq_without_eq"]),
+    ("DisallowedDependencies", &["disallowed_crates", "disallowed_modules", "disallowed_trait_impls"]),
+    ("DisallowedMacros", &["disallowed_macros"]),
+    ("DisallowedMethods", &["disallowed_methods"]),
+    ("DisallowedNames", &["disallowed_names"]),
+    ("DisallowedScriptIdents", &["disallowed_script_idents"]),
+    ("DisallowedTypes", &["disallowed_types"]),
+    ("DocLinkWithQuotes", &["doc_link_with_quotes"]),
+    ("DocMarkdown", &["doc_markdown", "missing_safety_doc", "missing_errors_doc", "missing_panics_doc", "needless_doctest_main"]),
+    ("DoubleParens", &["double_parens"]),
+    ("DropForgetRef", &["drop_ref", "forget_ref", "drop_copy", "forget_copy", "drop_non_drop", "forget_non_drop", "undropped_manually_drops"]),
+    ("DumpHir", &["dump_hir"]),
+    ("DuplicateMod", &["duplicate_mod"]),
+    ("EarlyAttributes", &["deprecated_cfg_attr", "mismatched_target_os", "empty_line_after_outer_attr"]),
+    ("ElseIfWithoutElse", &["else_if_without_else"]),
+    ("EmptyDrop", &["empty_drop"]),
+    ("EmptyEnum", &["empty_enum"]),
+    ("EmptyStructsWithBrackets", &["empty_structs_with_brackets"]),
+    ("EnumVariantNames", &["enum_variant_names", "module_name_repetitions", "module_inception"]),
+    ("EtaReduction", &["redundant_closure", "redundant_closure_for_method_calls"]),
+    ("EvalOrderDependence", &["mixed_read_write_in_expression", "diverging_sub_expression"]),
+    ("ExcessiveBools", &["struct_excessive_bools", "fn_params_excessive_bools"]),
+    ("ExhaustiveItems", &["exhaustive_enums", "exhaustive_structs"]),
+    ("Exit", &["exit"]),
+    ("ExplicitWrite", &["explicit_write"]),
+    ("FallibleImplFrom", &["fallible_impl_from"]),
+    ("FloatLiteral", &["excessive_precision", "lossy_float_literal"]),
+    ("FloatingPointArithmetic", &["imprecise_flops", "suboptimal_flops"]),
+    ("FormatArgs", &["format_in_format_args", "to_string_in_format_args"]),
+    ("FormatImpl", &["recursive_format_impl", "print_in_format_impl"]),
+    ("FormatPushString", &["format_push_string"]),
+    ("Formatting", &["suspicious_assignment_formatting", "suspicious_unary_op_formatting", "suspicious_else_formatting", "possible_missing_comma"]),
+    ("FromOverInto", &["from_over_into"]),
+    ("FromStrRadix10", &["from_str_radix_10"]),
+    ("Functions", &["too_many_arguments", "too_many_lines", "not_unsafe_ptr_arg_deref", "must_use_unit", "double_must_use", "must_use_candidate", "result_unit_err", "result_large_err"]),
+    ("FutureNotSend", &["future_not_send"]),
+    ("HashMapPass", &["map_entry"]),
+    ("IfChainStyle", &["if_chain_style"]),
+    ("IfLetMutex", &["if_let_mutex"]),
+    ("IfNotElse", &["if_not_else"]),
+    ("IfThenSomeElseNone", &["if_then_some_else_none"]),
+    ("ImplicitHasher", &["implicit_hasher"]),
+    ("ImplicitReturn", &["implicit_return"]),
+    ("ImplicitSaturatingSub", &["implicit_saturating_sub"]),
+    ("ImportRename", &["missing_enforced_import_renames"]),
+    ("IncompatibleMsrv", &["incompatible_msrv"]),
+    ("InconsistentStructConstructor", &["inconsistent_struct_constructor"]),
+    ("IndexRefutableSlice", &["index_refutable_slice"]),
+    ("IndexingSlicing", &["indexing_slicing", "out_of_bounds_indexing"]),
+    ("InfiniteIter", &["infinite_iter", "maybe_infinite_iter"]),
+    ("InherentToString", &["inherent_to_string", "inherent_to_string_shadow_display"]),
+    ("InlineAsmX86AttSyntax", &["inline_asm_x86_att_syntax"]),
+    ("InlineAsmX86IntelSyntax", &["inline_asm_x86_intel_syntax"]),
+    ("InlineFnWithoutBody", &["inline_fn_without_body"]),
+    ("IntPlusOne", &["int_plus_one"]),
+    ("InterningDefinedSymbol", &["interning_defined_symbol", "unnecessary_symbol_str"]),
+    ("InvalidPaths", &["invalid_paths"]),
+    ("InvalidUpcastComparisons", &["invalid_upcast_comparisons"]),
+    ("InvalidUtf8InUnchecked", &["invalid_utf8_in_unchecked"]),
+    ("ItemsAfterStatements", &["items_after_statements"]),
+    ("IterNotReturningIterator", &["iter_not_returning_iterator"]),
+    ("LargeConstArrays", &["large_const_arrays"]),
+    ("LargeEnumVariant", &["large_enum_variant"]),
+    ("LargeIncludeFile", &["large_include_file"]),
+    ("LargeStackArrays", &["large_stack_arrays"]),
+    ("Layers", &["layer_violation"]),
+    ("LenZero", &["len_zero", "len_without_is_empty", "comparison_to_empty"]),
+    ("LetIfSeq", &["useless_let_if_seq"]),
+    ("LetUnderscore", &["let_underscore_must_use", "let_underscore_lock", "let_underscore_drop"]),
+    ("Lifetimes", &["needless_lifetimes", "extra_unused_lifetimes"]),
+    ("LintWithoutLintPass", &["default_lint", "lint_without_lint_pass", "invalid_clippy_version_attribute", "missing_clippy_version_attribute", "default_deprecation_reason"]),
+    ("LiteralDigitGrouping", &["unreadable_literal", "inconsistent_digit_grouping", "large_digit_groups", "mistyped_literal_suffixes", "unusual_byte_groupings"]),
+    ("Loops", &["manual_memcpy", "manual_flatten", "needless_range_loop", "explicit_iter_loop", "explicit_into_iter_loop", "iter_next_loop", "for_loops_over_fallibles", "while_let_loop", "needless_collect", "explicit_counter_loop", "empty_loop", "while_let_on_iterator", "for_kv_map", "never_loop", "mut_range_bound", "while_immutable_condition", "same_item_push", "single_element_loop", "missing_spin_loop", "manual_find"]),
+    ("MacroBraces", &["nonstandard_macro_braces"]),
+    ("MacroUseImports", &["macro_use_imports"]),
+    ("MainRecursion", &["main_recursion"]),
+    ("ManualAssert", &["manual_assert"]),
+    ("ManualAsyncFn", &["manual_async_fn"]),
+    ("ManualBits", &["manual_bits"]),
+    ("ManualInstantElapsed", &["manual_instant_elapsed"]),
+    ("ManualNonExhaustiveEnum", &["manual_non_exhaustive"]),
+    ("ManualNonExhaustiveStruct", &["manual_non_exhaustive"]),
+    ("ManualRemEuclid", &["manual_rem_euclid"]),
+    ("ManualRetain", &["manual_retain"]),
+    ("ManualStringNew", &["manual_string_new"]),
+    ("ManualStrip", &["manual_strip"]),
+    ("MapUnit", &["option_map_unit_fn", "result_map_unit_fn"]),
+    ("MatchResultOk", &["match_result_ok"]),
+    ("MatchTypeOnDiagItem", &["match_type_on_diagnostic_item"]),
+    ("Matches", &["single_match", "match_ref_pats", "match_bool", "single_match_else", "match_overlapping_arm", "match_wild_err_arm", "match_as_ref", "wildcard_enum_match_arm", "match_wildcard_for_single_variants", "wildcard_in_or_patterns", "match_single_binding", "infallible_destructuring_match", "rest_pat_in_fully_bound_structs", "redundant_pattern_matching", "match_like_matches_macro", "match_same_arms", "needless_match", "collapsible_match", "manual_unwrap_or", "match_on_vec_items", "match_str_case_mismatch", "significant_drop_in_scrutinee", "try_err", "manual_map"]),
+    ("MemForget", &["mem_forget"]),
+    ("MemReplace", &["mem_replace_option_with_none", "mem_replace_with_uninit", "mem_replace_with_default"]),
+    ("MetadataCollector", &["internal_metadata_collector"]),
+    ("Methods", &["unwrap_used", "expect_used", "should_implement_trait", "wrong_self_convention", "ok_expect", "unwrap_or_else_default", "map_unwrap_or", "result_map_or_into_option", "option_map_or_none", "bind_instead_of_map", "or_fun_call", "or_then_unwrap", "expect_fun_call", "chars_next_cmp", "chars_last_cmp", "clone_on_copy", "clone_on_ref_ptr", "clone_double_ref", "collapsible_str_replace", "iter_overeager_cloned", "cloned_instead_of_copied", "flat_map_option", "inefficient_to_string", "new_ret_no_self", "single_char_pattern", "single_char_add_str", "search_is_some", "filter_next", "skip_while_next", "filter_map_identity", "map_identity", "manual_filter_map", "manual_find_map", "option_filter_map", "filter_map_next", "flat_map_identity", "map_flatten", "iterator_step_by_zero", "iter_next_slice", "iter_count", "iter_nth", "iter_nth_zero", "bytes_nth", "iter_skip_next", "get_unwrap", "get_last_with_len", "string_extend_chars", "iter_cloned_collect", "iter_with_drain", "useless_asref", "unnecessary_fold", "unnecessary_filter_map", "unnecessary_find_map", "into_iter_on_ref", "suspicious_map", "uninit_assumed_init", "manual_saturating_arithmetic", "zst_offset", "filetype_is_file", "option_as_ref_deref", "unnecessary_lazy_evaluations", "map_collect_result_unit", "from_iter_instead_of_collect", "inspect_for_each", "implicit_clone", "suspicious_to_owned", "suspicious_splitn", "manual_str_repeat", "extend_with_drain", "manual_split_once", "needless_splitn", "unnecessary_to_owned", "unnecessary_join", "err_expect", "needless_option_as_deref", "is_digit_ascii_radix", "needless_option_take", "no_effect_replace", "obfuscated_if_else", "iter_on_single_items", "iter_on_empty_collections", "naive_bytecount", "bytes_count_to_len", "case_sensitive_file_extension_comparisons", "get_first", "manual_ok_or", "map_clone", "map_err_ignore", "mut_mutex_lock", "nonsensical_open_options", "path_buf_push_overwrite", "range_zip_with_len", "repeat_once", "stable_sort_primitive", "unit_hash", "unnecessary_sort_by", "vec_resize_to_zero", "verbose_file_reads", "iter_kv_map"]),
+    ("MinMaxPass", &["min_max"]),
+    ("MiscEarlyLints", &["unneeded_field_pattern", "duplicate_underscore_argument", "double_neg", "mixed_case_hex_literals", "unseparated_literal_suffix", "separated_literal_suffix", "zero_prefixed_literal", "builtin_type_shadow", "redundant_pattern", "unneeded_wildcard_pattern"]),
+    ("MiscLints", &["toplevel_ref_arg", "used_underscore_binding", "short_circuit_statement", "zero_ptr"]),
+    ("MissingConstForFn", &["missing_const_for_fn"]),
+    ("MissingDoc", &["missing_docs_in_private_items"]),
+    ("MissingInline", &["missing_inline_in_public_items"]),
+    ("ModStyle", &["mod_module_files", "self_named_module_files"]),
+    ("MsrvAttrImpl", &["missing_msrv_attr_impl"]),
+    ("MultiAssignments", &["multi_assignments"]),
+    ("MultipleInherentImpl", &["multiple_inherent_impl"]),
+    ("MutMut", &["mut_mut"]),
+    ("MutableKeyType", &["mutable_key_type"]),
+    ("Mutex", &["mutex_atomic", "mutex_integer"]),
+    ("NeedlessArbitrarySelfType", &["needless_arbitrary_self_type"]),
+    ("NeedlessBool", &["needless_bool"]),
+    ("NeedlessBorrowedRef", &["needless_borrowed_reference"]),
+    ("NeedlessContinue", &["needless_continue"]),
+    ("NeedlessForEach", &["needless_for_each"]),
+    ("NeedlessLateInit", &["needless_late_init"]),
+    ("NeedlessParensOnRangeLiterals", &["needless_parens_on_range_literals"]),
+    ("NeedlessPassByValue", &["needless_pass_by_value"]),
+    ("NeedlessQuestionMark", &["needless_question_mark"]),
+    ("NeedlessUpdate", &["needless_update"]),
+    ("NegMultiply", &["neg_multiply"]),
+    ("NewWithoutDefault", &["new_without_default"]),
+    ("NoEffect", &["no_effect", "unnecessary_operation", "no_effect_underscore_binding"]),
+    ("NoNegCompOpForPartialOrd", &["neg_cmp_op_on_partial_ord"]),
+    ("NonCopyConst", &["declare_interior_mutable_const", "borrow_interior_mutable_const"]),
+    ("NonExpressiveNames", &["similar_names", "many_single_char_names", "just_underscores_and_digits"]),
+    ("NonOctalUnixPermissions", &["non_octal_unix_permissions"]),
+    ("NonSendFieldInSendTy", &["non_send_fields_in_send_ty"]),
+    ("NonminimalBool", &["nonminimal_bool", "overly_complex_bool_expr"]),
+    ("NumberedFields", &["init_numbered_fields"]),
+    ("OctalEscapes", &["octal_escapes"]),
+    ("OnlyUsedInRecursion", &["only_used_in_recursion"]),
+    ("Operators", &["absurd_extreme_comparisons", "arithmetic_side_effects", "integer_arithmetic", "float_arithmetic", "assign_op_pattern", "misrefactored_assign_op", "bad_bit_mask", "ineffective_bit_mask", "verbose_bit_mask", "double_comparisons", "duration_subsec", "eq_op", "op_ref", "erasing_op", "float_equality_without_abs", "identity_op", "integer_division", "cmp_nan", "cmp_owned", "float_cmp", "float_cmp_const", "modulo_one", "modulo_arithmetic", "needless_bitwise_bool", "ptr_eq", "self_assignment"]),
+    ("OptionEnvUnwrap", &["option_env_unwrap"]),
+    ("OptionIfLetElse", &["option_if_let_else"]),
+    ("OuterExpnDataPass", &["outer_expn_expn_data"]),
+    ("OverflowCheckConditional", &["overflow_check_conditional"]),
+    ("PanicInResultFn", &["panic_in_result_fn"]),
+    ("PanicUnimplemented", &["unimplemented", "unreachable", "todo", "panic"]),
+    ("PartialEqNeImpl", &["partialeq_ne_impl"]),
+    ("PartialeqToNone", &["partialeq_to_none"]),
+    ("PassByRefOrValue", &["trivially_copy_pass_by_ref", "large_types_passed_by_value"]),
+    ("PatternEquality", &["equatable_if_let"]),
+    ("PatternTypeMismatch", &["pattern_type_mismatch"]),
+    ("Precedence", &["precedence"]),
+    ("ProduceIce", &["produce_ice"]),
+    ("Ptr", &["ptr_arg", "cmp_null", "mut_from_ref", "invalid_null_ptr_usage"]),
+    ("PtrOffsetWithCast", &["ptr_offset_with_cast"]),
+    ("PubUse", &["pub_use"]),
+    ("QuestionMark", &["question_mark"]),
+    ("Ranges", &["range_plus_one", "range_minus_one", "reversed_empty_ranges", "manual_range_contains"]),
+    ("RcCloneInVecInit", &["rc_clone_in_vec_init"]),
+    ("ReadZeroByteVec", &["read_zero_byte_vec"]),
+    ("RedundantClone", &["redundant_clone"]),
+    ("RedundantClosureCall", &["redundant_closure_call"]),
+    ("RedundantElse", &["redundant_else"]),
+    ("RedundantFieldNames", &["redundant_field_names"]),
+    ("RedundantPubCrate", &["redundant_pub_crate"]),
+    ("RedundantSlicing", &["redundant_slicing", "deref_by_slicing"]),
+    ("RedundantStaticLifetimes", &["redundant_static_lifetimes"]),
+    ("RefOptionRef", &["ref_option_ref"]),
+    ("Regex", &["invalid_regex", "trivial_regex"]),
+    ("Return", &["let_and_return", "needless_return"]),
+    ("ReturnSelfNotMustUse", &["return_self_not_must_use"]),
+    ("SameNameMethod", &["same_name_method"]),
+    ("SelfNamedConstructors", &["self_named_constructors"]),
+    ("SemicolonIfNothingReturned", &["semicolon_if_nothing_returned"]),
+    ("SerdeApi", &["serde_api_misuse"]),
+    ("Shadow", &["shadow_same", "shadow_reuse", "shadow_unrelated"]),
+    ("SingleCharLifetimeNames", &["single_char_lifetime_names"]),
+    ("SingleComponentPathImports", &["single_component_path_imports"]),
+    ("SizeOfInElementCount", &["size_of_in_element_count"]),
+    ("SlowVectorInit", &["slow_vector_initialization"]),
+    ("StdReexports", &["std_instead_of_core", "std_instead_of_alloc", "alloc_instead_of_core"]),
+    ("StrToString", &["str_to_string"]),
+    ("StringAdd", &["string_add", "string_add_assign", "string_slice"]),
+    ("StringLitAsBytes", &["string_lit_as_bytes", "string_from_utf8_as_bytes"]),
+    ("StringToString", &["string_to_string"]),
+    ("StrlenOnCStrings", &["strlen_on_c_strings"]),
+    ("SuspiciousImpl", &["suspicious_arithmetic_impl", "suspicious_op_assign_impl"]),
+    ("SuspiciousOperationGroupings", &["suspicious_operation_groupings"]),
+    ("Swap", &["manual_swap", "almost_swapped"]),
+    ("SwapPtrToRef", &["swap_ptr_to_ref"]),
+    ("TabsInDocComments", &["tabs_in_doc_comments"]),
+    ("TemporaryAssignment", &["temporary_assignment"]),
+    ("ToDigitIsSome", &["to_digit_is_some"]),
+    ("TrailingEmptyArray", &["trailing_empty_array"]),
+    ("TraitBounds", &["type_repetition_in_bounds", "trait_duplication_in_bounds"]),
+    ("Transmute", &["crosspointer_transmute", "transmute_ptr_to_ref", "transmute_ptr_to_ptr", "useless_transmute", "wrong_transmute", "transmute_int_to_char", "transmute_bytes_to_str", "transmute_int_to_bool", "transmute_int_to_float", "transmute_float_to_int", "transmute_num_to_bytes", "unsound_collection_transmute", "transmutes_expressible_as_ptr_casts", "transmute_undefined_repr", "transmuting_null"]),
+    ("TrimSplitWhitespace", &["trim_split_whitespace"]),
+    ("TypeParamMismatch", &["mismatching_type_param_order"]),
+    ("Types", &["box_collection", "vec_box", "option_option", "linkedlist", "borrowed_box", "redundant_allocation", "rc_buffer", "rc_mutex", "type_complexity"]),
+    ("UndocumentedUnsafeBlocks", &["undocumented_unsafe_blocks"]),
+    ("Unicode", &["invisible_characters", "non_ascii_literal", "unicode_not_nfc"]),
+    ("UninitVec", &["uninit_vec"]),
+    ("UnitReturnExpectingOrd", &["unit_return_expecting_ord"]),
+    ("UnitTypes", &["let_unit_value", "unit_cmp", "unit_arg"]),
+    ("UnnamedAddress", &["fn_address_comparisons", "vtable_address_comparisons"]),
+    ("UnnecessaryMutPassed", &["unnecessary_mut_passed"]),
+    ("UnnecessaryOwnedEmptyStrings", &["unnecessary_owned_empty_strings"]),
+    ("UnnecessarySelfImports", &["unnecessary_self_imports"]),
+    ("UnnecessaryWraps", &["unnecessary_wraps"]),
+    ("UnnestedOrPatterns", &["unnested_or_patterns"]),
+    ("UnportableVariant", &["enum_clike_unportable_variant"]),
+    ("UnsafeNameRemoval", &["unsafe_removed_from_name"]),
+    ("UnusedAsync", &["unused_async"]),
+    ("UnusedIoAmount", &["unused_io_amount"]),
+    ("UnusedPeekable", &["unused_peekable"]),
+    ("UnusedRounding", &["unused_rounding"]),
+    ("UnusedSelf", &["unused_self"]),
+    ("UnusedUnit", &["unused_unit"]),
+    ("Unwrap", &["panicking_unwrap", "unnecessary_unwrap"]),
+    ("UnwrapInResult", &["unwrap_in_result"]),
+    ("UpperCaseAcronyms", &["upper_case_acronyms"]),
+    ("UseSelf", &["use_self"]),
+    ("UselessConversion", &["useless_conversion"]),
+    ("UselessFormat", &["useless_format"]),
+    ("UselessVec", &["useless_vec"]),
+    ("VecInitThenPush", &["vec_init_then_push"]),
+    ("WildcardImports", &["enum_glob_use", "wildcard_imports"]),
+    ("Write", &["print_with_newline", "println_empty_string", "print_stdout", "print_stderr", "use_debug", "print_literal", "write_with_newline", "writeln_empty_string", "write_literal"]),
+    ("ZeroDiv", &["zero_divided_by_zero"]),
+    ("ZeroSizedMapValues", &["zero_sized_map_values"]),
+]
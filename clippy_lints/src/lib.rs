@@ -50,10 +50,15 @@ extern crate rustc_typeck;
 extern crate clippy_utils;
 
 use clippy_utils::parse_msrv;
-use rustc_data_structures::fx::FxHashSet;
-use rustc_lint::LintId;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_lint::{Level, LintId};
+use rustc_middle::ty::TyCtxt;
 use rustc_semver::RustcVersion;
 use rustc_session::Session;
+use rustc_span::lev_distance::find_best_match_for_name;
+use rustc_span::Symbol;
+use std::collections::BTreeMap;
+use std::path::Path;
 
 /// Macro used to declare a Clippy lint.
 ///
@@ -199,6 +204,8 @@ mod default_union_representation;
 mod dereference;
 mod derivable_impls;
 mod derive;
+mod disallowed_dependencies;
+mod disallowed_macros;
 mod disallowed_methods;
 mod disallowed_names;
 mod disallowed_script_idents;
@@ -240,6 +247,7 @@ mod if_then_some_else_none;
 mod implicit_hasher;
 mod implicit_return;
 mod implicit_saturating_sub;
+mod incompatible_msrv;
 mod inconsistent_struct_constructor;
 mod index_refutable_slice;
 mod indexing_slicing;
@@ -257,6 +265,7 @@ mod large_const_arrays;
 mod large_enum_variant;
 mod large_include_file;
 mod large_stack_arrays;
+mod layers;
 mod len_zero;
 mod let_if_seq;
 mod let_underscore;
@@ -400,6 +409,7 @@ mod zero_div_zero;
 mod zero_sized_map_values;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
+pub use crate::utils::baseline::BaselineMode;
 pub use crate::utils::conf::Conf;
 use crate::utils::conf::{format_error, TryConf};
 
@@ -463,8 +473,7 @@ fn read_msrv(conf: &Conf, sess: &Session) -> Option<RustcVersion> {
 #[doc(hidden)]
 pub fn read_conf(sess: &Session) -> Conf {
     let file_name = match utils::conf::lookup_conf_file() {
-        Ok(Some(path)) => path,
-        Ok(None) => return Conf::default(),
+        Ok(path) => path,
         Err(error) => {
             sess.struct_err(&format!("error finding Clippy's configuration file: {}", error))
                 .emit();
@@ -472,52 +481,359 @@ pub fn read_conf(sess: &Session) -> Conf {
         },
     };
 
-    let TryConf { conf, errors, warnings } = utils::conf::read(&file_name);
+    let TryConf { conf, errors, warnings } = file_name
+        .as_deref()
+        .map_or_else(utils::conf::read_default, utils::conf::read);
+    // without a configuration file, the errors come from the values set by the environment or on the
+    // command line
+    let source = match &file_name {
+        Some(file_name) => format!("Clippy's configuration file `{}`", file_name.display()),
+        None => "Clippy's configuration".to_string(),
+    };
     // all conf errors are non-fatal, we just use the default conf in case of error
     for error in errors {
-        sess.err(&format!(
-            "error reading Clippy's configuration file `{}`: {}",
-            file_name.display(),
-            format_error(error)
-        ));
+        sess.err(&format!("error reading {}: {}", source, format_error(error)));
     }
 
     for warning in warnings {
-        sess.struct_warn(&format!(
-            "error reading Clippy's configuration file `{}`: {}",
-            file_name.display(),
-            format_error(warning)
-        ))
-        .emit();
+        sess.struct_warn(&format!("error reading {}: {}", source, format_error(warning)))
+            .emit();
     }
 
     conf
 }
 
-/// Register all lints and lint groups with the rustc plugin registry
+/// Sets the configuration values passed on the command line with `--config KEY=VALUE`, which take
+/// precedence over the configuration file and the `CLIPPY_CONF_<KEY>` environment variables. This
+/// must be called before the configuration is read.
 ///
 /// Used in `./src/driver.rs`.
-#[expect(clippy::too_many_lines)]
-pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf: &Conf) {
-    register_removed_non_tool_lints(store);
+pub fn set_conf_cli_values(values: Vec<String>) {
+    utils::conf::set_cli_values(values);
+}
 
-    include!("lib.deprecated.rs");
+/// Returns the `CLIPPY_CONF_<KEY>` environment variables overriding the configuration values, for
+/// Cargo to lint the crates again when they change.
+///
+/// Used in `./src/driver.rs`.
+pub fn conf_env_vars() -> Vec<String> {
+    utils::conf::env_vars()
+}
 
-    include!("lib.register_lints.rs");
-    include!("lib.register_restriction.rs");
-    include!("lib.register_pedantic.rs");
+/// Converts the name of a lint or lint group in the `[lints]` table of the configuration file to
+/// the name it's registered with, e.g. `unwrap-used` to `clippy::unwrap_used`.
+pub(crate) fn conf_lint_name(name: &str) -> String {
+    let name = name.to_ascii_lowercase().replace('-', "_");
+    format!("clippy::{}", name.strip_prefix("clippy::").unwrap_or(&name))
+}
 
-    #[cfg(feature = "internal")]
-    include!("lib.register_internal.rs");
+/// Reads the lint levels set in the `[lints]` table of the configuration file, to be passed to
+/// rustc like the `-A`/`-W`/`-D`/`-F` command line flags.
+///
+/// This is called before the session is created, so errors are not reported here. Unknown lint
+/// names are left out and reported by `check_conf_lint_levels` once the lints are registered.
+///
+/// Used in `./src/driver.rs`.
+pub fn conf_lint_levels() -> Vec<(String, Level)> {
+    let Ok(path) = utils::conf::lookup_conf_file() else {
+        return Vec::new();
+    };
+    let conf = path
+        .as_deref()
+        .map_or_else(utils::conf::read_default, utils::conf::read)
+        .conf;
 
-    include!("lib.register_all.rs");
-    include!("lib.register_style.rs");
-    include!("lib.register_complexity.rs");
-    include!("lib.register_correctness.rs");
-    include!("lib.register_suspicious.rs");
-    include!("lib.register_perf.rs");
-    include!("lib.register_cargo.rs");
-    include!("lib.register_nursery.rs");
+    // only the Clippy lints are registered: the renamed lints are looked up in `RENAMED_LINTS`, as
+    // registering them would need the rustc lints some of them are renamed to
+    let mut store = rustc_lint::LintStore::new();
+    register_lints(&mut store);
+
+    let mut levels: Vec<_> = conf
+        .lints
+        .iter()
+        .filter_map(|(name, level)| {
+            let name = conf_lint_name(name);
+            match renamed_lint(&name) {
+                Some(new_name) => Some((new_name.to_string(), level.level())),
+                None => store.find_lints(&name).is_ok().then_some((name, level.level())),
+            }
+        })
+        .collect();
+    // the last flag wins, so the groups come first to let the levels of single lints take precedence
+    let groups: FxHashSet<_> = store.get_lint_groups().map(|(name, ..)| name).collect();
+    levels.sort_by_key(|(name, _)| !groups.contains(name.as_str()));
+    levels
+}
+
+/// The name a renamed lint was renamed to, e.g. `clippy::blacklisted_name` to
+/// `clippy::disallowed_names`.
+pub(crate) fn renamed_lint(name: &str) -> Option<&'static str> {
+    renamed_lints::RENAMED_LINTS
+        .iter()
+        .find(|(old_name, _)| *old_name == name)
+        .map(|(_, new_name)| *new_name)
+}
+
+/// Resolves the levels of a `lints` table of the configuration file to the lints they apply to.
+/// Unknown lint names are left out.
+pub(crate) fn resolve_conf_lint_levels(
+    store: &rustc_lint::LintStore,
+    lints: &BTreeMap<String, utils::conf::LintLevel>,
+) -> FxHashMap<LintId, Level> {
+    let groups: FxHashSet<_> = store.get_lint_groups().map(|(name, ..)| name).collect();
+    let mut levels = FxHashMap::default();
+    // the levels of the groups are set first, so that the levels of single lints take precedence
+    for set_groups in [true, false] {
+        for (name, level) in lints {
+            let name = conf_lint_name(name);
+            if groups.contains(name.as_str()) == set_groups
+                && let Ok(lints) = store.find_lints(&name)
+            {
+                levels.extend(lints.into_iter().map(|lint| (lint, level.level())));
+            }
+        }
+    }
+    levels
+}
+
+/// Finds the names in the `[lints]` and `[[overrides]]` tables of the configuration file that are
+/// neither Clippy lints nor lint groups. Returns the warning to emit for each of them, along with a
+/// suggestion if a lint with a similar name exists.
+fn unknown_conf_lints(store: &rustc_lint::LintStore, conf: &Conf) -> Vec<(String, Option<String>)> {
+    let names = conf.lints.keys().map(|name| (name, "the `[lints]` table")).chain(
+        conf.overrides
+            .iter()
+            .flat_map(|path_override| path_override.lints.keys())
+            .map(|name| (name, "an `[[overrides]]` table")),
+    );
+    let mut unknown = Vec::new();
+    for (name, table) in names {
+        let lint_name = conf_lint_name(name);
+        if store.find_lints(&lint_name).is_ok() || renamed_lint(&lint_name).is_some() {
+            continue;
+        }
+
+        let candidates = store
+            .get_lints()
+            .iter()
+            .map(|lint| lint.name_lower())
+            .chain(store.get_lint_groups().map(|(group, ..)| group.to_string()))
+            .filter(|candidate| candidate.starts_with("clippy::"))
+            .map(|candidate| Symbol::intern(&candidate))
+            .collect::<Vec<_>>();
+        unknown.push((
+            format!("unknown lint `{}` in {} of Clippy's configuration file", name, table),
+            find_best_match_for_name(&candidates, Symbol::intern(&lint_name), None)
+                .map(|suggestion| format!("did you mean: `{}`", suggestion)),
+        ));
+    }
+    unknown
+}
+
+/// Reports the names in the `[lints]` and `[[overrides]]` tables of the configuration file that are
+/// neither Clippy lints nor lint groups.
+///
+/// Used in `./src/driver.rs`.
+pub fn check_conf_lint_levels(sess: &Session, store: &rustc_lint::LintStore, conf: &Conf) {
+    for (message, help) in unknown_conf_lints(store, conf) {
+        let mut diag = sess.struct_warn(&message);
+        if let Some(help) = help {
+            diag.help(&help);
+        }
+        diag.emit();
+    }
+}
+
+/// Only lets `cargo fix` apply the suggestions of the given lints and lint groups, see
+/// `cargo clippy --fix-only`. The names can omit the `clippy::` prefix. The diagnostics must be
+/// emitted through `fix_only_output`, which keeps the suggestions of the other diagnostics, rustc's
+/// included, from being applied. They are still emitted.
+///
+/// Used in `./src/driver.rs`.
+pub fn restrict_fixes(sess: &Session, store: &rustc_lint::LintStore, names: &[String]) {
+    utils::fix_only::restrict(sess, store, names);
+}
+
+/// The output of the JSON diagnostics for `restrict_fixes`, which writes them to stderr.
+///
+/// Used in `./src/driver.rs`.
+pub fn fix_only_output() -> Box<dyn std::io::Write + Send> {
+    Box::new(utils::fix_only::FixFilter::new(std::io::stderr()))
+}
+
+/// Prints the JSON Schema of the configuration file.
+///
+/// Used in `./src/driver.rs`.
+pub fn print_conf_schema() {
+    println!("{:#}", utils::conf::schema());
+}
+
+/// Checks the configuration file at `path`, or the one found from the current directory, without
+/// compiling anything. The problems found are printed to stderr. Returns whether no error was
+/// found.
+///
+/// Used in `./src/driver.rs`.
+pub fn check_conf_file(path: Option<&Path>) -> bool {
+    utils::check_conf::check(path)
+}
+
+/// Registers the emission hook recording or suppressing the lints of the baseline, see
+/// `cargo clippy --help`. This must be called after `register_path_overrides`.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_baseline(sess: &Session, mode: BaselineMode) {
+    utils::baseline::register(sess, mode);
+}
+
+/// Writes the lints recorded for the baseline once the crate is linted.
+///
+/// Used in `./src/driver.rs`.
+pub fn finish_baseline(sess: &Session) {
+    utils::baseline::finish(sess);
+}
+
+/// Writes the baseline at `path` from the lints recorded in `dir` by each crate. Returns whether
+/// it succeeded.
+///
+/// Used in `./src/driver.rs`.
+pub fn merge_baseline(dir: &Path, path: &Path) -> bool {
+    utils::baseline::merge(dir, path)
+}
+
+/// Reports the entries of the baseline at `path` that no crate whose lints are recorded in `dir`
+/// suppressed. Returns whether no error occurred.
+///
+/// Used in `./src/driver.rs`.
+pub fn check_baseline(dir: &Path, path: &Path) -> bool {
+    utils::baseline::check(dir, path)
+}
+
+/// Registers the emission hook allowing the lints outside of the changed lines listed in the file
+/// at `path`, see `cargo clippy --diff-base`. This must be called after `register_baseline`, so
+/// that the lints outside of the changed lines still match the entries of the baseline.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_diff_base(sess: &Session, path: &Path) {
+    utils::diff_base::register(sess, path);
+}
+
+/// Registers the emission hook counting the lints emitted in the crate, see
+/// `cargo clippy --summary`. This must be called after `register_diff_base`.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_summary() {
+    utils::summary::register();
+}
+
+/// Writes the number of each lint emitted in the crate to a file in `dir`, once the crate is
+/// linted.
+///
+/// Used in `./src/driver.rs`.
+pub fn finish_summary(sess: &Session, dir: &Path) {
+    utils::summary::finish(sess, dir);
+}
+
+/// Writes the summary at `path` from the lint counts written to `dir` by each crate, compared to
+/// the summary at `base`, or to the previous summary at `path`. Returns whether it succeeded.
+///
+/// Used in `./src/driver.rs`.
+pub fn merge_summary(dir: &Path, path: &Path, base: Option<&Path>) -> bool {
+    utils::summary::merge(dir, path, base)
+}
+
+/// Converts the JSON messages of Cargo read from stdin to a SARIF log printed to stdout, see
+/// `cargo clippy --message-format=sarif`. `root` is the workspace root.
+///
+/// Used in `./src/driver.rs`.
+///
+/// # Errors
+///
+/// This function errors out if stdin couldn't be read or stdout couldn't be written to.
+pub fn print_sarif(root: &Path, version: &str) -> std::io::Result<()> {
+    utils::sarif::convert(std::io::stdin().lock(), &mut std::io::stdout().lock(), root, version)
+}
+
+/// Measures the time spent in each lint pass, see `cargo clippy --timings`. This must be called
+/// after `register_path_overrides`, so that the passes of the overrides are measured as well.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_timings(store: &mut rustc_lint::LintStore) {
+    utils::timings::register(store);
+}
+
+/// Writes the time spent in each lint pass to a file in `dir`, once the crate is linted.
+///
+/// Used in `./src/driver.rs`.
+pub fn finish_timings(sess: &Session, dir: &Path) {
+    utils::timings::finish(sess, dir);
+}
+
+/// Prints the time spent in each lint pass and in each crate, from the files written to `dir` by
+/// each crate, and writes them as JSON to `path`. Returns whether it succeeded.
+///
+/// Used in `./src/driver.rs`.
+pub fn report_timings(dir: &Path, path: &Path) -> bool {
+    utils::timings::report(dir, path)
+}
+
+/// Keeps track of the lint pass being run, to write it to the bundle of an internal compiler error.
+/// This must be called after all the lint passes are registered.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_ice_tracking(store: &mut rustc_lint::LintStore) {
+    utils::ice::register(store);
+}
+
+/// Writes the bundle of an internal compiler error to a new directory in `dir`, see
+/// `CLIPPY_ICE_DIR`, and returns its path. `message` describes the panic.
+///
+/// Used in `./src/driver.rs`.
+///
+/// # Errors
+///
+/// This function errors out if the directory of the bundle couldn't be created or written to.
+pub fn write_ice_bundle(dir: &Path, message: &str) -> std::io::Result<std::path::PathBuf> {
+    utils::ice::write_bundle(dir, message)
+}
+
+/// Catches the panics of the lint passes, see `cargo clippy --catch-panics`. This must be called
+/// after all the lint passes are registered, and after `register_ice_tracking`.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_catch_panics(store: &mut rustc_lint::LintStore) {
+    utils::catch_panics::register(store);
+}
+
+/// Returns whether the panic described by `info` is caught by `register_catch_panics`, in which
+/// case it is reported as a warning rather than as an internal compiler error.
+///
+/// Used in `./src/driver.rs`.
+pub fn catch_lint_pass_panic(info: &std::panic::PanicInfo<'_>) -> bool {
+    utils::catch_panics::catch(info)
+}
+
+/// Reports the configuration and the lint levels used for the crate being compiled.
+///
+/// Used in `./src/driver.rs`.
+pub fn report_effective_conf(tcx: TyCtxt<'_>) {
+    utils::effective_conf::report(tcx);
+}
+
+/// Applies the `[[overrides]]` tables of the configuration file. This registers the lint passes
+/// again for the overrides that set configuration values, so it must be called after all the lints
+/// and lint passes are registered.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_path_overrides(store: &mut rustc_lint::LintStore, sess: &Session, conf: &Conf) {
+    utils::path_overrides::register(store, sess, conf);
+}
+
+/// Register all lints and lint groups with the rustc plugin registry
+///
+/// Used in `./src/driver.rs`.
+#[expect(clippy::too_many_lines)]
+pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf: &Conf) {
+    register_lints(store);
 
     #[cfg(feature = "internal")]
     {
@@ -824,6 +1140,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| Box::new(async_yields_async::AsyncYieldsAsync));
     let disallowed_methods = conf.disallowed_methods.clone();
     store.register_late_pass(move || Box::new(disallowed_methods::DisallowedMethods::new(disallowed_methods.clone())));
+    let disallowed_macros = conf.disallowed_macros.clone();
+    store.register_late_pass(move || Box::new(disallowed_macros::DisallowedMacros::new(disallowed_macros.clone())));
     store.register_early_pass(|| Box::new(asm_syntax::InlineAsmX86AttSyntax));
     store.register_early_pass(|| Box::new(asm_syntax::InlineAsmX86IntelSyntax));
     store.register_late_pass(|| Box::new(empty_drop::EmptyDrop));
@@ -839,6 +1157,18 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| Box::new(unused_async::UnusedAsync));
     let disallowed_types = conf.disallowed_types.clone();
     store.register_late_pass(move || Box::new(disallowed_types::DisallowedTypes::new(disallowed_types.clone())));
+    let disallowed_crates = conf.disallowed_crates.clone();
+    let disallowed_modules = conf.disallowed_modules.clone();
+    let disallowed_trait_impls = conf.disallowed_trait_impls.clone();
+    store.register_late_pass(move || {
+        Box::new(disallowed_dependencies::DisallowedDependencies::new(
+            disallowed_crates.clone(),
+            disallowed_modules.clone(),
+            disallowed_trait_impls.clone(),
+        ))
+    });
+    let layers = conf.layers.clone();
+    store.register_late_pass(move || Box::new(layers::Layers::new(layers.clone())));
     let import_renames = conf.enforced_import_renames.clone();
     store.register_late_pass(move || {
         Box::new(missing_enforced_import_rename::ImportRename::new(
@@ -906,9 +1236,33 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|| Box::new(unused_peekable::UnusedPeekable));
     store.register_early_pass(|| Box::new(multi_assignments::MultiAssignments));
     store.register_late_pass(|| Box::new(bool_to_int_with_if::BoolToIntWithIf));
+    store.register_late_pass(move || Box::new(incompatible_msrv::IncompatibleMsrv::new(msrv)));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
+/// Register all lints and lint groups, without their lint passes.
+fn register_lints(store: &mut rustc_lint::LintStore) {
+    register_removed_non_tool_lints(store);
+
+    include!("lib.deprecated.rs");
+
+    include!("lib.register_lints.rs");
+    include!("lib.register_restriction.rs");
+    include!("lib.register_pedantic.rs");
+
+    #[cfg(feature = "internal")]
+    include!("lib.register_internal.rs");
+
+    include!("lib.register_all.rs");
+    include!("lib.register_style.rs");
+    include!("lib.register_complexity.rs");
+    include!("lib.register_correctness.rs");
+    include!("lib.register_suspicious.rs");
+    include!("lib.register_perf.rs");
+    include!("lib.register_cargo.rs");
+    include!("lib.register_nursery.rs");
+}
+
 #[rustfmt::skip]
 fn register_removed_non_tool_lints(store: &mut rustc_lint::LintStore) {
     store.register_removed(
@@ -3,6 +3,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
+use crate::utils::conf::ConfSchema;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_opt;
 use if_chain::if_chain;
@@ -277,3 +278,21 @@ impl<'de> Deserialize<'de> for MacroMatcher {
         deser.deserialize_struct("MacroMatcher", FIELDS, MacVisitor)
     }
 }
+
+impl ConfSchema for MacroMatcher {
+    fn schema() -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "brace": { "enum": BRACES.iter().map(|(open, _)| open).collect::<Vec<_>>() },
+            },
+            "required": ["name", "brace"],
+            "additionalProperties": false,
+        })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "name": self.name, "brace": self.braces.0 }))
+    }
+}
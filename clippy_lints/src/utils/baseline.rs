@@ -0,0 +1,336 @@
+//! Baseline files, which record the lints emitted for a package so that they can be suppressed
+//! until they are fixed, see `cargo clippy --write-baseline` and `cargo clippy --baseline`.
+//!
+//! Lints are identified by a [`Fingerprint`], which doesn't depend on their line or column, so
+//! that editing the code around them doesn't break the baseline.
+//!
+//! Cargo runs one `clippy-driver` per crate, possibly in parallel, and not at all for the crates
+//! that didn't change. Each driver writes the lints it found, or the entries of the baseline it
+//! suppressed, to a fragment file in a directory created by `cargo-clippy`. Once Cargo is done,
+//! the fragments are merged into the baseline by [`merge`], or compared to it by [`check`].
+
+use clippy_utils::diagnostics::{register_emission_hook, Emission, EmissionHook};
+use rustc_errors::{ColorConfig, Handler};
+use rustc_lint::Level;
+use rustc_middle::lint::LevelAndSource;
+use rustc_session::Session;
+use rustc_span::FileName;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::{env, fs, process};
+
+/// The maximum number of characters of the snippets stored in the baseline.
+const MAX_SNIPPET_LEN: usize = 100;
+
+/// How the baseline is used when linting a crate.
+#[derive(Clone, Debug)]
+#[expect(clippy::module_name_repetitions)]
+pub enum BaselineMode {
+    /// Record the lints in a fragment written to the given directory.
+    Write(PathBuf),
+    /// Suppress the lints found in the given baseline file, and record the suppressed ones in a
+    /// fragment written to the given directory, if any.
+    Suppress(PathBuf, Option<PathBuf>),
+}
+
+/// Identifies a lint emitted by Clippy.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct Fingerprint {
+    lint: String,
+    file: String,
+    /// The path of the enclosing item, see `clippy_utils::diagnostics::item_path`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    item: Option<String>,
+    /// The code the lint points to, with its whitespace collapsed.
+    snippet: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    #[serde(flatten)]
+    fingerprint: Fingerprint,
+    /// The number of lints with this fingerprint.
+    count: usize,
+}
+
+/// The content of a baseline file.
+#[derive(Serialize, Deserialize)]
+struct Baseline {
+    entries: Vec<Entry>,
+}
+
+/// The lints found in a crate.
+#[derive(Serialize, Deserialize)]
+struct Fragment {
+    /// The directory the paths of the files are relative to.
+    root: PathBuf,
+    /// The files of the crate.
+    files: Vec<String>,
+    entries: Vec<Entry>,
+}
+
+struct State {
+    /// The directory to write the fragment of the crate in.
+    dir: Option<PathBuf>,
+    /// The number of lints of the baseline left to suppress, `None` when writing the baseline.
+    remaining: Option<BTreeMap<Fingerprint, usize>>,
+    /// The lints found when writing the baseline, or the lints suppressed otherwise.
+    found: BTreeMap<Fingerprint, usize>,
+}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+struct BaselineHook;
+
+impl EmissionHook for BaselineHook {
+    fn lint_level(&self, emission: &Emission<'_>, level: LevelAndSource) -> Option<LevelAndSource> {
+        if level.0 == Level::Allow {
+            return None;
+        }
+        let fingerprint = fingerprint(emission)?;
+        let mut state = STATE.lock().unwrap();
+        let state = state.as_mut()?;
+
+        if let Some(remaining) = &mut state.remaining {
+            let count = remaining.get_mut(&fingerprint).filter(|count| **count > 0)?;
+            *count -= 1;
+            *state.found.entry(fingerprint).or_default() += 1;
+            Some((Level::Allow, level.1))
+        } else {
+            *state.found.entry(fingerprint).or_default() += 1;
+            None
+        }
+    }
+}
+
+fn fingerprint(emission: &Emission<'_>) -> Option<Fingerprint> {
+    // lints in macro expansions are identified by the macro call
+    let span = emission.span.source_callsite();
+    let source_map = emission.sess.source_map();
+    let FileName::Real(name) = source_map.span_to_filename(span) else {
+        return None;
+    };
+    Some(Fingerprint {
+        lint: emission.lint.name_lower(),
+        file: normalize_path(name.local_path()?),
+        item: emission.item_path.clone(),
+        snippet: normalize_snippet(&source_map.span_to_snippet(span).ok()?),
+    })
+}
+
+fn normalize_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Collapses the whitespace of `snippet`, so that formatting the code doesn't change the
+/// fingerprints, and truncates it to [`MAX_SNIPPET_LEN`] characters.
+fn normalize_snippet(snippet: &str) -> String {
+    let snippet = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+    snippet.chars().take(MAX_SNIPPET_LEN).collect()
+}
+
+/// Registers the emission hook recording or suppressing the lints of the baseline. This must be
+/// called after the other emission hooks are registered, so that the lints allowed by them aren't
+/// recorded.
+pub fn register(sess: &Session, mode: BaselineMode) {
+    let state = match mode {
+        BaselineMode::Write(dir) => State {
+            dir: Some(dir),
+            remaining: None,
+            found: BTreeMap::new(),
+        },
+        BaselineMode::Suppress(path, dir) => match read_baseline(&path) {
+            Ok(baseline) => State {
+                dir,
+                remaining: Some(baseline),
+                found: BTreeMap::new(),
+            },
+            Err(e) => {
+                sess.err(&format!("error reading the baseline `{}`: {}", path.display(), e));
+                return;
+            },
+        },
+    };
+    *STATE.lock().unwrap() = Some(state);
+    register_emission_hook(Box::new(BaselineHook));
+}
+
+/// Writes the fragment of the crate, once all the lints are emitted.
+pub fn finish(sess: &Session) {
+    let Some(state) = STATE.lock().unwrap().take() else {
+        return;
+    };
+    if state.remaining.is_some() {
+        let suppressed: usize = state.found.values().sum();
+        if suppressed > 0 {
+            sess.note_without_error(&format!(
+                "{} Clippy lint{} suppressed by the baseline",
+                suppressed,
+                if suppressed == 1 { " was" } else { "s were" }
+            ));
+        }
+    }
+
+    let Some(dir) = state.dir else {
+        return;
+    };
+    let files = sess
+        .source_map()
+        .files()
+        .iter()
+        .filter(|file| !file.is_imported())
+        .filter_map(|file| match &file.name {
+            FileName::Real(name) => name.local_path().map(normalize_path),
+            _ => None,
+        })
+        .collect();
+    let fragment = Fragment {
+        root: env::current_dir().unwrap_or_default(),
+        files,
+        entries: to_entries(state.found),
+    };
+    let path = dir.join(format!(
+        "{}-{}.json",
+        sess.opts.crate_name.as_deref().unwrap_or("crate"),
+        process::id()
+    ));
+    if let Err(e) = fs::write(&path, serde_json::to_string(&fragment).unwrap()) {
+        sess.err(&format!("error writing `{}`: {}", path.display(), e));
+    }
+}
+
+fn to_entries(counts: BTreeMap<Fingerprint, usize>) -> Vec<Entry> {
+    counts
+        .into_iter()
+        .map(|(fingerprint, count)| Entry { fingerprint, count })
+        .collect()
+}
+
+fn read_baseline(path: &Path) -> Result<BTreeMap<Fingerprint, usize>, String> {
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let baseline: Baseline = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+    let mut counts = BTreeMap::new();
+    for entry in baseline.entries {
+        *counts.entry(entry.fingerprint).or_default() += entry.count;
+    }
+    Ok(counts)
+}
+
+fn read_fragments(dir: &Path) -> Result<Vec<Fragment>, String> {
+    let mut fragments = Vec::new();
+    for file in fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let path = file.map_err(|e| e.to_string())?.path();
+        let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        fragments.push(serde_json::from_str(&content).map_err(|e| format!("`{}`: {}", path.display(), e))?);
+    }
+    Ok(fragments)
+}
+
+/// Merges the counts of the entries of `fragments`. The same file can be linted in several crates,
+/// like a library and its unit tests, so the counts are not added up.
+fn merge_fragments(fragments: &mut [Fragment]) -> BTreeMap<Fingerprint, usize> {
+    let mut counts = BTreeMap::new();
+    for entry in fragments.iter_mut().flat_map(|fragment| fragment.entries.drain(..)) {
+        let count = counts.entry(entry.fingerprint).or_default();
+        *count = entry.count.max(*count);
+    }
+    counts
+}
+
+fn handler() -> Handler {
+    let fallback_bundle = rustc_errors::fallback_fluent_bundle(rustc_errors::DEFAULT_LOCALE_RESOURCES, false);
+    Handler::with_tty_emitter(ColorConfig::Auto, true, None, None, None, fallback_bundle)
+}
+
+/// Writes the baseline at `path` from the fragments in `dir`. Returns whether it succeeded.
+pub fn merge(dir: &Path, path: &Path) -> bool {
+    rustc_span::create_default_session_globals_then(|| {
+        let handler = handler();
+        let written = read_fragments(dir).and_then(|mut fragments| {
+            let counts = merge_fragments(&mut fragments);
+            let baseline = Baseline {
+                entries: to_entries(counts),
+            };
+            fs::write(path, serde_json::to_string_pretty(&baseline).unwrap() + "\n").map_err(|e| e.to_string())?;
+            Ok(baseline.entries.iter().map(|entry| entry.count).sum::<usize>())
+        });
+        match written {
+            Ok(count) => handler.note_without_error(&format!(
+                "wrote {} Clippy lint{} to the baseline `{}`",
+                count,
+                if count == 1 { "" } else { "s" },
+                path.display()
+            )),
+            Err(e) => {
+                handler.err(&format!("error writing the baseline `{}`: {}", path.display(), e));
+            },
+        }
+        handler.has_errors().is_none()
+    })
+}
+
+/// Reports the entries of the baseline at `path` that weren't suppressed by any of the crates
+/// whose fragments are in `dir`. Returns whether no error occurred.
+///
+/// The entries of the files that weren't linted, because Cargo didn't need to check them again,
+/// are only reported if the file doesn't exist anymore.
+pub fn check(dir: &Path, path: &Path) -> bool {
+    rustc_span::create_default_session_globals_then(|| {
+        let handler = handler();
+        let stale = read_fragments(dir).and_then(|mut fragments| {
+            let baseline = read_baseline(path)?;
+            let suppressed = merge_fragments(&mut fragments);
+            let files: BTreeSet<_> = fragments.iter().flat_map(|fragment| &fragment.files).collect();
+            let checked = |file: &String| {
+                files.contains(file)
+                    || (!fragments.is_empty() && fragments.iter().all(|fragment| !fragment.root.join(file).exists()))
+            };
+            Ok(baseline
+                .into_iter()
+                .filter_map(|(fingerprint, count)| {
+                    let suppressed = suppressed.get(&fingerprint).copied().unwrap_or(0);
+                    if count > suppressed && checked(&fingerprint.file) {
+                        Some((fingerprint, count - suppressed))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>())
+        });
+
+        match stale {
+            Ok(stale) if !stale.is_empty() => {
+                let mut diag = handler.struct_warn(&format!(
+                    "{} entr{} of the baseline `{}` no longer occur{}",
+                    stale.len(),
+                    if stale.len() == 1 { "y" } else { "ies" },
+                    path.display(),
+                    if stale.len() == 1 { "s" } else { "" }
+                ));
+                for (fingerprint, count) in stale {
+                    let mut note = format!("`{}` in `{}`", fingerprint.lint, fingerprint.file);
+                    if let Some(item) = fingerprint.item {
+                        note.push_str(&format!(", in `{}`", item));
+                    }
+                    note.push_str(&format!(": `{}`", fingerprint.snippet));
+                    if count > 1 {
+                        note.push_str(&format!(" ({} times)", count));
+                    }
+                    diag.note(&note);
+                }
+                diag.help(&format!(
+                    "remove them, or run `cargo clippy --write-baseline {}` to write the baseline again",
+                    path.display()
+                ));
+                diag.emit();
+            },
+            Ok(_) => {},
+            Err(e) => {
+                handler.err(&format!("error checking the baseline `{}`: {}", path.display(), e));
+            },
+        }
+        handler.has_errors().is_none()
+    })
+}
@@ -0,0 +1,112 @@
+//! Catches the panics of the lint passes, see `cargo clippy --catch-panics`.
+//!
+//! A lint pass that panics is disabled for the rest of the crate, and the panic is reported as a
+//! warning, so that the other lints still run and the build doesn't fail.
+
+use crate::utils::pass_hooks::{wrap_passes, PassHook};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_lint::{EarlyContext, LateContext, LintContext, LintStore};
+use rustc_session::Session;
+use rustc_span::Span;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::{Cell, RefCell};
+use std::env;
+use std::panic::{self, AssertUnwindSafe, PanicInfo};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+thread_local! {
+    /// Whether a lint pass is being run by [`CatchHook`].
+    static CATCHING: Cell<bool> = Cell::new(false);
+    /// The description of the last panic caught, written by [`catch`].
+    static CAUGHT: RefCell<Option<String>> = RefCell::new(None);
+}
+
+#[derive(Default)]
+struct CatchHook {
+    /// The lint passes that panicked.
+    disabled: Mutex<FxHashSet<&'static str>>,
+}
+
+impl CatchHook {
+    fn run(
+        &self,
+        sess: &Session,
+        span: Option<Span>,
+        pass: &'static str,
+        method: &'static str,
+        call: &mut dyn FnMut(),
+    ) {
+        if self.disabled.lock().unwrap().contains(pass) {
+            return;
+        }
+        let previous = CATCHING.with(|catching| catching.replace(true));
+        let result = panic::catch_unwind(AssertUnwindSafe(call));
+        CATCHING.with(|catching| catching.set(previous));
+        if result.is_ok() {
+            return;
+        }
+
+        self.disabled.lock().unwrap().insert(pass);
+        let msg = format!(
+            "the lint pass `{}` panicked in `{}`, its lints are disabled for the rest of the crate",
+            pass, method
+        );
+        let mut diag = match span {
+            Some(span) => sess.struct_span_warn(span, &msg),
+            None => sess.struct_warn(&msg),
+        };
+        if let Some(caught) = CAUGHT.with(RefCell::take) {
+            diag.note(&caught);
+        }
+        diag.help("this is a bug in Clippy, please report it at https://github.com/rust-lang/rust-clippy/issues/new");
+        diag.emit();
+    }
+}
+
+impl PassHook for CatchHook {
+    fn around(&self, _: &'static str, _: &'static str, call: &mut dyn FnMut()) {
+        // the early and late passes go through the other methods
+        call();
+    }
+
+    fn around_early(&self, cx: &EarlyContext<'_>, pass: &'static str, method: &'static str, call: &mut dyn FnMut()) {
+        self.run(cx.sess(), None, pass, method, call);
+    }
+
+    fn around_late(&self, cx: &LateContext<'_>, pass: &'static str, method: &'static str, call: &mut dyn FnMut()) {
+        let span = cx.tcx.hir().opt_span(cx.last_node_with_lint_attrs);
+        self.run(cx.sess(), span, pass, method, call);
+    }
+}
+
+/// Wraps the lint passes registered in `store` to catch their panics. This must be called after all
+/// the lint passes are registered.
+pub fn register(store: &mut LintStore) {
+    let hook: Arc<dyn PassHook> = Arc::new(CatchHook::default());
+    wrap_passes(store, &hook);
+}
+
+/// Records the panic described by `info` if it is caught by a lint pass wrapped by [`register`],
+/// and returns whether it is, in which case it is reported as a warning instead of an ICE.
+pub fn catch(info: &PanicInfo<'_>) -> bool {
+    if !CATCHING.with(Cell::get) {
+        return false;
+    }
+    let mut caught = info.to_string();
+    let backtrace = Backtrace::capture();
+    if backtrace.status() == BacktraceStatus::Captured {
+        caught.push_str(&format!("\nstack backtrace:\n{}", backtrace));
+    }
+    if let Some(dir) = env::var_os("CLIPPY_ICE_DIR") {
+        match super::ice::write_bundle(Path::new(&dir), &info.to_string()) {
+            Ok(bundle) => caught.push_str(&format!(
+                "\nthe bundle of the panic was written to `{}`",
+                bundle.display()
+            )),
+            Err(e) => caught.push_str(&format!("\ncould not write the bundle of the panic: {}", e)),
+        }
+    }
+    CAUGHT.with(|cell| *cell.borrow_mut() = Some(caught));
+    true
+}
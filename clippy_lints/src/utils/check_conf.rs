@@ -0,0 +1,106 @@
+//! Checks a configuration file without compiling anything, see `cargo clippy --check-config`.
+
+use crate::utils::conf::{self, format_error, parse_config_field_doc, TryConf};
+use crate::Conf;
+use itertools::Itertools;
+use rustc_errors::registry::Registry;
+use rustc_errors::{ColorConfig, Handler};
+use rustc_lint::{Level, LintId, LintStore};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Checks the configuration file at `path`, or the one found from the current directory, and
+/// reports the problems found to stderr. Returns whether no error was found.
+pub fn check(path: Option<&Path>) -> bool {
+    rustc_span::create_default_session_globals_then(|| {
+        let fallback_bundle = rustc_errors::fallback_fluent_bundle(rustc_errors::DEFAULT_LOCALE_RESOURCES, false);
+        let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, None, None, None, fallback_bundle);
+
+        let path = match path.map_or_else(conf::lookup_conf_file, |path| Ok(Some(PathBuf::from(path)))) {
+            Ok(Some(path)) => path,
+            Ok(None) => {
+                handler.err("no Clippy configuration file found in the current directory or its parents");
+                return false;
+            },
+            Err(error) => {
+                handler.err(&format!("error finding Clippy's configuration file: {}", error));
+                return false;
+            },
+        };
+
+        let TryConf { conf, errors, warnings } = conf::read(&path);
+        for error in errors {
+            handler.err(&format!(
+                "error reading Clippy's configuration file `{}`: {}",
+                path.display(),
+                format_error(error)
+            ));
+        }
+        for warning in warnings {
+            handler.warn(&format!(
+                "error reading Clippy's configuration file `{}`: {}",
+                path.display(),
+                format_error(warning)
+            ));
+        }
+
+        let mut store = LintStore::new();
+        crate::register_lints(&mut store);
+        for (message, help) in crate::unknown_conf_lints(&store, &conf) {
+            let mut diag = handler.struct_warn(&message);
+            if let Some(help) = help {
+                diag.help(&help);
+            }
+            diag.emit();
+        }
+        check_disabled_lints(&handler, &store, &conf, &path);
+
+        handler.print_error_count(&Registry::new(&[]));
+        handler.has_errors().is_none()
+    })
+}
+
+/// Warns about the keys set in the configuration file whose lints are all allowed, either by
+/// default or by the `[lints]` table. Lint attributes and command line flags can't be taken into
+/// account without compiling the crate.
+fn check_disabled_lints(handler: &Handler, store: &LintStore, conf: &Conf, path: &Path) {
+    let Some(table) = fs::read_to_string(path)
+        .ok()
+        .and_then(|content| toml::from_str::<toml::value::Table>(&content).ok())
+    else {
+        return;
+    };
+    let levels = crate::resolve_conf_lint_levels(store, &conf.lints);
+
+    for field in conf::fields() {
+        let key = field.key();
+        if !table.contains_key(&key) {
+            continue;
+        }
+        let Some((lints, _)) = parse_config_field_doc(field.doc) else {
+            continue;
+        };
+        let lints: Vec<_> = lints
+            .iter()
+            .filter_map(|lint| store.find_lints(&format!("clippy::{}", lint)).ok())
+            .flatten()
+            .collect();
+        let allowed = |lint: &LintId| levels.get(lint).copied().unwrap_or(lint.lint.default_level) == Level::Allow;
+        if !lints.is_empty() && lints.iter().all(allowed) {
+            handler
+                .struct_warn(&format!(
+                    "`{}` is set, but none of the lints it configures are enabled",
+                    key
+                ))
+                .note(&format!(
+                    "it configures {}",
+                    lints
+                        .iter()
+                        .map(|lint| format!("`{}`", lint.lint.name_lower()))
+                        .join(", ")
+                ))
+                .help("enable them in the `[lints]` table, with lint attributes or on the command line")
+                .emit();
+        }
+    }
+}
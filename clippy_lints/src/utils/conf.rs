@@ -2,11 +2,16 @@
 
 #![allow(clippy::module_name_repetitions)]
 
+use clippy_utils::str_utils::glob_match;
+use rustc_data_structures::fx::FxHashSet;
 use serde::de::{Deserializer, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
 use serde::Deserialize;
+use serde_json::json;
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Mutex;
 use std::{cmp, env, fmt, fs, io, iter};
 
 #[rustfmt::skip]
@@ -44,7 +49,13 @@ pub struct Rename {
 #[serde(untagged)]
 pub enum DisallowedMethod {
     Simple(String),
-    WithReason { path: String, reason: Option<String> },
+    WithReason {
+        path: String,
+        reason: Option<String>,
+        /// The method is only disallowed when all of these match the arguments of the call.
+        #[serde(default)]
+        args: Vec<DisallowedArg>,
+    },
 }
 
 impl DisallowedMethod {
@@ -53,6 +64,58 @@ impl DisallowedMethod {
 
         path
     }
+
+    pub fn args(&self) -> &[DisallowedArg] {
+        match self {
+            Self::Simple(_) => &[],
+            Self::WithReason { args, .. } => args,
+        }
+    }
+}
+
+/// A constraint on an argument of a disallowed method. The index of the argument counts the
+/// receiver of a method as the first argument.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum DisallowedArg {
+    /// The argument is a literal or a constant equal to the value.
+    Value { index: usize, value: ArgValue },
+    /// The argument is the path of the constant, static or enum variant.
+    Path { index: usize, path: String },
+}
+
+impl DisallowedArg {
+    pub fn index(&self) -> usize {
+        let (Self::Value { index, .. } | Self::Path { index, .. }) = self;
+
+        *index
+    }
+}
+
+/// The value of a [`DisallowedArg`]. A string can contain `*` and `?` wildcards, and also
+/// matches a `char`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ArgValue {
+    Bool(bool),
+    Int(i64),
+    Str(String),
+}
+
+/// A single disallowed macro, used by the `DISALLOWED_MACROS` lint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DisallowedMacro {
+    Simple(String),
+    WithReason { path: String, reason: Option<String> },
+}
+
+impl DisallowedMacro {
+    pub fn path(&self) -> &str {
+        let (Self::Simple(path) | Self::WithReason { path, .. }) = self;
+
+        path
+    }
 }
 
 /// A single disallowed type, used by the `DISALLOWED_TYPES` lint.
@@ -63,6 +126,133 @@ pub enum DisallowedType {
     WithReason { path: String, reason: Option<String> },
 }
 
+/// A single disallowed crate, used by the `DISALLOWED_CRATES` lint. The path is the name of the
+/// crate, or a glob pattern matching it.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DisallowedCrate {
+    Simple(String),
+    WithReason { path: String, reason: Option<String> },
+}
+
+impl DisallowedCrate {
+    pub fn path(&self) -> &str {
+        let (Self::Simple(path) | Self::WithReason { path, .. }) = self;
+
+        path
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Simple(_) => None,
+            Self::WithReason { reason, .. } => reason.as_deref(),
+        }
+    }
+}
+
+/// A single disallowed module, used by the `DISALLOWED_MODULES` lint. The path is the path of the
+/// module, or a glob pattern matching it.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DisallowedModule {
+    Simple(String),
+    WithReason { path: String, reason: Option<String> },
+}
+
+impl DisallowedModule {
+    pub fn path(&self) -> &str {
+        let (Self::Simple(path) | Self::WithReason { path, .. }) = self;
+
+        path
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Simple(_) => None,
+            Self::WithReason { reason, .. } => reason.as_deref(),
+        }
+    }
+}
+
+/// A single disallowed trait implementation, used by the `DISALLOWED_TRAIT_IMPLS` lint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisallowedTraitImpl {
+    /// The path of the trait, or a glob pattern matching it.
+    #[serde(rename = "trait")]
+    pub trait_path: String,
+    /// The path of the types the trait must not be implemented for, or a glob pattern matching
+    /// them. The trait must not be implemented at all if this is `None`.
+    #[serde(rename = "for")]
+    pub self_ty: Option<String>,
+    pub reason: Option<String>,
+}
+
+/// A layer of the project, used by the `LAYER_VIOLATION` lint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Layer {
+    pub name: String,
+    /// The paths of the crates and modules in the layer, or glob patterns matching them.
+    pub paths: Vec<String>,
+}
+
+/// A lint level set in the `[lints]` table of the configuration.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+    Forbid,
+}
+
+impl LintLevel {
+    pub fn level(self) -> rustc_lint::Level {
+        match self {
+            Self::Allow => rustc_lint::Level::Allow,
+            Self::Warn => rustc_lint::Level::Warn,
+            Self::Deny => rustc_lint::Level::Deny,
+            Self::Forbid => rustc_lint::Level::Forbid,
+        }
+    }
+}
+
+/// Lint levels and configuration values that only apply to some files, set in an `[[overrides]]`
+/// table of the configuration.
+#[derive(Clone, Debug, Deserialize)]
+pub struct PathOverride {
+    /// Glob patterns matching the files the override applies to. Once the configuration is read,
+    /// these are absolute.
+    pub paths: Vec<String>,
+    #[serde(default)]
+    pub lints: BTreeMap<String, LintLevel>,
+    /// The configuration values set by the override. Once the configuration is read, this holds
+    /// all the values used for the matching files.
+    #[serde(flatten)]
+    pub values: toml::value::Table,
+}
+
+impl PathOverride {
+    /// Returns whether the override applies to the file at `path`.
+    pub fn matches(&self, path: &Path) -> bool {
+        let path = path.to_string_lossy();
+        self.paths.iter().any(|pattern| glob_match(pattern, &path, "/"))
+    }
+
+    /// Returns the configuration used for the matching files, if the override sets any
+    /// configuration value.
+    pub fn conf(&self) -> Option<Conf> {
+        if self.values.is_empty() {
+            return None;
+        }
+        let mut conf = TryConf::deserialize(toml::Value::Table(self.values.clone())).ok()?.conf;
+        extend_vec_if_indicator_present(&mut conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
+        extend_vec_if_indicator_present(&mut conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
+        Some(conf)
+    }
+}
+
 /// Conf with parse errors
 #[derive(Default)]
 pub struct TryConf {
@@ -72,10 +262,10 @@ pub struct TryConf {
 }
 
 impl TryConf {
-    fn from_error(error: impl Error + 'static) -> Self {
+    fn from_error(error: impl Into<Box<dyn Error>>) -> Self {
         Self {
             conf: Conf::default(),
-            errors: vec![Box::new(error)],
+            errors: vec![error.into()],
             warnings: vec![],
         }
     }
@@ -96,6 +286,340 @@ fn conf_error(s: impl Into<String>) -> Box<dyn Error> {
     Box::new(ConfError(s.into()))
 }
 
+/// The type of a configuration value, described as a JSON Schema.
+pub trait ConfSchema {
+    fn schema() -> serde_json::Value;
+
+    /// Converts the value to JSON, if it can be written in the configuration file as is.
+    fn to_json(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+impl ConfSchema for bool {
+    fn schema() -> serde_json::Value {
+        json!({ "type": "boolean" })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!(self))
+    }
+}
+
+impl ConfSchema for u64 {
+    fn schema() -> serde_json::Value {
+        json!({ "type": "integer", "minimum": 0 })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!(self))
+    }
+}
+
+impl ConfSchema for String {
+    fn schema() -> serde_json::Value {
+        json!({ "type": "string" })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!(self))
+    }
+}
+
+impl<T: ConfSchema> ConfSchema for Option<T> {
+    fn schema() -> serde_json::Value {
+        T::schema()
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        // `None` values are left out of the configuration file
+        self.as_ref().and_then(T::to_json)
+    }
+}
+
+impl<T: ConfSchema> ConfSchema for Vec<T> {
+    fn schema() -> serde_json::Value {
+        json!({ "type": "array", "items": T::schema() })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        self.iter().map(T::to_json).collect()
+    }
+}
+
+impl<T: ConfSchema> ConfSchema for FxHashSet<T> {
+    fn schema() -> serde_json::Value {
+        json!({ "type": "array", "items": T::schema(), "uniqueItems": true })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        self.iter().map(T::to_json).collect()
+    }
+}
+
+impl<T: ConfSchema> ConfSchema for BTreeMap<String, T> {
+    fn schema() -> serde_json::Value {
+        json!({ "type": "object", "additionalProperties": T::schema() })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        self.iter()
+            .map(|(key, value)| Some((key.clone(), value.to_json()?)))
+            .collect::<Option<serde_json::Map<_, _>>>()
+            .map(serde_json::Value::Object)
+    }
+}
+
+/// The schema of a path with an optional reason, like [`DisallowedMethod`] and [`DisallowedType`].
+fn path_with_reason_schema() -> serde_json::Value {
+    json!({
+        "anyOf": [
+            { "type": "string" },
+            {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string" },
+                    "reason": { "type": "string" },
+                },
+                "required": ["path"],
+                "additionalProperties": false,
+            },
+        ]
+    })
+}
+
+impl ConfSchema for DisallowedMethod {
+    fn schema() -> serde_json::Value {
+        let mut schema = path_with_reason_schema();
+        schema["anyOf"][1]["properties"]["args"] = Vec::<DisallowedArg>::schema();
+        schema
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        let (path, reason) = match self {
+            Self::Simple(path) => (path, None),
+            Self::WithReason { path, reason, .. } => (path, reason.as_ref()),
+        };
+        if reason.is_none() && self.args().is_empty() {
+            return Some(json!(path));
+        }
+        let mut value = json!({ "path": path });
+        if let Some(reason) = reason {
+            value["reason"] = json!(reason);
+        }
+        if !self.args().is_empty() {
+            let args: Option<Vec<_>> = self.args().iter().map(DisallowedArg::to_json).collect();
+            value["args"] = json!(args?);
+        }
+        Some(value)
+    }
+}
+
+impl ConfSchema for DisallowedArg {
+    fn schema() -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "index": { "type": "integer", "minimum": 0 },
+                "value": { "type": ["boolean", "integer", "string"] },
+                "path": { "type": "string" },
+            },
+            "required": ["index"],
+            "oneOf": [
+                { "required": ["value"] },
+                { "required": ["path"] },
+            ],
+            "additionalProperties": false,
+        })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(match self {
+            Self::Value { index, value } => {
+                let value = match value {
+                    ArgValue::Bool(value) => json!(value),
+                    ArgValue::Int(value) => json!(value),
+                    ArgValue::Str(value) => json!(value),
+                };
+                json!({ "index": index, "value": value })
+            },
+            Self::Path { index, path } => json!({ "index": index, "path": path }),
+        })
+    }
+}
+
+impl ConfSchema for DisallowedMacro {
+    fn schema() -> serde_json::Value {
+        path_with_reason_schema()
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(match self {
+            Self::Simple(path) | Self::WithReason { path, reason: None } => json!(path),
+            Self::WithReason {
+                path,
+                reason: Some(reason),
+            } => json!({ "path": path, "reason": reason }),
+        })
+    }
+}
+
+impl ConfSchema for DisallowedType {
+    fn schema() -> serde_json::Value {
+        path_with_reason_schema()
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(match self {
+            Self::Simple(path) | Self::WithReason { path, reason: None } => json!(path),
+            Self::WithReason {
+                path,
+                reason: Some(reason),
+            } => json!({ "path": path, "reason": reason }),
+        })
+    }
+}
+
+impl ConfSchema for DisallowedCrate {
+    fn schema() -> serde_json::Value {
+        path_with_reason_schema()
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(match self.reason() {
+            None => json!(self.path()),
+            Some(reason) => json!({ "path": self.path(), "reason": reason }),
+        })
+    }
+}
+
+impl ConfSchema for DisallowedModule {
+    fn schema() -> serde_json::Value {
+        path_with_reason_schema()
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(match self.reason() {
+            None => json!(self.path()),
+            Some(reason) => json!({ "path": self.path(), "reason": reason }),
+        })
+    }
+}
+
+impl ConfSchema for DisallowedTraitImpl {
+    fn schema() -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "trait": { "type": "string" },
+                "for": { "type": "string" },
+                "reason": { "type": "string" },
+            },
+            "required": ["trait"],
+            "additionalProperties": false,
+        })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        let mut value = json!({ "trait": self.trait_path });
+        if let Some(self_ty) = &self.self_ty {
+            value["for"] = json!(self_ty);
+        }
+        if let Some(reason) = &self.reason {
+            value["reason"] = json!(reason);
+        }
+        Some(value)
+    }
+}
+
+impl ConfSchema for Layer {
+    fn schema() -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "paths": { "type": "array", "items": { "type": "string" } },
+            },
+            "required": ["name", "paths"],
+            "additionalProperties": false,
+        })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "name": self.name, "paths": self.paths }))
+    }
+}
+
+impl ConfSchema for Rename {
+    fn schema() -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": { "type": "string" },
+                "rename": { "type": "string" },
+            },
+            "required": ["path", "rename"],
+            "additionalProperties": false,
+        })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "path": self.path, "rename": self.rename }))
+    }
+}
+
+impl ConfSchema for LintLevel {
+    fn schema() -> serde_json::Value {
+        json!({ "enum": ["allow", "warn", "deny", "forbid"] })
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        let level = match self {
+            Self::Allow => "allow",
+            Self::Warn => "warn",
+            Self::Deny => "deny",
+            Self::Forbid => "forbid",
+        };
+        Some(json!(level))
+    }
+}
+
+impl ConfSchema for PathOverride {
+    fn schema() -> serde_json::Value {
+        // the other properties are configuration values, checked when the configuration is read
+        json!({
+            "type": "object",
+            "properties": {
+                "paths": Vec::<String>::schema(),
+                "lints": BTreeMap::<String, LintLevel>::schema(),
+            },
+            "required": ["paths"],
+        })
+    }
+}
+
+fn format_value<T: ConfSchema + fmt::Debug>(value: &T) -> String {
+    value
+        .to_json()
+        .map_or_else(|| format!("{:?}", value), |value| value.to_string())
+}
+
+/// A field of [`Conf`], as declared in `define_Conf!`.
+pub struct ConfField {
+    pub name: &'static str,
+    pub doc: &'static str,
+    pub deprecation_reason: Option<&'static str>,
+    pub schema: serde_json::Value,
+    pub default: Option<serde_json::Value>,
+}
+
+impl ConfField {
+    /// The name of the field in the configuration file.
+    pub fn key(&self) -> String {
+        self.name.replace('_', "-")
+    }
+}
+
 macro_rules! define_Conf {
     ($(
         $(#[doc = $doc:literal])+
@@ -117,6 +641,34 @@ macro_rules! define_Conf {
             }
         }
 
+        macro_rules! wrap_option {
+            () => (None);
+            ($x:literal) => (Some($x));
+        }
+
+        impl Conf {
+            /// Returns the value of each field, in the order of [`fields`], written as in the
+            /// configuration file when possible.
+            pub fn values(&self) -> Vec<String> {
+                vec![$(format_value(&self.$name),)*]
+            }
+        }
+
+        /// Returns the fields of the configuration.
+        pub fn fields() -> Vec<ConfField> {
+            vec![
+                $(
+                    ConfField {
+                        name: stringify!($name),
+                        doc: concat!($($doc, '\n',)*),
+                        deprecation_reason: wrap_option!($($dep)?),
+                        schema: <$ty as ConfSchema>::schema(),
+                        default: ConfSchema::to_json(&defaults::$name()),
+                    },
+                )*
+            ]
+        }
+
         impl<'de> Deserialize<'de> for TryConf {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
                 deserializer.deserialize_map(ConfVisitor)
@@ -126,7 +678,7 @@ macro_rules! define_Conf {
         #[derive(Deserialize)]
         #[serde(field_identifier, rename_all = "kebab-case")]
         #[allow(non_camel_case_types)]
-        enum Field { $($name,)* third_party, }
+        enum Field { $($name,)* third_party, inherit, }
 
         struct ConfVisitor;
 
@@ -141,9 +693,9 @@ macro_rules! define_Conf {
                 let mut errors = Vec::new();
                 let mut warnings = Vec::new();
                 $(let mut $name = None;)*
-                // could get `Field` here directly, but get `str` first for diagnostics
-                while let Some(name) = map.next_key::<&str>()? {
-                    match Field::deserialize(name.into_deserializer())? {
+                // could get `Field` here directly, but get `String` first for diagnostics
+                while let Some(name) = map.next_key::<String>()? {
+                    match Field::deserialize(name.as_str().into_deserializer())? {
                         $(Field::$name => {
                             $(warnings.push(conf_error(format!("deprecated field `{}`. {}", name, $dep)));)?
                             match map.next_value() {
@@ -166,7 +718,11 @@ macro_rules! define_Conf {
                             }
                         })*
                         // white-listed; ignore
-                        Field::third_party => drop(map.next_value::<IgnoredAny>())
+                        Field::third_party => drop(map.next_value::<IgnoredAny>()),
+                        // already resolved by `read`, only check the type here
+                        Field::inherit => if let Err(e) = map.next_value::<bool>() {
+                            errors.push(conf_error(e.to_string()));
+                        },
                     }
                 }
                 let conf = Conf { $($name: $name.unwrap_or_else(defaults::$name),)* };
@@ -178,11 +734,6 @@ macro_rules! define_Conf {
         pub mod metadata {
             use crate::utils::internal_lints::metadata_collector::ClippyConfiguration;
 
-            macro_rules! wrap_option {
-                () => (None);
-                ($x:literal) => (Some($x));
-            }
-
             pub(crate) fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
                 vec![
                     $(
@@ -205,7 +756,7 @@ macro_rules! define_Conf {
 }
 
 define_Conf! {
-    /// Lint: Arithmetic.
+    /// Lint: ARITHMETIC_SIDE_EFFECTS.
     ///
     /// Suppress checking of the passed type names.
     (arithmetic_side_effects_allowed: rustc_data_structures::fx::FxHashSet<String> = <_>::default()),
@@ -213,7 +764,7 @@ define_Conf! {
     ///
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED.
+    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, INCOMPATIBLE_MSRV.
     ///
     /// The minimum rust version that the project supports
     (msrv: Option<String> = None),
@@ -283,7 +834,7 @@ define_Conf! {
     ///
     /// The maximum size (in bytes) to consider a `Copy` type for passing by value instead of by reference.
     (trivial_copy_size_limit: Option<u64> = None),
-    /// Lint: LARGE_TYPE_PASS_BY_MOVE.
+    /// Lint: LARGE_TYPES_PASSED_BY_VALUE.
     ///
     /// The minimum size (in bytes) to consider a type for passing by reference instead of by value.
     (pass_by_value_size_limit: u64 = 256),
@@ -317,12 +868,36 @@ define_Conf! {
     (warn_on_all_wildcard_imports: bool = false),
     /// Lint: DISALLOWED_METHODS.
     ///
-    /// The list of disallowed methods, written as fully qualified paths.
+    /// The list of disallowed methods, written as fully qualified paths. A method can be disallowed only when some of
+    /// its arguments match the `args` of the entry.
     (disallowed_methods: Vec<crate::utils::conf::DisallowedMethod> = Vec::new()),
+    /// Lint: DISALLOWED_MACROS.
+    ///
+    /// The list of disallowed macros, written as fully qualified paths.
+    (disallowed_macros: Vec<crate::utils::conf::DisallowedMacro> = Vec::new()),
     /// Lint: DISALLOWED_TYPES.
     ///
     /// The list of disallowed types, written as fully qualified paths.
     (disallowed_types: Vec<crate::utils::conf::DisallowedType> = Vec::new()),
+    /// Lint: DISALLOWED_CRATES.
+    ///
+    /// The list of disallowed crates, written as crate names or glob patterns.
+    (disallowed_crates: Vec<crate::utils::conf::DisallowedCrate> = Vec::new()),
+    /// Lint: DISALLOWED_MODULES.
+    ///
+    /// The list of disallowed modules, written as fully qualified paths or glob patterns.
+    (disallowed_modules: Vec<crate::utils::conf::DisallowedModule> = Vec::new()),
+    /// Lint: DISALLOWED_TRAIT_IMPLS.
+    ///
+    /// The list of disallowed trait implementations, written as tables with the path of the `trait`, and optionally
+    /// the path of the types it is implemented `for` and a `reason`. The paths can be glob patterns.
+    (disallowed_trait_impls: Vec<crate::utils::conf::DisallowedTraitImpl> = Vec::new()),
+    /// Lint: LAYER_VIOLATION.
+    ///
+    /// The layers of the project, from the top one to the bottom one, written as tables with the `name` of the layer
+    /// and the `paths` of its crates and modules. The paths can be glob patterns. A layer can only use the layers below
+    /// it.
+    (layers: Vec<crate::utils::conf::Layer> = Vec::new()),
     /// Lint: UNREADABLE_LITERAL.
     ///
     /// Should the fraction of a decimal be linted to include separators.
@@ -379,23 +954,130 @@ define_Conf! {
     ///
     /// Whether `dbg!` should be allowed in test functions
     (allow_dbg_in_tests: bool = false),
-    /// Lint: RESULT_LARGE_ERR
+    /// Lint: RESULT_LARGE_ERR.
     ///
     /// The maximum size of the `Err`-variant in a `Result` returned from a function
     (large_error_threshold: u64 = 128),
+    /// Lint: ALL.
+    ///
+    /// The levels of lints and lint groups, applied as if they were passed on the command line. For example:
+    /// * `unwrap_used = "deny"` denies `clippy::unwrap_used`.
+    /// * `pedantic = "warn"` warns on all lints of the `clippy::pedantic` group.
+    ///
+    /// Flags passed on the command line and lint attributes in the code take precedence.
+    (lints: std::collections::BTreeMap<String, crate::utils::conf::LintLevel> = std::collections::BTreeMap::new()),
+    /// Lint: ALL.
+    ///
+    /// Lint levels and configuration values that only apply to the files matching some glob patterns. For example:
+    /// ```toml
+    /// [[overrides]]
+    /// paths = ["tests/**", "benches/**"]
+    /// too-many-lines-threshold = 200
+    /// lints = { unwrap_used = "allow" }
+    /// ```
+    ///
+    /// The patterns are relative to the directory of the configuration file. When several overrides match a file,
+    /// the last one takes precedence.
+    (overrides: Vec<crate::utils::conf::PathOverride> = Vec::new()),
+}
+
+/// This parses the field documentation of the config struct.
+///
+/// ```rust, ignore
+/// parse_config_field_doc(cx, "Lint: LINT_NAME_1, LINT_NAME_2. Papa penguin, papa penguin")
+/// ```
+///
+/// Would yield:
+/// ```rust, ignore
+/// Some(["lint_name_1", "lint_name_2"], "Papa penguin, papa penguin")
+/// ```
+pub(crate) fn parse_config_field_doc(doc_comment: &str) -> Option<(Vec<String>, String)> {
+    const DOC_START: &str = " Lint: ";
+    if_chain! {
+        if doc_comment.starts_with(DOC_START);
+        if let Some(split_pos) = doc_comment.find('.');
+        then {
+            let mut doc_comment = doc_comment.to_string();
+            let mut documentation = doc_comment.split_off(split_pos);
+
+            // Extract lints
+            doc_comment.make_ascii_lowercase();
+            let lints: Vec<String> = doc_comment.split_off(DOC_START.len()).split(", ").map(str::to_string).collect();
+
+            // Format documentation correctly
+            // split off leading `.` from lint name list and indent for correct formatting
+            documentation = documentation.trim_start_matches('.').trim().replace("\n ", "\n    ");
+
+            Some((lints, documentation))
+        } else {
+            None
+        }
+    }
+}
+
+/// Builds the JSON Schema of the configuration file.
+pub fn schema() -> serde_json::Value {
+    let mut properties = serde_json::Map::new();
+    for field in fields() {
+        let key = field.key();
+        let mut schema = field.schema;
+        let (lints, doc) = parse_config_field_doc(field.doc).unwrap_or_default();
+        let mut description = doc;
+        if !lints.is_empty() {
+            description.push_str(&format!("\n\nLints: {}", lints.join(", ")));
+        }
+        if let Some(reason) = field.deprecation_reason {
+            description = format!("Deprecated: {}\n\n{}", reason, description);
+            schema["deprecated"] = json!(true);
+        }
+        schema["description"] = json!(description);
+        if let Some(default) = field.default {
+            schema["default"] = default;
+        }
+        properties.insert(key, schema);
+    }
+    properties.insert(
+        "inherit".to_string(),
+        json!({
+            "type": "boolean",
+            "description": "Whether to extend the configuration file found in the parent directories",
+            "default": false,
+        }),
+    );
+    properties.insert(
+        "third-party".to_string(),
+        json!({ "description": "Ignored by Clippy, reserved for third-party tools" }),
+    );
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Clippy configuration",
+        "description": "The configuration file of Clippy, `clippy.toml` or `.clippy.toml`",
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    })
 }
 
 /// Search for the configuration file.
 pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
-    /// Possible filename to search for.
-    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
-
     // Start looking for a config file in CLIPPY_CONF_DIR, or failing that, CARGO_MANIFEST_DIR.
     // If neither of those exist, use ".".
-    let mut current = env::var_os("CLIPPY_CONF_DIR")
+    let current = env::var_os("CLIPPY_CONF_DIR")
         .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
         .map_or_else(|| PathBuf::from("."), PathBuf::from);
 
+    lookup_conf_file_from(current)
+}
+
+/// The configuration files found next to another one, which are ignored.
+static WARNED_IGNORED_FILES: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// Search for the configuration file in `current` and its ancestors.
+fn lookup_conf_file_from(mut current: PathBuf) -> io::Result<Option<PathBuf>> {
+    /// Possible filename to search for.
+    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
+
     let mut found_config: Option<PathBuf> = None;
 
     loop {
@@ -406,13 +1088,18 @@ pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
                     Err(e) => return Err(e),
                     Ok(md) if md.is_dir() => {},
                     Ok(_) => {
-                        // warn if we happen to find two config files #8323
+                        // warn if we happen to find two config files #8323, once as the file is
+                        // looked up for the lint levels and then for the configuration values
                         if let Some(ref found_config_) = found_config {
-                            eprintln!(
-                                "Using config file `{}`\nWarning: `{}` will be ignored.",
-                                found_config_.display(),
-                                config_file.display(),
-                            );
+                            let mut warned = WARNED_IGNORED_FILES.lock().unwrap();
+                            if !warned.contains(&config_file) {
+                                eprintln!(
+                                    "Using config file `{}`\nWarning: `{}` will be ignored.",
+                                    found_config_.display(),
+                                    config_file.display(),
+                                );
+                                warned.push(config_file);
+                            }
                         } else {
                             found_config = Some(config_file);
                         }
@@ -434,21 +1121,370 @@ pub fn lookup_conf_file() -> io::Result<Option<PathBuf>> {
 
 /// Read the `toml` configuration file.
 ///
+/// If the file sets `inherit = true`, the configuration file of the parent directories is read as
+/// well and the two are merged, see [`merge_tables`]. The values set by the `CLIPPY_CONF_<KEY>`
+/// environment variables and on the command line replace the ones of the files.
+///
 /// In case of error, the function tries to continue as much as possible.
 pub fn read(path: &Path) -> TryConf {
     let content = match fs::read_to_string(path) {
         Err(e) => return TryConf::from_error(e),
         Ok(content) => content,
     };
-    match toml::from_str::<TryConf>(&content) {
-        Ok(mut conf) => {
-            extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
-            extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
+    let (mut table, mut conf) = match toml::from_str::<toml::value::Table>(&content) {
+        Ok(table) if inherits(&table) => read_inherited(path, table),
+        table => (
+            table.unwrap_or_default(),
+            toml::from_str::<TryConf>(&content).unwrap_or_else(TryConf::from_error),
+        ),
+    };
+    let overridden = apply_value_overrides(&mut conf, &mut table);
+    if let Some(dir) = path.parent() {
+        resolve_overrides(&mut conf, table, overridden, dir);
+    }
+    extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
+    extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
+
+    conf
+}
+
+/// Reads the configuration used when there is no configuration file, made of the values set by the
+/// `CLIPPY_CONF_<KEY>` environment variables and on the command line.
+pub fn read_default() -> TryConf {
+    let mut conf = TryConf::default();
+    apply_value_overrides(&mut conf, &mut toml::value::Table::new());
+    extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
+    extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
+
+    conf
+}
+
+fn inherits(table: &toml::value::Table) -> bool {
+    table.get("inherit").and_then(toml::Value::as_bool).unwrap_or(false)
+}
+
+/// Merges `table`, read from `path`, with the configuration files found in the parent
+/// directories, for as long as they set `inherit = true`. Returns the merged table along with the
+/// configuration it holds.
+fn read_inherited(path: &Path, mut table: toml::value::Table) -> (toml::value::Table, TryConf) {
+    let mut errors = Vec::new();
+    let mut current = path.to_path_buf();
+
+    while inherits(&table) {
+        table.remove("inherit");
+
+        let parent = match parent_conf_file(&current) {
+            Ok(Some(parent)) => parent,
+            Ok(None) => {
+                errors.push(conf_error(format!(
+                    "`inherit = true` is set in `{}`, but no configuration file was found in its parent directories",
+                    current.display()
+                )));
+                break;
+            },
+            Err(e) => {
+                errors.push(conf_error(format!(
+                    "error finding the configuration file to inherit from: {}",
+                    e
+                )));
+                break;
+            },
+        };
+
+        let parent_table = match fs::read_to_string(&parent) {
+            Ok(content) => toml::from_str::<toml::value::Table>(&content).map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        match parent_table {
+            Ok(mut parent_table) => {
+                // the paths of the overrides are relative to the file that sets them
+                if let Some(dir) = parent.parent() {
+                    absolutize_override_paths(&mut parent_table, dir);
+                }
+                table = merge_tables(parent_table, table);
+            },
+            Err(e) => {
+                errors.push(conf_error(format!(
+                    "error reading inherited configuration file `{}`: {}",
+                    parent.display(),
+                    e
+                )));
+                break;
+            },
+        }
+        current = parent;
+    }
+
+    let mut conf = TryConf::deserialize(toml::Value::Table(table.clone())).unwrap_or_else(TryConf::from_error);
+    conf.errors.extend(errors);
+    (table, conf)
+}
+
+/// Finds the configuration file inherited by the one at `path`.
+fn parent_conf_file(path: &Path) -> io::Result<Option<PathBuf>> {
+    // `path` is a canonicalized file path, so this is the directory containing the directory of
+    // `path`
+    match path.parent().and_then(Path::parent) {
+        Some(dir) => lookup_conf_file_from(dir.to_path_buf()),
+        None => Ok(None),
+    }
+}
+
+/// Returns the configuration files setting each key of the configuration read from `path`, the
+/// ones inherited from first. A key is only set by several files if its values are merged, see
+/// [`merge_tables`].
+///
+/// Errors are ignored, they are reported by [`read`].
+pub fn value_sources(path: &Path) -> BTreeMap<String, Vec<PathBuf>> {
+    let mut sources: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    let mut values = toml::value::Table::new();
+    let mut current = Some(path.to_path_buf());
+
+    while let Some(path) = current.take() {
+        let Some(table) = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| toml::from_str::<toml::value::Table>(&content).ok())
+        else {
+            break;
+        };
+        for (key, value) in &table {
+            let merged = match values.get(key) {
+                None => true,
+                Some(child) => {
+                    matches!(
+                        (child, value),
+                        (toml::Value::Array(_), toml::Value::Array(_)) | (toml::Value::Table(_), toml::Value::Table(_))
+                    )
+                },
+            };
+            if key != "inherit" && merged {
+                sources.entry(key.clone()).or_default().insert(0, path.clone());
+                values.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+        if inherits(&table) {
+            current = parent_conf_file(&path).ok().flatten();
+        }
+    }
+    sources
+}
+
+/// Makes the paths of the `[[overrides]]` of `table` absolute, relative to `dir`.
+fn absolutize_override_paths(table: &mut toml::value::Table, dir: &Path) {
+    let overrides = table.get_mut("overrides").and_then(toml::Value::as_array_mut);
+    for paths in overrides
+        .into_iter()
+        .flatten()
+        .filter_map(|o| o.get_mut("paths").and_then(toml::Value::as_array_mut))
+    {
+        for path in paths {
+            if let toml::Value::String(path) = path {
+                *path = dir.join(&*path).to_string_lossy().into_owned();
+            }
+        }
+    }
+}
+
+/// Makes the paths of the overrides of `conf` absolute, relative to `dir`, and completes the
+/// values of each override with the ones of `table`, the whole configuration. The `overridden`
+/// values, set outside of the configuration file, take precedence over the ones of the overrides.
+fn resolve_overrides(
+    conf: &mut TryConf,
+    mut table: toml::value::Table,
+    mut overridden: toml::value::Table,
+    dir: &Path,
+) {
+    for key in ["inherit", "lints", "overrides"] {
+        table.remove(key);
+        overridden.remove(key);
+    }
+    for path_override in &mut conf.conf.overrides {
+        for path in &mut path_override.paths {
+            // this leaves the paths made absolute by `read_inherited` untouched
+            *path = dir.join(&*path).to_string_lossy().into_owned();
+        }
+        if path_override.values.is_empty() {
+            continue;
+        }
+        for key in ["inherit", "overrides"] {
+            if path_override.values.contains_key(key) {
+                conf.errors.push(conf_error(format!(
+                    "`{}` can't be set in an `[[overrides]]` table",
+                    key
+                )));
+            }
+        }
+        match TryConf::deserialize(toml::Value::Table(path_override.values.clone())) {
+            Ok(override_conf) => conf.errors.extend(override_conf.errors),
+            Err(e) => conf.errors.push(Box::new(e)),
+        }
+        let mut values = table.clone();
+        values.extend(std::mem::take(&mut path_override.values));
+        values.extend(overridden.clone());
+        path_override.values = values;
+    }
+}
+
+/// The prefix of the environment variables overriding a configuration value, followed by the key
+/// in uppercase with `_` instead of `-`, like `CLIPPY_CONF_TOO_MANY_LINES_THRESHOLD`.
+const ENV_PREFIX: &str = "CLIPPY_CONF_";
+
+/// The values passed on the command line with `--config KEY=VALUE`, see [`set_cli_values`].
+static CLI_VALUES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Sets the values passed on the command line with `--config KEY=VALUE`.
+pub fn set_cli_values(values: Vec<String>) {
+    *CLI_VALUES.lock().unwrap() = values;
+}
+
+/// Returns the environment variable overriding the value of each configuration key.
+pub fn env_vars() -> Vec<String> {
+    fields()
+        .iter()
+        .map(|field| format!("{}{}", ENV_PREFIX, field.name.to_ascii_uppercase()))
+        .collect()
+}
 
-            conf
-        },
-        Err(e) => TryConf::from_error(e),
+/// A configuration value set outside of the configuration file.
+struct ValueOverride {
+    key: String,
+    /// The value, written in TOML.
+    value: String,
+    /// Describes where the value is set, like `` `CLIPPY_CONF_MSRV` ``.
+    source: String,
+}
+
+/// Returns the configuration values set by the `CLIPPY_CONF_<KEY>` environment variables, then the
+/// ones set on the command line, which take precedence.
+fn value_overrides() -> Vec<ValueOverride> {
+    let mut overrides: Vec<_> = env::vars_os()
+        .filter_map(|(name, value)| {
+            let name = name.into_string().ok()?;
+            let key = name.strip_prefix(ENV_PREFIX)?;
+            // `CLIPPY_CONF_DIR` is the directory the configuration file is looked up from
+            if key == "DIR" {
+                return None;
+            }
+            Some(ValueOverride {
+                key: key.to_ascii_lowercase().replace('_', "-"),
+                value: value.into_string().ok()?,
+                source: format!("`{}`", name),
+            })
+        })
+        .collect();
+    overrides.sort_by(|a, b| a.source.cmp(&b.source));
+
+    for value in CLI_VALUES.lock().unwrap().iter() {
+        let (key, toml_value) = value.split_once('=').unwrap_or((value.as_str(), ""));
+        overrides.push(ValueOverride {
+            key: key.trim().to_string(),
+            value: toml_value.to_string(),
+            source: format!("`--config {}`", value),
+        });
+    }
+    overrides
+}
+
+/// Returns where the configuration values set outside of the configuration file are set, by key.
+pub fn value_override_sources() -> BTreeMap<String, String> {
+    value_overrides()
+        .into_iter()
+        .map(|value| (value.key, value.source))
+        .collect()
+}
+
+/// Parses a single TOML value, like `50`, `true` or `"text"`.
+fn parse_toml_value(value: &str) -> Result<toml::Value, String> {
+    let mut table = toml::from_str::<toml::value::Table>(&format!("value = {}", value)).map_err(|e| e.to_string())?;
+    match table.remove("value") {
+        Some(value) if table.is_empty() => Ok(value),
+        _ => Err("expected a single value".to_string()),
+    }
+}
+
+/// Describes the invalid value `source` sets for `key`. The TOML deserializer appends the key to
+/// its errors, which the message already names.
+fn invalid_override(key: &str, source: &str, error: &dyn fmt::Display) -> Box<dyn Error> {
+    let error = error.to_string();
+    let error = error.strip_suffix(&format!(" for key `{}`", key)).unwrap_or(&error);
+    conf_error(format!("invalid value for `{}`, set by {}: {}", key, source, error))
+}
+
+/// Sets the values of [`value_overrides`] in `table`, the whole configuration, and in `conf`, read
+/// from it. Each value is checked with the deserializers of the configuration file. Returns the
+/// values set.
+fn apply_value_overrides(conf: &mut TryConf, table: &mut toml::value::Table) -> toml::value::Table {
+    let keys: FxHashSet<_> = fields().iter().map(ConfField::key).collect();
+    let mut overridden = toml::value::Table::new();
+
+    for ValueOverride { key, value, source } in value_overrides() {
+        if !keys.contains(&key) {
+            conf.errors.push(conf_error(format!(
+                "unknown configuration key `{}`, set by {}",
+                key, source
+            )));
+            continue;
+        }
+        let value = match parse_toml_value(&value) {
+            Ok(value) => value,
+            Err(e) => {
+                conf.errors.push(conf_error(format!(
+                    "invalid TOML value for `{}`, set by {}: {}",
+                    key, source, e
+                )));
+                continue;
+            },
+        };
+        let mut single = toml::value::Table::new();
+        single.insert(key.clone(), value.clone());
+        match TryConf::deserialize(toml::Value::Table(single)) {
+            Ok(TryConf { errors, warnings, .. }) if errors.is_empty() => {
+                conf.warnings.extend(warnings);
+                overridden.insert(key, value);
+            },
+            Ok(TryConf { errors, .. }) => {
+                for error in errors {
+                    conf.errors.push(invalid_override(&key, &source, &error));
+                }
+            },
+            Err(e) => conf.errors.push(invalid_override(&key, &source, &e)),
+        }
+    }
+
+    if !overridden.is_empty() {
+        table.extend(overridden.clone());
+        // the errors of the configuration file are already reported
+        if let Ok(overridden_conf) = TryConf::deserialize(toml::Value::Table(table.clone())) {
+            conf.conf = overridden_conf.conf;
+        }
+    }
+    overridden
+}
+
+/// Merges the configuration `child` into `parent`.
+///
+/// Values set in `child` replace the ones in `parent`, except for lists, which are merged by
+/// appending the values of `child` that aren't already in `parent`, and tables, which are merged
+/// recursively.
+fn merge_tables(mut parent: toml::value::Table, child: toml::value::Table) -> toml::value::Table {
+    for (key, value) in child {
+        match (parent.get_mut(&key), value) {
+            (Some(toml::Value::Array(parent_values)), toml::Value::Array(values)) => {
+                for value in values {
+                    if !parent_values.contains(&value) {
+                        parent_values.push(value);
+                    }
+                }
+            },
+            (Some(toml::Value::Table(parent_table)), toml::Value::Table(table)) => {
+                *parent_table = merge_tables(std::mem::take(parent_table), table);
+            },
+            (_, value) => {
+                parent.insert(key, value);
+            },
+        }
     }
+    parent
 }
 
 fn extend_vec_if_indicator_present(vec: &mut Vec<String>, default: &[&str]) {
@@ -0,0 +1,75 @@
+//! Only emits the lints on the lines changed relative to a git revision, see
+//! `cargo clippy --diff-base`. The changed lines are computed by `cargo-clippy`, which passes them
+//! to the driver in a file.
+
+use clippy_utils::diagnostics::{register_emission_hook, Emission, EmissionHook};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_lint::Level;
+use rustc_middle::lint::LevelAndSource;
+use rustc_session::Session;
+use rustc_span::FileName;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The changed lines of each file, by their canonical path. An empty list means that the whole
+/// file is new.
+struct ChangedLines {
+    files: FxHashMap<PathBuf, Vec<(usize, usize)>>,
+}
+
+impl EmissionHook for ChangedLines {
+    fn lint_level(&self, emission: &Emission<'_>, level: LevelAndSource) -> Option<LevelAndSource> {
+        if level.0 == Level::Allow {
+            return None;
+        }
+        // lints in macro expansions are shown at the macro call
+        let span = emission.span.source_callsite();
+        let source_map = emission.sess.source_map();
+        let lo = source_map.lookup_char_pos(span.lo());
+        let hi_line = source_map.lookup_char_pos(span.hi()).line;
+        let ranges = match &lo.file.name {
+            FileName::Real(name) => name
+                .local_path()
+                .and_then(|path| fs::canonicalize(path).ok())
+                .and_then(|path| self.files.get(&path)),
+            _ => None,
+        };
+        let changed = ranges.map_or(false, |ranges| {
+            ranges.is_empty() || ranges.iter().any(|&(start, end)| start <= hi_line && lo.line <= end)
+        });
+        (!changed).then_some((Level::Allow, level.1))
+    }
+}
+
+/// Registers the emission hook allowing the lints outside of the lines listed in `path`, written
+/// by `cargo-clippy`.
+pub fn register(sess: &Session, path: &Path) {
+    match fs::read_to_string(path) {
+        Ok(content) => {
+            let files = content.lines().filter_map(parse_line).collect();
+            register_emission_hook(Box::new(ChangedLines { files }));
+        },
+        Err(e) => {
+            sess.err(&format!(
+                "error reading the changed lines from `{}`: {}",
+                path.display(),
+                e
+            ));
+        },
+    }
+}
+
+/// Parses a line like `/path/to/file.rs:10-12,20-20`.
+fn parse_line(line: &str) -> Option<(PathBuf, Vec<(usize, usize)>)> {
+    let (path, ranges) = line.rsplit_once(':')?;
+    let path = fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path));
+    let ranges = ranges
+        .split(',')
+        .filter(|range| !range.is_empty())
+        .map(|range| {
+            let (start, end) = range.split_once('-')?;
+            Some((start.parse().ok()?, end.parse().ok()?))
+        })
+        .collect::<Option<_>>()?;
+    Some((path, ranges))
+}
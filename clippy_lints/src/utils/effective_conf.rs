@@ -0,0 +1,146 @@
+//! Describes the configuration and the lint levels used for a crate, see
+//! `cargo clippy --print-effective-config`.
+
+use crate::utils::conf::{self, Conf};
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_hir::CRATE_HIR_ID;
+use rustc_lint::{Level, Lint};
+use rustc_middle::lint::LintLevelSource;
+use rustc_middle::ty::TyCtxt;
+use std::env;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Reports the configuration file used for the crate, the value of each configuration key along
+/// with where it comes from, and the level of each Clippy lint at the crate root.
+///
+/// This is reported as a diagnostic rather than printed so that Cargo replays it when the crate is
+/// fresh.
+pub fn report(tcx: TyCtxt<'_>) {
+    let path = conf::lookup_conf_file().ok().flatten();
+    let conf = path.as_deref().map_or_else(conf::read_default, conf::read).conf;
+
+    let mut diag = tcx.sess.struct_note_without_error(&format!(
+        "effective Clippy configuration of crate `{}`",
+        tcx.crate_name(LOCAL_CRATE)
+    ));
+    diag.note(&conf_file_note(path.as_deref()));
+    diag.note(&values_note(path.as_deref(), &conf));
+    if !conf.overrides.is_empty() {
+        diag.note(&overrides_note(&conf));
+    }
+    diag.note(&levels_note(tcx, path.as_deref()));
+    diag.emit();
+}
+
+fn conf_file_note(path: Option<&Path>) -> String {
+    let mut note = match path {
+        Some(path) => format!("configuration file: `{}`", path.display()),
+        None => "no configuration file found, the default configuration is used".to_string(),
+    };
+    if let Some(dir) = env::var_os("CLIPPY_CONF_DIR") {
+        let _ = write!(note, "\nlooked up from `CLIPPY_CONF_DIR={}`", Path::new(&dir).display());
+    } else if let Some(dir) = env::var_os("CARGO_MANIFEST_DIR") {
+        let _ = write!(
+            note,
+            "\nlooked up from the package directory `{}`",
+            Path::new(&dir).display()
+        );
+    }
+    note
+}
+
+fn values_note(path: Option<&Path>, conf: &Conf) -> String {
+    let sources = path.map(conf::value_sources).unwrap_or_default();
+    let override_sources = conf::value_override_sources();
+    let mut note = "configuration values:".to_string();
+    for (field, value) in conf::fields().into_iter().zip(conf.values()) {
+        let key = field.key();
+        // these are described in the other notes
+        if key == "lints" || key == "overrides" {
+            continue;
+        }
+        let source = match (override_sources.get(&key), sources.get(&key)) {
+            // the values set by the environment or on the command line take precedence
+            (Some(source), _) => source.clone(),
+            (None, Some(files)) => files
+                .iter()
+                .map(|file| format!("`{}`", file.display()))
+                .collect::<Vec<_>>()
+                .join(", "),
+            // deprecated keys are only shown when they are set
+            (None, None) if field.deprecation_reason.is_some() => continue,
+            (None, None) => "default".to_string(),
+        };
+        let _ = write!(note, "\n    {} = {} ({})", key, value, source);
+    }
+    note
+}
+
+fn overrides_note(conf: &Conf) -> String {
+    let mut note = "the lint levels and configuration values are overridden for these files:".to_string();
+    for path_override in &conf.overrides {
+        let _ = write!(note, "\n    {}", path_override.paths.join(", "));
+    }
+    note
+}
+
+fn levels_note(tcx: TyCtxt<'_>, path: Option<&Path>) -> String {
+    let store = rustc_lint::unerased_lint_store(tcx);
+    let mut lints: Vec<&'static Lint> = store
+        .get_lints()
+        .iter()
+        .copied()
+        .filter(|lint| lint.name_lower().starts_with("clippy::"))
+        .collect();
+    lints.sort_by_key(|lint| lint.name);
+    // the levels of the `[lints]` table come first in the command line flags
+    let conf_levels = crate::conf_lint_levels().len();
+
+    let mut note = "lint levels at the crate root:".to_string();
+    for lint in lints {
+        let (level, source) = tcx.lint_level_at_node(lint, CRATE_HIR_ID);
+        let _ = write!(
+            note,
+            "\n    {}: {} ({})",
+            lint.name_lower(),
+            level.as_str(),
+            level_source(tcx, path, conf_levels, level, source)
+        );
+    }
+    note
+}
+
+fn level_source(
+    tcx: TyCtxt<'_>,
+    path: Option<&Path>,
+    conf_levels: usize,
+    level: Level,
+    source: LintLevelSource,
+) -> String {
+    match source {
+        LintLevelSource::Default => "default".to_string(),
+        LintLevelSource::Node(name, span, _) => format!(
+            "`#![{}({})]` at {}",
+            level.as_str(),
+            name,
+            tcx.sess.source_map().span_to_embeddable_string(span)
+        ),
+        LintLevelSource::CommandLine(name, flag_level) => {
+            // the levels of the `[lints]` table are only used if no later flag sets the same lint
+            let lint_opts = &tcx.sess.opts.lint_opts;
+            let last_flag = lint_opts.iter().rposition(|(lint, _)| *lint == name.as_str());
+            match (last_flag, path) {
+                (Some(i), Some(path)) if i < conf_levels => {
+                    format!(
+                        "`{} = \"{}\"` in the `[lints]` table of `{}`",
+                        name,
+                        flag_level.as_str(),
+                        path.display()
+                    )
+                },
+                _ => format!("command line flag `--{} {}`", flag_level.as_str(), name),
+            }
+        },
+    }
+}
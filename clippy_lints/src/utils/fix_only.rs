@@ -0,0 +1,115 @@
+//! Restricts the suggestions that `cargo fix` applies to the ones of some lints, see
+//! `cargo clippy --fix-only`.
+//!
+//! `cargo fix` applies the machine applicable suggestions of the diagnostics the driver emits as
+//! JSON. The diagnostics are written through [`FixFilter`], which makes the suggestions of every
+//! other diagnostic only maybe incorrect, the ones of rustc's lints and errors included.
+
+use rustc_data_structures::fx::FxHashSet;
+use rustc_lint::LintStore;
+use rustc_session::Session;
+use serde_json::Value;
+use std::io::{self, Write};
+use std::sync::OnceLock;
+
+/// The names of the lints whose suggestions can be applied, known once the lints are registered.
+static FIXABLE: OnceLock<FxHashSet<String>> = OnceLock::new();
+
+/// Resolves the lints and lint groups whose suggestions can be applied. The names can omit the
+/// `clippy::` prefix.
+pub fn restrict(sess: &Session, store: &LintStore, names: &[String]) {
+    let mut fixable = FxHashSet::default();
+    for name in names {
+        let name = if name.starts_with("clippy::") {
+            name.replace('-', "_")
+        } else {
+            format!("clippy::{}", name.replace('-', "_"))
+        };
+        match store.find_lints(&name) {
+            Ok(lints) => fixable.extend(lints.into_iter().map(|lint| lint.lint.name_lower())),
+            Err(_) => {
+                sess.err(&format!("unknown Clippy lint or lint group `{}` to fix", name));
+            },
+        }
+    }
+    FIXABLE.set(fixable).ok();
+}
+
+/// Writes the JSON diagnostics to `out`, with the machine applicable suggestions of the ones that
+/// aren't emitted by a fixable lint made maybe incorrect. The other lines are written unchanged.
+pub struct FixFilter<W: Write> {
+    out: W,
+    /// The start of a line, written without its end.
+    pending: Vec<u8>,
+}
+
+impl<W: Write> FixFilter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl<W: Write> Write for FixFilter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        while let Some(end) = self.pending.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=end).collect();
+            match restrict_line(&line) {
+                Some(restricted) => self.out.write_all(restricted.as_bytes())?,
+                None => self.out.write_all(&line)?,
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let pending = std::mem::take(&mut self.pending);
+        self.out.write_all(&pending)?;
+        self.out.flush()
+    }
+}
+
+impl<W: Write> Drop for FixFilter<W> {
+    fn drop(&mut self) {
+        self.flush().ok();
+    }
+}
+
+/// Returns `line` with its suggestions restricted, if it's a diagnostic whose suggestions can't be
+/// applied and that has some.
+fn restrict_line(line: &[u8]) -> Option<String> {
+    let mut diag: Value = serde_json::from_slice(line).ok()?;
+    let code = diag.get("code")?.get("code").and_then(Value::as_str);
+    let fixable = FIXABLE
+        .get()
+        .map_or(false, |fixable| code.map_or(false, |code| fixable.contains(code)));
+    if fixable || !restrict_diag(&mut diag) {
+        return None;
+    }
+    Some(format!("{}\n", diag))
+}
+
+/// Makes the machine applicable suggestions of `diag` and of its children maybe incorrect, and
+/// returns whether there was one.
+fn restrict_diag(diag: &mut Value) -> bool {
+    let mut restricted = false;
+    if let Some(Value::Array(spans)) = diag.get_mut("spans") {
+        for span in spans {
+            if let Some(applicability) = span.get_mut("suggestion_applicability") {
+                if applicability == "MachineApplicable" {
+                    *applicability = Value::from("MaybeIncorrect");
+                    restricted = true;
+                }
+            }
+        }
+    }
+    if let Some(Value::Array(children)) = diag.get_mut("children") {
+        for child in children {
+            restricted |= restrict_diag(child);
+        }
+    }
+    restricted
+}
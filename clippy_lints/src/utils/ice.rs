@@ -0,0 +1,267 @@
+//! Writes a bundle of what is needed to reproduce an internal compiler error, when `CLIPPY_ICE_DIR`
+//! is set.
+//!
+//! The lint passes are wrapped to keep track of the pass being run and, for the late passes, of the
+//! node it is checking, which the panic hook of the driver then writes along with the command line
+//! and the configuration.
+
+use crate::utils::conf::{self, LintLevel};
+use crate::utils::pass_hooks::{wrap_passes, PassHook};
+use rustc_hir::HirId;
+use rustc_lint::{LateContext, LintStore};
+use rustc_middle::ty::tls;
+use rustc_span::Span;
+use std::cell::Cell;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::{env, fs, io, process};
+
+/// The maximum number of lines of the snippet written to the bundle.
+const MAX_SNIPPET_LINES: usize = 200;
+
+/// The lint pass being run.
+#[derive(Clone, Copy)]
+struct Current {
+    pass: &'static str,
+    method: &'static str,
+    /// The node checked by a late pass, or rather the innermost node that can have lint attributes.
+    node: Option<(HirId, Span)>,
+}
+
+thread_local! {
+    static CURRENT: Cell<Option<Current>> = Cell::new(None);
+}
+
+struct IceHook;
+
+impl IceHook {
+    fn run(current: Current, call: &mut dyn FnMut()) {
+        // if `call` panics, the hook of the driver still sees the pass it was running
+        let previous = CURRENT.with(|cell| cell.replace(Some(current)));
+        call();
+        CURRENT.with(|cell| cell.set(previous));
+    }
+}
+
+impl PassHook for IceHook {
+    fn around(&self, pass: &'static str, method: &'static str, call: &mut dyn FnMut()) {
+        Self::run(
+            Current {
+                pass,
+                method,
+                node: None,
+            },
+            call,
+        );
+    }
+
+    fn around_late(&self, cx: &LateContext<'_>, pass: &'static str, method: &'static str, call: &mut dyn FnMut()) {
+        let hir_id = cx.last_node_with_lint_attrs;
+        // all the passes are run on a node one after the other, so its span is only looked up once
+        let node = match CURRENT.with(Cell::get).and_then(|current| current.node) {
+            Some((id, span)) if id == hir_id => (id, span),
+            _ => (hir_id, cx.tcx.hir().span(hir_id)),
+        };
+        Self::run(
+            Current {
+                pass,
+                method,
+                node: Some(node),
+            },
+            call,
+        );
+    }
+}
+
+/// Wraps the lint passes registered in `store` to keep track of the pass being run. This must be
+/// called after all the lint passes are registered.
+pub fn register(store: &mut LintStore) {
+    let hook: Arc<dyn PassHook> = Arc::new(IceHook);
+    wrap_passes(store, &hook);
+}
+
+/// Writes the bundle of the panic described by `message` to a new directory in `dir`, and returns
+/// the path of that directory.
+pub fn write_bundle(dir: &Path, message: &str) -> io::Result<PathBuf> {
+    let args: Vec<String> = env::args().collect();
+    let crate_name = args
+        .iter()
+        .position(|arg| arg == "--crate-name")
+        .and_then(|pos| args.get(pos + 1))
+        .map_or("crate", String::as_str);
+    let bundle = dir.join(format!("clippy-ice-{}-{}", crate_name, process::id()));
+    fs::create_dir_all(&bundle)?;
+
+    fs::write(bundle.join("report.txt"), report(message))?;
+    fs::write(bundle.join("command.txt"), command(&args))?;
+    fs::write(bundle.join("clippy.toml"), effective_conf())?;
+    Ok(bundle)
+}
+
+fn report(message: &str) -> String {
+    let mut report = format!("{}\n\n", message);
+    let Some(current) = CURRENT.with(Cell::get) else {
+        report.push_str("no lint pass was running\n");
+        return report;
+    };
+    let _ = writeln!(report, "lint pass: `{}`, in `{}`", current.pass, current.method);
+    if let Some((_, span)) = current.node {
+        let source = tls::with_opt(|tcx| {
+            let source_map = tcx?.sess.source_map();
+            Some((
+                source_map.span_to_diagnostic_string(span),
+                source_map.span_to_snippet(span).ok(),
+            ))
+        });
+        match source {
+            Some((location, snippet)) => {
+                let _ = writeln!(report, "span: {}", location);
+                if let Some(snippet) = snippet {
+                    let lines: Vec<_> = snippet.lines().collect();
+                    let _ = writeln!(report, "\n{}", lines[..lines.len().min(MAX_SNIPPET_LINES)].join("\n"));
+                    if lines.len() > MAX_SNIPPET_LINES {
+                        let _ = writeln!(report, "... {} more lines", lines.len() - MAX_SNIPPET_LINES);
+                    }
+                }
+            },
+            None => {
+                let _ = writeln!(report, "span: {:?}", span);
+            },
+        }
+    }
+    report
+}
+
+/// Describes how the driver was run: its arguments, working directory and environment variables.
+fn command(args: &[String]) -> String {
+    let mut command = String::from("arguments:\n");
+    for arg in args {
+        let _ = writeln!(command, "    {}", arg);
+    }
+    if let Ok(dir) = env::current_dir() {
+        let _ = writeln!(command, "\nworking directory: {}", dir.display());
+    }
+    command.push_str("\nenvironment:\n");
+    let vars = [
+        "CLIPPY_ARGS",
+        "CLIPPY_CONF_DIR",
+        "CARGO_MANIFEST_DIR",
+        "CARGO_PRIMARY_PACKAGE",
+    ]
+    .map(String::from);
+    // along with the variables overriding the configuration values
+    for var in vars.into_iter().chain(conf::env_vars()) {
+        if let Some(value) = env::var_os(&var) {
+            let _ = writeln!(command, "    {}={}", var, value.to_string_lossy());
+        }
+    }
+    command
+}
+
+/// Writes the configuration used for the crate, as the value of each key rather than the content of
+/// the file, since it can be inherited from the parent directories.
+fn effective_conf() -> String {
+    let path = conf::lookup_conf_file().ok().flatten();
+    let conf = path.as_deref().map_or_else(conf::read_default, conf::read).conf;
+    let mut content = match &path {
+        Some(path) => format!("# the effective configuration read from `{}`\n", path.display()),
+        None => "# no configuration file was found, this is the default configuration\n".to_string(),
+    };
+    for (field, value) in conf::fields().into_iter().zip(conf.values()) {
+        let key = field.key();
+        if field.deprecation_reason.is_none() && key != "lints" && key != "overrides" {
+            let _ = writeln!(content, "{} = {}", key, value);
+        }
+    }
+    if !conf.lints.is_empty() {
+        content.push_str("\n[lints]\n");
+        for (name, level) in &conf.lints {
+            let _ = writeln!(content, "{:?} = \"{}\"", name, level_name(*level));
+        }
+    }
+    for path_override in &conf.overrides {
+        let _ = writeln!(
+            content,
+            "\n[[overrides]]\npaths = {}",
+            serde_json::to_string(&path_override.paths).unwrap_or_default()
+        );
+        if !path_override.lints.is_empty() {
+            let lints: Vec<_> = path_override
+                .lints
+                .iter()
+                .map(|(name, level)| format!("{:?} = \"{}\"", name, level_name(*level)))
+                .collect();
+            let _ = writeln!(content, "lints = {{ {} }}", lints.join(", "));
+        }
+        content.push_str(&toml::to_string(&path_override.values).unwrap_or_default());
+    }
+    content
+}
+
+fn level_name(level: LintLevel) -> &'static str {
+    match level {
+        LintLevel::Allow => "allow",
+        LintLevel::Warn => "warn",
+        LintLevel::Deny => "deny",
+        LintLevel::Forbid => "forbid",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{effective_conf, write_bundle};
+    use std::{env, fs, process};
+
+    #[test]
+    fn bundle() {
+        let dir = env::temp_dir().join(format!("clippy-ice-test-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let conf_path = dir.join("clippy.toml");
+        fs::write(
+            &conf_path,
+            "too-many-arguments-threshold = 4\n\n\
+             [lints]\n\
+             unwrap_used = \"deny\"\n\n\
+             [[overrides]]\n\
+             paths = [\"tests/**\"]\n\
+             too-many-arguments-threshold = 10\n\
+             lints = { unwrap_used = \"allow\" }\n",
+        )
+        .unwrap();
+        env::set_var("CLIPPY_CONF_DIR", &dir);
+
+        let conf = effective_conf();
+        let conf_path = conf_path.canonicalize().unwrap();
+        assert!(conf.starts_with(&format!(
+            "# the effective configuration read from `{}`\n",
+            conf_path.display()
+        )));
+        // every key is written, with its default value unless it's set
+        assert!(conf.contains("\ntoo-many-arguments-threshold = 4\n"));
+        assert!(conf.contains("\ntoo-many-lines-threshold = 100\n"));
+        // the paths of the overrides are resolved from the directory of the file
+        assert!(conf.ends_with(&format!(
+            "\n[lints]\n\
+             \"unwrap_used\" = \"deny\"\n\n\
+             [[overrides]]\n\
+             paths = [\"{}/tests/**\"]\n\
+             lints = {{ \"unwrap_used\" = \"allow\" }}\n\
+             too-many-arguments-threshold = 10\n",
+            conf_path.parent().unwrap().display()
+        )));
+
+        let bundle = write_bundle(&dir, "panicked at 'oops', src/lib.rs:1:1").unwrap();
+        assert_eq!(bundle, dir.join(format!("clippy-ice-crate-{}", process::id())));
+        assert_eq!(
+            fs::read_to_string(bundle.join("report.txt")).unwrap(),
+            "panicked at 'oops', src/lib.rs:1:1\n\nno lint pass was running\n"
+        );
+        let command = fs::read_to_string(bundle.join("command.txt")).unwrap();
+        assert!(command.starts_with("arguments:\n"));
+        assert!(command.contains(&format!("\nenvironment:\n    CLIPPY_CONF_DIR={}\n", dir.display())));
+        assert_eq!(fs::read_to_string(bundle.join("clippy.toml")).unwrap(), conf);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -8,6 +8,7 @@
 //! a simple mistake)
 
 use crate::renamed_lints::RENAMED_LINTS;
+use crate::utils::conf::parse_config_field_doc;
 use crate::utils::internal_lints::{extract_clippy_version_value, is_lint_ref_type};
 
 use clippy_utils::diagnostics::span_lint;
@@ -36,6 +37,9 @@ use std::process::Command;
 
 /// This is the output file of the lint collector.
 const OUTPUT_FILE: &str = "../util/gh-pages/lints.json";
+/// The applicability of the lints for `cargo clippy --explain` and `--list-lints`, written next to
+/// the lint list.
+const APPLICABILITY_FILE: &str = "../src/applicability.rs";
 /// These lints are excluded from the export.
 const BLACK_LISTED_LINTS: &[&str] = &["lint_author", "dump_hir", "internal_metadata_collector"];
 /// These groups will be ignored by the lint group matcher. This is useful for collections like
@@ -236,7 +240,29 @@ impl Drop for MetadataCollector {
         }
         let mut file = OpenOptions::new().write(true).create(true).open(OUTPUT_FILE).unwrap();
         writeln!(file, "{}", serde_json::to_string_pretty(&lints).unwrap()).unwrap();
+
+        fs::write(APPLICABILITY_FILE, applicability_table(&lints)).unwrap();
+    }
+}
+
+/// Formats the applicability of the lints as the table of `src/applicability.rs`, leaving out the
+/// deprecated lints like the lint documentation does.
+fn applicability_table(lints: &[LintMetadata]) -> String {
+    let mut table = String::from(
+        "// autogenerated by the metadata collector. Run `cargo collect-metadata` to update it\n\n\
+        /// The applicability of the suggestions of the lints, sorted by name. `Unresolved` if the lint\n\
+        /// doesn't suggest anything or the metadata collector couldn't find out.\n\
+        pub static APPLICABILITY: &[(&str, &str)] = &[\n",
+    );
+    for lint in lints.iter().filter(|lint| lint.group != DEPRECATED_LINT_GROUP_STR) {
+        let applicability = lint
+            .applicability
+            .as_ref()
+            .map_or(APPLICABILITY_UNRESOLVED_STR, ApplicabilityInfo::name);
+        writeln!(table, "    (\"{}\", \"{}\"),", lint.id, applicability).unwrap();
     }
+    table.push_str("];\n");
+    table
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
@@ -495,18 +521,20 @@ impl Serialize for ApplicabilityInfo {
     {
         let mut s = serializer.serialize_struct("ApplicabilityInfo", 2)?;
         s.serialize_field("is_multi_part_suggestion", &self.is_multi_part_suggestion)?;
-        if let Some(index) = self.applicability {
-            s.serialize_field(
-                "applicability",
-                &paths::APPLICABILITY_VALUES[index][APPLICABILITY_NAME_INDEX],
-            )?;
-        } else {
-            s.serialize_field("applicability", APPLICABILITY_UNRESOLVED_STR)?;
-        }
+        s.serialize_field("applicability", self.name())?;
         s.end()
     }
 }
 
+impl ApplicabilityInfo {
+    /// The name of the `Applicability` variant, or `Unresolved`.
+    fn name(&self) -> &'static str {
+        self.applicability.map_or(APPLICABILITY_UNRESOLVED_STR, |index| {
+            paths::APPLICABILITY_VALUES[index][APPLICABILITY_NAME_INDEX]
+        })
+    }
+}
+
 // ==================================================================
 // Configuration
 // ==================================================================
@@ -547,40 +575,6 @@ fn collect_configs() -> Vec<ClippyConfiguration> {
     crate::utils::conf::metadata::get_configuration_metadata()
 }
 
-/// This parses the field documentation of the config struct.
-///
-/// ```rust, ignore
-/// parse_config_field_doc(cx, "Lint: LINT_NAME_1, LINT_NAME_2. Papa penguin, papa penguin")
-/// ```
-///
-/// Would yield:
-/// ```rust, ignore
-/// Some(["lint_name_1", "lint_name_2"], "Papa penguin, papa penguin")
-/// ```
-fn parse_config_field_doc(doc_comment: &str) -> Option<(Vec<String>, String)> {
-    const DOC_START: &str = " Lint: ";
-    if_chain! {
-        if doc_comment.starts_with(DOC_START);
-        if let Some(split_pos) = doc_comment.find('.');
-        then {
-            let mut doc_comment = doc_comment.to_string();
-            let mut documentation = doc_comment.split_off(split_pos);
-
-            // Extract lints
-            doc_comment.make_ascii_lowercase();
-            let lints: Vec<String> = doc_comment.split_off(DOC_START.len()).split(", ").map(str::to_string).collect();
-
-            // Format documentation correctly
-            // split off leading `.` from lint name list and indent for correct formatting
-            documentation = documentation.trim_start_matches('.').trim().replace("\n ", "\n    ");
-
-            Some((lints, documentation))
-        } else {
-            None
-        }
-    }
-}
-
 /// Transforms a given `snake_case_string` to a tasty `kebab-case-string`
 fn to_kebab(config_name: &str) -> String {
     config_name.replace('_', "-")
@@ -1,5 +1,17 @@
 pub mod author;
+pub mod baseline;
+pub mod catch_panics;
+pub mod check_conf;
 pub mod conf;
+pub mod diff_base;
 pub mod dump_hir;
+pub mod effective_conf;
+pub mod fix_only;
+pub mod ice;
 #[cfg(feature = "internal")]
 pub mod internal_lints;
+pub mod pass_hooks;
+pub mod path_overrides;
+pub mod sarif;
+pub mod summary;
+pub mod timings;
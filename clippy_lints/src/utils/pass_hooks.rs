@@ -0,0 +1,110 @@
+//! Hooks run around each call to Clippy's lint passes.
+//!
+//! The passes of a `LintStore` are wrapped with [`wrap_passes`], each call to a `check_*` method
+//! of a wrapped pass then goes through [`PassHook::around`]. Wrapped passes can be wrapped again to
+//! run several hooks.
+
+use rustc_ast as ast;
+use rustc_data_structures::sync::{Send, Sync};
+use rustc_hir as hir;
+use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintPass, LintStore};
+use rustc_span::symbol::Ident;
+use rustc_span::Span;
+use std::sync::Arc;
+
+type EarlyPassObject = Box<dyn EarlyLintPass + Send>;
+type LatePassObject = Box<dyn for<'tcx> LateLintPass<'tcx> + Send>;
+
+/// Code run around the calls to the methods of the wrapped lint passes.
+pub trait PassHook: Send + Sync {
+    /// Called for each call to the method `method` of the lint pass named `pass`. `call` performs
+    /// the call, and must be called at most once. Not calling it skips the call, along with the
+    /// hooks the pass was wrapped with before.
+    fn around(&self, pass: &'static str, method: &'static str, call: &mut dyn FnMut());
+
+    /// Called instead of [`PassHook::around`] for the early lint passes, with the context of the
+    /// call.
+    fn around_early(&self, _cx: &EarlyContext<'_>, pass: &'static str, method: &'static str, call: &mut dyn FnMut()) {
+        self.around(pass, method, call);
+    }
+
+    /// Called instead of [`PassHook::around`] for the late lint passes, with the context of the
+    /// call.
+    fn around_late(&self, _cx: &LateContext<'_>, pass: &'static str, method: &'static str, call: &mut dyn FnMut()) {
+        self.around(pass, method, call);
+    }
+}
+
+/// Wraps all the early and late lint passes registered in `store` so that `hook` runs around each
+/// of their calls.
+pub fn wrap_passes(store: &mut LintStore, hook: &Arc<dyn PassHook>) {
+    store.early_passes = std::mem::take(&mut store.early_passes)
+        .into_iter()
+        .map(|inner| -> Box<dyn Fn() -> EarlyPassObject + Send + Sync> {
+            let hook = Arc::clone(hook);
+            Box::new(move || -> EarlyPassObject {
+                Box::new(WrappedPass {
+                    hook: Arc::clone(&hook),
+                    pass: inner(),
+                })
+            })
+        })
+        .collect();
+    store.late_passes = std::mem::take(&mut store.late_passes)
+        .into_iter()
+        .map(|inner| -> Box<dyn Fn() -> LatePassObject + Send + Sync> {
+            let hook = Arc::clone(hook);
+            Box::new(move || -> LatePassObject {
+                Box::new(WrappedPass {
+                    hook: Arc::clone(&hook),
+                    pass: inner(),
+                })
+            })
+        })
+        .collect();
+}
+
+struct WrappedPass<P> {
+    hook: Arc<dyn PassHook>,
+    pass: P,
+}
+
+#[allow(rustc::lint_pass_impl_without_macro)]
+impl LintPass for WrappedPass<EarlyPassObject> {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+#[allow(rustc::lint_pass_impl_without_macro)]
+impl LintPass for WrappedPass<LatePassObject> {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+macro_rules! wrap_early_methods {
+    ([], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => {
+        $(fn $name(&mut self, cx: &EarlyContext<'_>, $($param: $arg),*) {
+            let pass = &mut self.pass;
+            self.hook.around_early(cx, pass.name(), stringify!($name), &mut || pass.$name(cx, $($param),*));
+        })*
+    };
+}
+
+macro_rules! wrap_late_methods {
+    ([], [$hir:tt], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => {
+        $(fn $name(&mut self, cx: &LateContext<$hir>, $($param: $arg),*) {
+            let pass = &mut self.pass;
+            self.hook.around_late(cx, pass.name(), stringify!($name), &mut || pass.$name(cx, $($param),*));
+        })*
+    };
+}
+
+impl EarlyLintPass for WrappedPass<EarlyPassObject> {
+    rustc_lint::early_lint_methods!(wrap_early_methods, []);
+}
+
+impl<'tcx> LateLintPass<'tcx> for WrappedPass<LatePassObject> {
+    rustc_lint::late_lint_methods!(wrap_late_methods, [], ['tcx]);
+}
@@ -0,0 +1,246 @@
+//! Applies the `[[overrides]]` tables of the configuration, which set lint levels and
+//! configuration values for the files matching some glob patterns.
+//!
+//! Lint levels are applied by an emission hook, see `clippy_utils::diagnostics::EmissionHook`.
+//! Configuration values are applied by registering the lint passes once more for each set of
+//! values of the overrides, with the configuration of the overrides setting them. Only the passes
+//! emitting the lints configured by the keys whose value differs from the one of the whole crate
+//! are registered again, the lints being the ones listed in the documentation of the keys. Each set
+//! of passes then only reports lints in the files whose last override sets its values, which is
+//! checked by the same emission hook.
+
+use crate::utils::conf::{self, parse_config_field_doc, Conf, PathOverride};
+use crate::utils::pass_hooks::{wrap_passes, PassHook};
+use clippy_utils::diagnostics::{register_emission_hook, Emission, EmissionHook};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_lint::{Level, Lint, LintId, LintStore};
+use rustc_middle::lint::{LevelAndSource, LintLevelSource};
+use rustc_session::Session;
+use rustc_span::{BytePos, FileName, Span, Symbol};
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// The lints of each of Clippy's lint passes, by the name of the pass, sorted by name.
+static PASS_LINTS: &[(&str, &[&str])] = include!("../lib.pass_lints.rs");
+
+thread_local! {
+    /// The scope and the name of the lint pass being run, if it is scoped.
+    static CURRENT_SCOPE: Cell<Option<(usize, &'static str)>> = Cell::new(None);
+}
+
+struct ScopeHook(usize);
+
+impl PassHook for ScopeHook {
+    fn around(&self, pass: &'static str, _: &'static str, call: &mut dyn FnMut()) {
+        let previous = CURRENT_SCOPE.with(|scope| scope.replace(Some((self.0, pass))));
+        call();
+        CURRENT_SCOPE.with(|scope| scope.set(previous));
+    }
+}
+
+struct Overrides {
+    /// The overrides setting configuration values, along with the scope of the passes using their
+    /// values. The scope `0` is the one of the passes using the configuration of the whole crate.
+    scopes: Vec<(PathOverride, usize)>,
+    /// The names of the passes registered again for each scope but the one of the whole crate.
+    /// The other passes of the whole crate report their lints in the files of the scope.
+    passes: Vec<FxHashSet<&'static str>>,
+    /// The lint levels set by each override.
+    levels: Vec<(PathOverride, FxHashMap<LintId, Level>)>,
+    /// The canonical path of each source file the lints are emitted in, by the start of the file.
+    files: Mutex<FxHashMap<BytePos, Option<PathBuf>>>,
+}
+
+impl Overrides {
+    /// The scope of the pass `pass` reporting the lints in `file`: the one of the last override
+    /// setting configuration values that applies to it, if the pass is registered for it. The
+    /// lints whose file is unknown are reported by the passes of the whole crate.
+    fn scope_of(&self, pass: &str, file: Option<&Path>) -> usize {
+        file.and_then(|file| {
+            self.scopes
+                .iter()
+                .rev()
+                .find(|(path_override, _)| path_override.matches(file))
+        })
+        .filter(|&&(_, scope)| self.passes[scope - 1].contains(pass))
+        .map_or(0, |&(_, scope)| scope)
+    }
+
+    /// Returns the canonical path of the file `span` is in, or of the file it is expanded in for
+    /// the spans of external macros.
+    fn source_file(&self, sess: &Session, span: Span) -> Option<PathBuf> {
+        if span.is_dummy() {
+            return None;
+        }
+        let file = sess.source_map().lookup_source_file(span.source_callsite().lo());
+        self.files
+            .lock()
+            .unwrap()
+            .entry(file.start_pos)
+            .or_insert_with(|| match &file.name {
+                FileName::Real(name) => name.local_path()?.canonicalize().ok(),
+                _ => None,
+            })
+            .clone()
+    }
+}
+
+impl EmissionHook for Overrides {
+    fn lint_level(&self, emission: &Emission<'_>, level: LevelAndSource) -> Option<LevelAndSource> {
+        let Emission { sess, lint, span, .. } = *emission;
+        let file = self.source_file(sess, span);
+        if let Some((scope, pass)) = CURRENT_SCOPE.with(Cell::get)
+            && scope != self.scope_of(pass, file.as_deref())
+        {
+            return Some((Level::Allow, level.1));
+        }
+
+        // lint attributes take precedence over the overrides
+        if let LintLevelSource::Node(..) = level.1 {
+            return None;
+        }
+        let file = file?;
+        let override_level = self
+            .levels
+            .iter()
+            .rev()
+            .filter(|(path_override, _)| path_override.matches(&file))
+            .find_map(|(_, levels)| levels.get(&LintId::of(lint)).copied())?;
+        Some(command_line_level(sess, lint, override_level))
+    }
+}
+
+/// Registers the lint passes and the lint levels of the overrides of `conf`. This must be called
+/// once all the lints and lint passes are registered in `store`.
+pub fn register(store: &mut LintStore, sess: &Session, conf: &Conf) {
+    if conf.overrides.is_empty() {
+        return;
+    }
+
+    // the overrides setting the same values share a set of passes
+    let mut scopes = Vec::new();
+    let mut shared: Vec<&PathOverride> = Vec::new();
+    for path_override in conf.overrides.iter().filter(|o| !o.values.is_empty()) {
+        let scope = if let Some(i) = shared.iter().position(|o| o.values == path_override.values) {
+            i + 1
+        } else {
+            shared.push(path_override);
+            shared.len()
+        };
+        scopes.push((path_override.clone(), scope));
+    }
+
+    let mut passes = Vec::new();
+    if !shared.is_empty() {
+        let hook: Arc<dyn PassHook> = Arc::new(ScopeHook(0));
+        wrap_passes(store, &hook);
+        for (i, path_override) in shared.into_iter().enumerate() {
+            // errors in the values are reported when reading the configuration
+            let override_conf = path_override.conf().unwrap_or_default();
+            let mut override_store = LintStore::new();
+            crate::register_plugins(&mut override_store, sess, &override_conf);
+            passes.push(retain_configured_passes(
+                &mut override_store,
+                store,
+                conf,
+                &override_conf,
+            ));
+            let hook: Arc<dyn PassHook> = Arc::new(ScopeHook(i + 1));
+            wrap_passes(&mut override_store, &hook);
+            store.early_passes.append(&mut override_store.early_passes);
+            store.late_passes.append(&mut override_store.late_passes);
+        }
+    }
+
+    let levels = conf
+        .overrides
+        .iter()
+        .map(|path_override| {
+            (
+                path_override.clone(),
+                crate::resolve_conf_lint_levels(store, &path_override.lints),
+            )
+        })
+        .filter(|(_, levels)| !levels.is_empty())
+        .collect();
+    register_emission_hook(Box::new(Overrides {
+        scopes,
+        passes,
+        levels,
+        files: Mutex::default(),
+    }));
+}
+
+/// Removes the passes of `override_store` that emit none of the lints configured by the values of
+/// `override_conf` that differ from the ones of `conf`, as they would lint like the passes of the
+/// whole crate, and returns the names of the passes left. All the passes are kept if the lints of
+/// one of these values aren't known.
+fn retain_configured_passes(
+    override_store: &mut LintStore,
+    store: &LintStore,
+    conf: &Conf,
+    override_conf: &Conf,
+) -> FxHashSet<&'static str> {
+    let mut configured = FxHashSet::default();
+    let mut all_lints = false;
+    let values = conf.values().into_iter().zip(override_conf.values());
+    for (field, (value, override_value)) in conf::fields().iter().zip(values) {
+        // the lint levels are applied by the emission hook
+        if value == override_value || matches!(field.name, "lints" | "overrides") {
+            continue;
+        }
+        let lints = match parse_config_field_doc(field.doc) {
+            Some((lints, _)) => lints,
+            None => Vec::new(),
+        };
+        all_lints |= lints.is_empty();
+        for lint in lints {
+            match store.find_lints(&format!("clippy::{}", lint)) {
+                Ok(ids) => configured.extend(ids.into_iter().map(|id| id.lint.name_lower())),
+                Err(_) => all_lints = true,
+            }
+        }
+    }
+
+    let is_configured = |pass: &str| {
+        all_lints
+            || PASS_LINTS
+                .binary_search_by(|&(name, _)| name.cmp(pass))
+                .map_or(true, |index| {
+                    PASS_LINTS[index]
+                        .1
+                        .iter()
+                        .any(|lint| configured.contains(&format!("clippy::{}", lint)))
+                })
+    };
+    let mut names = FxHashSet::default();
+    let mut retain = |name: &'static str| {
+        let configured = is_configured(name);
+        if configured {
+            names.insert(name);
+        }
+        configured
+    };
+    override_store.early_passes.retain(|pass| retain(pass().name()));
+    override_store.late_passes.retain(|pass| retain(pass().name()));
+    names
+}
+
+/// Computes the level of `lint` as if `level` was passed on the command line.
+fn command_line_level(sess: &Session, lint: &'static Lint, level: Level) -> LevelAndSource {
+    let mut level_and_source = (
+        level,
+        LintLevelSource::CommandLine(Symbol::intern(&lint.name_lower()), level),
+    );
+    if level == Level::Warn
+        && let Some((_, warnings)) = sess.opts.lint_opts.iter().rev().find(|(name, _)| name == "warnings")
+        && *warnings != Level::Warn
+    {
+        level_and_source = (*warnings, LintLevelSource::CommandLine(Symbol::intern("warnings"), *warnings));
+    }
+    if let Some(cap) = sess.opts.lint_cap {
+        level_and_source.0 = level_and_source.0.min(cap);
+    }
+    level_and_source
+}
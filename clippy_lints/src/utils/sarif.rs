@@ -0,0 +1,220 @@
+//! Converts the JSON diagnostics printed by Cargo to a [SARIF](https://sarifweb.azurewebsites.net/)
+//! log, see `cargo clippy --message-format=sarif`.
+
+use clippy_utils::diagnostics::docs_url;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_lint::{Level, LintId, LintStore};
+use serde_json::{json, Value};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+/// The base of the relative paths of the diagnostics, which are relative to the workspace root.
+const SRCROOT: &str = "%SRCROOT%";
+
+/// The rules of the SARIF log, one for each Clippy lint.
+struct Rules {
+    rules: Vec<Value>,
+    /// The index of each rule in `rules`, by lint name.
+    indices: FxHashMap<String, usize>,
+}
+
+impl Rules {
+    /// Describes the Clippy lints with the same metadata as the lint list: the description, the
+    /// group and the default level.
+    fn new() -> Self {
+        let mut store = LintStore::new();
+        crate::register_lints(&mut store);
+        let groups: Vec<_> = store
+            .get_lint_groups()
+            .filter(|(name, ..)| name.starts_with("clippy::") && *name != "clippy::all")
+            .collect();
+        let mut lints: Vec<_> = store
+            .get_lints()
+            .iter()
+            .copied()
+            .filter(|lint| lint.name_lower().starts_with("clippy::"))
+            .collect();
+        lints.sort_by_key(|lint| lint.name);
+
+        let mut rules = Vec::new();
+        let mut indices = FxHashMap::default();
+        for lint in lints {
+            let id = lint.name_lower();
+            let name = id.trim_start_matches("clippy::");
+            let group = groups
+                .iter()
+                .find(|(_, lints, _)| lints.contains(&LintId::of(lint)))
+                .map_or("", |(group, ..)| group.trim_start_matches("clippy::"));
+            rules.push(json!({
+                "id": id,
+                "name": name,
+                "shortDescription": { "text": lint.desc },
+                "helpUri": docs_url(name),
+                "defaultConfiguration": { "level": sarif_level(lint.default_level) },
+                "properties": { "group": group },
+            }));
+            indices.insert(id, rules.len() - 1);
+        }
+        Self { rules, indices }
+    }
+}
+
+fn sarif_level(level: Level) -> &'static str {
+    match level {
+        Level::Allow | Level::Expect(_) => "none",
+        Level::Warn | Level::ForceWarn(_) => "warning",
+        Level::Deny | Level::Forbid => "error",
+    }
+}
+
+/// Reads the messages printed by `cargo check --message-format=json` from `input`, and writes a
+/// SARIF 2.1.0 log of the diagnostics to `output`. `root` is the workspace root, which the paths of
+/// the diagnostics are relative to.
+pub fn convert(input: impl BufRead, output: &mut impl Write, root: &Path, version: &str) -> io::Result<()> {
+    let rules = Rules::new();
+    let mut seen = FxHashSet::default();
+    let mut results = Vec::new();
+    for line in input.lines() {
+        let Ok(message) = serde_json::from_str::<Value>(&line?) else {
+            continue;
+        };
+        if message["reason"] != "compiler-message" {
+            continue;
+        }
+        // the diagnostics of the files shared by several targets are emitted once per target
+        if let Some(result) = result(&message["message"], &rules)
+            && seen.insert(result.to_string())
+        {
+            results.push(result);
+        }
+    }
+
+    let log = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "clippy",
+                    "version": version,
+                    "informationUri": "https://github.com/rust-lang/rust-clippy",
+                    "rules": rules.rules,
+                },
+            },
+            "originalUriBaseIds": {
+                SRCROOT: { "uri": file_uri(root, true) },
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    });
+    serde_json::to_writer_pretty(&mut *output, &log)?;
+    writeln!(output)
+}
+
+/// Converts a diagnostic of rustc to a SARIF result, if it points to some code.
+fn result(diagnostic: &Value, rules: &Rules) -> Option<Value> {
+    let level = match diagnostic["level"].as_str()? {
+        "error" => "error",
+        "warning" => "warning",
+        _ => "note",
+    };
+    let span = diagnostic["spans"]
+        .as_array()?
+        .iter()
+        .find(|span| span["is_primary"] == true)?;
+
+    let mut result = json!({
+        "level": level,
+        "message": { "text": diagnostic["message"] },
+        "locations": [{ "physicalLocation": physical_location(span)? }],
+    });
+    if let Some(code) = diagnostic["code"]["code"].as_str() {
+        result["ruleId"] = json!(code);
+        if let Some(index) = rules.indices.get(code) {
+            result["ruleIndex"] = json!(index);
+        }
+    }
+    let fixes: Vec<_> = diagnostic["children"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .chain([diagnostic])
+        .filter_map(fix)
+        .collect();
+    if !fixes.is_empty() {
+        result["fixes"] = json!(fixes);
+    }
+    Some(result)
+}
+
+/// Converts the machine applicable suggestions of a diagnostic to a SARIF fix.
+fn fix(diagnostic: &Value) -> Option<Value> {
+    let mut changes: Vec<(Value, Vec<Value>)> = Vec::new();
+    for span in diagnostic["spans"].as_array()? {
+        if span["suggestion_applicability"] != "MachineApplicable" {
+            continue;
+        }
+        let Some(replacement) = span["suggested_replacement"].as_str() else {
+            continue;
+        };
+        let location = artifact_location(span["file_name"].as_str()?);
+        let replacement = json!({
+            "deletedRegion": region(span)?,
+            "insertedContent": { "text": replacement },
+        });
+        match changes
+            .iter_mut()
+            .find(|(change_location, _)| *change_location == location)
+        {
+            Some((_, replacements)) => replacements.push(replacement),
+            None => changes.push((location, vec![replacement])),
+        }
+    }
+    if changes.is_empty() {
+        return None;
+    }
+    Some(json!({
+        "description": { "text": diagnostic["message"] },
+        "artifactChanges": changes
+            .into_iter()
+            .map(|(location, replacements)| json!({
+                "artifactLocation": location,
+                "replacements": replacements,
+            }))
+            .collect::<Vec<_>>(),
+    }))
+}
+
+fn physical_location(span: &Value) -> Option<Value> {
+    Some(json!({
+        "artifactLocation": artifact_location(span["file_name"].as_str()?),
+        "region": region(span)?,
+    }))
+}
+
+fn artifact_location(file_name: &str) -> Value {
+    let path = Path::new(file_name);
+    if path.is_absolute() {
+        json!({ "uri": file_uri(path, false) })
+    } else {
+        json!({ "uri": file_name.replace('\\', "/"), "uriBaseId": SRCROOT })
+    }
+}
+
+fn region(span: &Value) -> Option<Value> {
+    Some(json!({
+        "startLine": span["line_start"].as_u64()?,
+        "startColumn": span["column_start"].as_u64()?,
+        "endLine": span["line_end"].as_u64()?,
+        "endColumn": span["column_end"].as_u64()?,
+    }))
+}
+
+/// Returns the `file://` URI of an absolute path, ending with `/` for a directory.
+fn file_uri(path: &Path, dir: bool) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let separator = if path.starts_with('/') { "" } else { "/" };
+    let trailing = if dir && !path.ends_with('/') { "/" } else { "" };
+    format!("file://{}{}{}", separator, path, trailing)
+}
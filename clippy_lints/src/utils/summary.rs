@@ -0,0 +1,282 @@
+//! Counts the lints emitted in each crate of a workspace, see `cargo clippy --summary`.
+//!
+//! As for the baseline, each driver writes the counts of the crate it lints to a fragment file in a
+//! directory created by `cargo-clippy`, and [`merge`] writes the summary once Cargo is done.
+
+use clippy_utils::diagnostics::{register_emission_hook, Emission, EmissionHook};
+use rustc_lint::Level;
+use rustc_middle::lint::LevelAndSource;
+use rustc_session::config::CrateType;
+use rustc_session::Session;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::sync::Mutex;
+
+/// The number of lints emitted, by lint name.
+type Counts = BTreeMap<String, usize>;
+
+static COUNTS: Mutex<Option<Counts>> = Mutex::new(None);
+
+struct SummaryHook;
+
+impl EmissionHook for SummaryHook {
+    fn lint_level(&self, emission: &Emission<'_>, level: LevelAndSource) -> Option<LevelAndSource> {
+        // expected lints aren't emitted
+        if matches!(level.0, Level::Warn | Level::ForceWarn(_) | Level::Deny | Level::Forbid) {
+            if let Some(counts) = COUNTS.lock().unwrap().as_mut() {
+                *counts.entry(emission.lint.name_lower()).or_default() += 1;
+            }
+        }
+        None
+    }
+}
+
+/// The counts of a crate.
+#[derive(Serialize, Deserialize)]
+struct Fragment {
+    #[serde(rename = "crate")]
+    krate: String,
+    lints: Counts,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct Summary {
+    /// The number of lints emitted in all the crates.
+    total: usize,
+    /// The number of each lint emitted in all the crates.
+    lints: Counts,
+    /// The number of each lint emitted in each crate.
+    crates: BTreeMap<String, Counts>,
+    /// The counts that differ from the summary compared to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    changes: Vec<Change>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Change {
+    #[serde(rename = "crate")]
+    krate: String,
+    lint: String,
+    previous: usize,
+    current: usize,
+}
+
+/// Describes the crate being compiled, like `name (lib)` or `name (test)`, to tell apart the
+/// crates of the same package.
+pub fn crate_description(sess: &Session) -> String {
+    let name = sess.opts.crate_name.as_deref().unwrap_or("crate");
+    let kind = if sess.opts.test {
+        "test".to_string()
+    } else {
+        match sess.crate_types().first() {
+            Some(CrateType::Rlib) | None => "lib".to_string(),
+            Some(crate_type) => crate_type.to_string(),
+        }
+    };
+    format!("{} ({})", name, kind)
+}
+
+/// Registers the emission hook counting the lints. This must be called after the other emission
+/// hooks are registered, so that the lints allowed by them aren't counted.
+pub fn register() {
+    *COUNTS.lock().unwrap() = Some(Counts::new());
+    register_emission_hook(Box::new(SummaryHook));
+}
+
+/// Writes the counts of the crate to a fragment in `dir`, once all the lints are emitted.
+pub fn finish(sess: &Session, dir: &Path) {
+    let Some(lints) = COUNTS.lock().unwrap().take() else {
+        return;
+    };
+    let fragment = Fragment {
+        krate: crate_description(sess),
+        lints,
+    };
+    let path = dir.join(format!(
+        "{}-{}.json",
+        sess.opts.crate_name.as_deref().unwrap_or("crate"),
+        process::id()
+    ));
+    if let Err(e) = fs::write(&path, serde_json::to_string(&fragment).unwrap()) {
+        sess.err(&format!("error writing `{}`: {}", path.display(), e));
+    }
+}
+
+fn read_fragments(dir: &Path) -> Result<Vec<Fragment>, String> {
+    let mut fragments = Vec::new();
+    for file in fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let path = file.map_err(|e| e.to_string())?.path();
+        let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        fragments.push(serde_json::from_str(&content).map_err(|e| format!("`{}`: {}", path.display(), e))?);
+    }
+    Ok(fragments)
+}
+
+fn read_summary(path: &Path) -> Result<Summary, String> {
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| e.to_string())
+}
+
+/// Returns the counts of `current` that differ from the ones of `previous`.
+fn changes(previous: &Summary, current: &Summary) -> Vec<Change> {
+    let empty = Counts::new();
+    let crates: BTreeSet<_> = previous.crates.keys().chain(current.crates.keys()).collect();
+    let mut changes = Vec::new();
+    for krate in crates {
+        let previous = previous.crates.get(krate).unwrap_or(&empty);
+        let current = current.crates.get(krate).unwrap_or(&empty);
+        let lints: BTreeSet<_> = previous.keys().chain(current.keys()).collect();
+        for lint in lints {
+            let previous = previous.get(lint).copied().unwrap_or(0);
+            let current = current.get(lint).copied().unwrap_or(0);
+            if previous != current {
+                changes.push(Change {
+                    krate: krate.clone(),
+                    lint: lint.clone(),
+                    previous,
+                    current,
+                });
+            }
+        }
+    }
+    changes
+}
+
+/// Prints a table of the counts of each lint, along with the previous counts if any.
+fn print_table(summary: &Summary, previous: Option<&Summary>) {
+    let lints: BTreeSet<_> = summary
+        .lints
+        .keys()
+        .chain(previous.into_iter().flat_map(|previous| previous.lints.keys()))
+        .collect();
+    let mut rows: Vec<_> = lints
+        .into_iter()
+        .map(|lint| {
+            let count = summary.lints.get(lint).copied().unwrap_or(0);
+            let previous = previous.map(|previous| previous.lints.get(lint).copied().unwrap_or(0));
+            (lint, count, previous)
+        })
+        .collect();
+    rows.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    if previous.is_some() {
+        eprintln!("| {:<50} | count | previous |", "lint");
+        eprintln!("| {} | ----- | -------- |", "-".repeat(50));
+    } else {
+        eprintln!("| {:<50} | count |", "lint");
+        eprintln!("| {} | ----- |", "-".repeat(50));
+    }
+    for (lint, count, previous) in rows {
+        match previous {
+            Some(previous) => eprintln!("| {:<50} | {:>5} | {:>8} |", lint, count, previous),
+            None => eprintln!("| {:<50} | {:>5} |", lint, count),
+        }
+    }
+}
+
+/// Writes the summary at `path` from the fragments in `dir`, compared to the summary at `base`, or
+/// to the previous summary at `path` if there is no `base`. Returns whether it succeeded.
+pub fn merge(dir: &Path, path: &Path, base: Option<&Path>) -> bool {
+    let fragments = match read_fragments(dir) {
+        Ok(fragments) => fragments,
+        Err(e) => {
+            eprintln!("error: could not read the lint counts: {}", e);
+            return false;
+        },
+    };
+    let previous = match base {
+        Some(base) => match read_summary(base) {
+            Ok(previous) => Some(previous),
+            Err(e) => {
+                eprintln!("error: could not read the summary `{}`: {}", base.display(), e);
+                return false;
+            },
+        },
+        None => read_summary(path).ok(),
+    };
+
+    let mut summary = Summary::default();
+    for fragment in fragments {
+        let counts = summary.crates.entry(fragment.krate).or_default();
+        for (lint, count) in fragment.lints {
+            *summary.lints.entry(lint.clone()).or_default() += count;
+            *counts.entry(lint).or_default() += count;
+            summary.total += count;
+        }
+    }
+    if let Some(previous) = &previous {
+        summary.changes = changes(previous, &summary);
+    }
+
+    print_table(&summary, previous.as_ref());
+    eprintln!();
+    let mut totals = format!(
+        "{} Clippy lint{} emitted in {} crate{}",
+        summary.total,
+        if summary.total == 1 { "" } else { "s" },
+        summary.crates.len(),
+        if summary.crates.len() == 1 { "" } else { "s" }
+    );
+    if let Some(previous) = &previous {
+        totals.push_str(&format!(", {} before", previous.total));
+    }
+    eprintln!("{}", totals);
+    match fs::write(path, serde_json::to_string_pretty(&summary).unwrap() + "\n") {
+        Ok(()) => {
+            eprintln!("Wrote the summary to `{}`", path.display());
+            true
+        },
+        Err(e) => {
+            eprintln!("error: could not write the summary `{}`: {}", path.display(), e);
+            false
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{changes, Counts, Summary};
+
+    fn summary(crates: &[(&str, &[(&str, usize)])]) -> Summary {
+        let mut summary = Summary::default();
+        for (krate, lints) in crates {
+            let counts: Counts = lints
+                .iter()
+                .map(|(lint, count)| ((*lint).to_string(), *count))
+                .collect();
+            summary.crates.insert((*krate).to_string(), counts);
+        }
+        summary
+    }
+
+    #[test]
+    fn compare() {
+        let previous = summary(&[
+            ("a (lib)", &[("clippy::needless_return", 2), ("clippy::unwrap_used", 1)]),
+            ("b (lib)", &[("clippy::unwrap_used", 3)]),
+        ]);
+        let current = summary(&[
+            (
+                "a (lib)",
+                &[("clippy::needless_return", 2), ("clippy::let_and_return", 1)],
+            ),
+            ("c (bin)", &[("clippy::unwrap_used", 1)]),
+        ]);
+        let changes: Vec<_> = changes(&previous, &current)
+            .into_iter()
+            .map(|change| (change.krate, change.lint, change.previous, change.current))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("a (lib)".to_string(), "clippy::let_and_return".to_string(), 0, 1),
+                ("a (lib)".to_string(), "clippy::unwrap_used".to_string(), 1, 0),
+                ("b (lib)".to_string(), "clippy::unwrap_used".to_string(), 3, 0),
+                ("c (bin)".to_string(), "clippy::unwrap_used".to_string(), 0, 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! Measures the time spent in each of Clippy's lint passes, see `cargo clippy --timings`.
+//!
+//! As for the baseline, each driver writes the times of the crate it lints to a fragment file in a
+//! directory created by `cargo-clippy`, and [`report`] sums them up once Cargo is done.
+
+use crate::utils::pass_hooks::{wrap_passes, PassHook};
+use crate::utils::summary::crate_description;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_lint::LintStore;
+use rustc_session::Session;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use std::{fs, process};
+
+/// The number of passes listed in the table printed by [`report`], the JSON file lists all of them.
+const MAX_TABLE_PASSES: usize = 20;
+
+#[derive(Default)]
+struct TimingHook {
+    /// The time spent in each lint pass, by name.
+    times: Mutex<FxHashMap<&'static str, Duration>>,
+}
+
+impl PassHook for TimingHook {
+    fn around(&self, pass: &'static str, _: &'static str, call: &mut dyn FnMut()) {
+        let start = Instant::now();
+        call();
+        let elapsed = start.elapsed();
+        *self.times.lock().unwrap().entry(pass).or_default() += elapsed;
+    }
+}
+
+static HOOK: Mutex<Option<Arc<TimingHook>>> = Mutex::new(None);
+
+#[derive(Serialize, Deserialize)]
+struct PassTime {
+    pass: String,
+    secs: f64,
+}
+
+/// The times of the passes of a crate.
+#[derive(Serialize, Deserialize)]
+struct CrateTimes {
+    /// The name of the crate, followed by its type, see [`crate_description`].
+    #[serde(rename = "crate")]
+    krate: String,
+    secs: f64,
+    passes: Vec<PassTime>,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    secs: f64,
+    passes: Vec<PassTime>,
+    crates: &'a [CrateTimes],
+}
+
+/// Wraps the lint passes registered in `store` to measure the time spent in each of them. This must
+/// be called after all the lint passes are registered.
+pub fn register(store: &mut LintStore) {
+    let timing_hook = Arc::new(TimingHook::default());
+    let hook: Arc<dyn PassHook> = timing_hook.clone();
+    wrap_passes(store, &hook);
+    *HOOK.lock().unwrap() = Some(timing_hook);
+}
+
+/// Writes the times of the crate to a fragment in `dir`, once all the passes have run.
+pub fn finish(sess: &Session, dir: &Path) {
+    let Some(hook) = HOOK.lock().unwrap().take() else {
+        return;
+    };
+    let mut passes: Vec<_> = hook
+        .times
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(pass, time)| PassTime {
+            pass: (*pass).to_string(),
+            secs: time.as_secs_f64(),
+        })
+        .collect();
+    sort_passes(&mut passes);
+
+    let times = CrateTimes {
+        krate: crate_description(sess),
+        secs: passes.iter().map(|pass| pass.secs).sum(),
+        passes,
+    };
+    let path = dir.join(format!(
+        "{}-{}.json",
+        sess.opts.crate_name.as_deref().unwrap_or("crate"),
+        process::id()
+    ));
+    if let Err(e) = fs::write(&path, serde_json::to_string(&times).unwrap()) {
+        sess.err(&format!("error writing `{}`: {}", path.display(), e));
+    }
+}
+
+fn sort_passes(passes: &mut [PassTime]) {
+    passes.sort_by(|a, b| b.secs.total_cmp(&a.secs).then_with(|| a.pass.cmp(&b.pass)));
+}
+
+fn read_fragments(dir: &Path) -> Result<Vec<CrateTimes>, String> {
+    let mut crates = Vec::new();
+    for file in fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let path = file.map_err(|e| e.to_string())?.path();
+        let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        crates.push(serde_json::from_str(&content).map_err(|e| format!("`{}`: {}", path.display(), e))?);
+    }
+    Ok(crates)
+}
+
+/// Prints a table of the time spent in each lint pass and in each crate from the fragments in
+/// `dir`, and writes all the times as JSON to `path`. Returns whether it succeeded.
+pub fn report(dir: &Path, path: &Path) -> bool {
+    let mut crates = match read_fragments(dir) {
+        Ok(crates) => crates,
+        Err(e) => {
+            eprintln!("error: could not read the timings: {}", e);
+            return false;
+        },
+    };
+    crates.sort_by(|a, b| b.secs.total_cmp(&a.secs).then_with(|| a.krate.cmp(&b.krate)));
+
+    let mut totals: FxHashMap<&str, f64> = FxHashMap::default();
+    for pass in crates.iter().flat_map(|krate| &krate.passes) {
+        *totals.entry(&pass.pass).or_default() += pass.secs;
+    }
+    let mut passes: Vec<_> = totals
+        .into_iter()
+        .map(|(pass, secs)| PassTime {
+            pass: pass.to_string(),
+            secs,
+        })
+        .collect();
+    sort_passes(&mut passes);
+    let secs: f64 = crates.iter().map(|krate| krate.secs).sum();
+
+    eprintln!("Time spent in Clippy's lint passes: {:.3}s", secs);
+    eprintln!();
+    eprintln!("{:>9}  {:>6}  pass", "time", "share");
+    for pass in passes.iter().take(MAX_TABLE_PASSES) {
+        eprintln!(
+            "{:>8.3}s  {:>5.1}%  {}",
+            pass.secs,
+            pass.secs * 100. / secs.max(f64::MIN_POSITIVE),
+            pass.pass
+        );
+    }
+    if passes.len() > MAX_TABLE_PASSES {
+        eprintln!("{:>17}  ... {} more", "", passes.len() - MAX_TABLE_PASSES);
+    }
+    eprintln!();
+    eprintln!("{:>9}  crate", "time");
+    for krate in &crates {
+        eprintln!("{:>8.3}s  {}", krate.secs, krate.krate);
+    }
+    eprintln!();
+
+    let report = Report {
+        secs,
+        passes,
+        crates: &crates,
+    };
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(path, serde_json::to_string_pretty(&report).unwrap() + "\n"));
+    match written {
+        Ok(()) => {
+            eprintln!("Wrote the timings of all the passes to `{}`", path.display());
+            true
+        },
+        Err(e) => {
+            eprintln!("error: could not write `{}`: {}", path.display(), e);
+            false
+        },
+    }
+}
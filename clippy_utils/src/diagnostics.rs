@@ -8,23 +8,162 @@
 //! Thank you!
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
-use rustc_errors::{Applicability, Diagnostic, MultiSpan};
+use rustc_errors::{Applicability, Diagnostic, LintDiagnosticBuilder, MultiSpan};
 use rustc_hir::HirId;
-use rustc_lint::{LateContext, Lint, LintContext};
+use rustc_lint::{EarlyContext, LateContext, Lint, LintContext};
+use rustc_middle::lint::{struct_lint_level, LevelAndSource};
+use rustc_middle::ty::TyCtxt;
+use rustc_session::Session;
 use rustc_span::source_map::Span;
 use std::env;
+use std::sync::RwLock;
+
+/// A lint about to be emitted by Clippy, see [`EmissionHook`].
+pub struct Emission<'a> {
+    pub sess: &'a Session,
+    pub lint: &'static Lint,
+    pub span: Span,
+    /// The path of the item the lint is emitted in, see [`item_path`]. This is only known for the
+    /// lints emitted by late lint passes.
+    pub item_path: Option<String>,
+}
+
+/// A hook called before Clippy emits a lint, see [`register_emission_hook`].
+pub trait EmissionHook: Send + Sync {
+    /// Returns the level at which the lint should be emitted, if it differs from `level`, the one
+    /// rustc computed from the lint attributes and command line flags.
+    fn lint_level(&self, emission: &Emission<'_>, level: LevelAndSource) -> Option<LevelAndSource>;
+}
+
+static EMISSION_HOOKS: RwLock<Vec<Box<dyn EmissionHook>>> = RwLock::new(Vec::new());
+
+/// Registers a hook that can change the level of every lint emitted through the functions of this
+/// module. The hooks are called in the order they are registered.
+pub fn register_emission_hook(hook: Box<dyn EmissionHook>) {
+    EMISSION_HOOKS.write().unwrap().push(hook);
+}
+
+/// A [`LintContext`] that can tell where the level of a lint at the current node comes from.
+pub trait LintLevelContext: LintContext {
+    fn lint_level_and_source(&self, lint: &'static Lint) -> LevelAndSource;
+
+    /// The path of the item containing the current node, if known.
+    fn item_path(&self) -> Option<String>;
+}
+
+impl LintLevelContext for LateContext<'_> {
+    fn lint_level_and_source(&self, lint: &'static Lint) -> LevelAndSource {
+        self.tcx.lint_level_at_node(lint, self.last_node_with_lint_attrs)
+    }
+
+    fn item_path(&self) -> Option<String> {
+        Some(item_path(self.tcx, self.last_node_with_lint_attrs))
+    }
+}
+
+impl LintLevelContext for EarlyContext<'_> {
+    fn lint_level_and_source(&self, lint: &'static Lint) -> LevelAndSource {
+        self.builder.lint_level(lint)
+    }
+
+    fn item_path(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Returns the path of the item containing `hir_id`, without the crate name and the
+/// disambiguators of anonymous items like impls, so that it doesn't change when unrelated items
+/// are added, e.g. `module::{{impl}}::method`.
+pub fn item_path(tcx: TyCtxt<'_>, hir_id: HirId) -> String {
+    tcx.def_path(hir_id.owner.to_def_id())
+        .data
+        .iter()
+        .map(|data| data.data.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// Asks the registered emission hooks for the level of `lint` at `sp`. `level` and `item_path`
+/// are only computed when a hook is registered.
+fn hooked_level(
+    sess: &Session,
+    lint: &'static Lint,
+    sp: &MultiSpan,
+    level: impl FnOnce() -> LevelAndSource,
+    item_path: impl FnOnce() -> Option<String>,
+) -> Option<LevelAndSource> {
+    let hooks = EMISSION_HOOKS.read().unwrap();
+    if hooks.is_empty() {
+        return None;
+    }
+    let emission = Emission {
+        sess,
+        lint,
+        span: sp.primary_span()?,
+        item_path: item_path(),
+    };
+    let level = level();
+    let hooked = hooks
+        .iter()
+        .fold(level, |level, hook| hook.lint_level(&emission, level).unwrap_or(level));
+    (hooked != level).then_some(hooked)
+}
+
+fn struct_span_lint<C: LintLevelContext>(
+    cx: &C,
+    lint: &'static Lint,
+    sp: impl Into<MultiSpan>,
+    decorate: impl for<'a> FnOnce(LintDiagnosticBuilder<'a, ()>),
+) {
+    let sp = sp.into();
+    match hooked_level(
+        cx.sess(),
+        lint,
+        &sp,
+        || cx.lint_level_and_source(lint),
+        || cx.item_path(),
+    ) {
+        Some((level, src)) => struct_lint_level(cx.sess(), lint, level, src, Some(sp), decorate),
+        None => cx.struct_span_lint(lint, sp, decorate),
+    }
+}
+
+fn struct_span_lint_hir(
+    cx: &LateContext<'_>,
+    lint: &'static Lint,
+    hir_id: HirId,
+    sp: impl Into<MultiSpan>,
+    decorate: impl for<'a> FnOnce(LintDiagnosticBuilder<'a, ()>),
+) {
+    let sp = sp.into();
+    match hooked_level(
+        cx.sess(),
+        lint,
+        &sp,
+        || cx.tcx.lint_level_at_node(lint, hir_id),
+        || Some(item_path(cx.tcx, hir_id)),
+    ) {
+        Some((level, src)) => struct_lint_level(cx.sess(), lint, level, src, Some(sp), decorate),
+        None => cx.tcx.struct_span_lint_hir(lint, hir_id, sp, decorate),
+    }
+}
+
+/// Returns the URL of the documentation of the Clippy lint `name`, without the `clippy::` prefix.
+pub fn docs_url(name: &str) -> String {
+    format!(
+        "https://rust-lang.github.io/rust-clippy/{}/index.html#{}",
+        &option_env!("RUST_RELEASE_NUM").map_or("master".to_string(), |n| {
+            // extract just major + minor version and ignore patch versions
+            format!("rust-{}", n.rsplit_once('.').unwrap().1)
+        }),
+        name
+    )
+}
 
 fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
         if let Some(lint) = lint.name_lower().strip_prefix("clippy::") {
-            diag.help(&format!(
-                "for further information visit https://rust-lang.github.io/rust-clippy/{}/index.html#{}",
-                &option_env!("RUST_RELEASE_NUM").map_or("master".to_string(), |n| {
-                    // extract just major + minor version and ignore patch versions
-                    format!("rust-{}", n.rsplit_once('.').unwrap().1)
-                }),
-                lint
-            ));
+            diag.help(&format!("for further information visit {}", docs_url(lint)));
         }
     }
 }
@@ -46,8 +185,8 @@ fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
 /// 17 |     std::mem::forget(seven);
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
-pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
-    cx.struct_span_lint(lint, sp, |diag| {
+pub fn span_lint<T: LintLevelContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
+    struct_span_lint(cx, lint, sp, |diag| {
         let mut diag = diag.build(msg);
         docs_link(&mut diag, lint);
         diag.emit();
@@ -74,7 +213,7 @@ pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<Mult
 ///    |
 ///    = help: consider using `f64::NAN` if you would like a constant representing NaN
 /// ```
-pub fn span_lint_and_help<'a, T: LintContext>(
+pub fn span_lint_and_help<'a, T: LintLevelContext>(
     cx: &'a T,
     lint: &'static Lint,
     span: impl Into<MultiSpan>,
@@ -82,7 +221,7 @@ pub fn span_lint_and_help<'a, T: LintContext>(
     help_span: Option<Span>,
     help: &str,
 ) {
-    cx.struct_span_lint(lint, span, |diag| {
+    struct_span_lint(cx, lint, span, |diag| {
         let mut diag = diag.build(msg);
         if let Some(help_span) = help_span {
             diag.span_help(help_span, help);
@@ -117,7 +256,7 @@ pub fn span_lint_and_help<'a, T: LintContext>(
 /// 10 |     forget(&SomeStruct);
 ///    |            ^^^^^^^^^^^
 /// ```
-pub fn span_lint_and_note<'a, T: LintContext>(
+pub fn span_lint_and_note<'a, T: LintLevelContext>(
     cx: &'a T,
     lint: &'static Lint,
     span: impl Into<MultiSpan>,
@@ -125,7 +264,7 @@ pub fn span_lint_and_note<'a, T: LintContext>(
     note_span: Option<Span>,
     note: &str,
 ) {
-    cx.struct_span_lint(lint, span, |diag| {
+    struct_span_lint(cx, lint, span, |diag| {
         let mut diag = diag.build(msg);
         if let Some(note_span) = note_span {
             diag.span_note(note_span, note);
@@ -143,11 +282,11 @@ pub fn span_lint_and_note<'a, T: LintContext>(
 /// If you change the signature, remember to update the internal lint `CollapsibleCalls`
 pub fn span_lint_and_then<C, S, F>(cx: &C, lint: &'static Lint, sp: S, msg: &str, f: F)
 where
-    C: LintContext,
+    C: LintLevelContext,
     S: Into<MultiSpan>,
     F: FnOnce(&mut Diagnostic),
 {
-    cx.struct_span_lint(lint, sp, |diag| {
+    struct_span_lint(cx, lint, sp, |diag| {
         let mut diag = diag.build(msg);
         f(&mut diag);
         docs_link(&mut diag, lint);
@@ -156,7 +295,7 @@ where
 }
 
 pub fn span_lint_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, sp: Span, msg: &str) {
-    cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
+    struct_span_lint_hir(cx, lint, hir_id, sp, |diag| {
         let mut diag = diag.build(msg);
         docs_link(&mut diag, lint);
         diag.emit();
@@ -171,7 +310,7 @@ pub fn span_lint_hir_and_then(
     msg: &str,
     f: impl FnOnce(&mut Diagnostic),
 ) {
-    cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
+    struct_span_lint_hir(cx, lint, hir_id, sp, |diag| {
         let mut diag = diag.build(msg);
         f(&mut diag);
         docs_link(&mut diag, lint);
@@ -199,7 +338,7 @@ pub fn span_lint_hir_and_then(
 ///     = note: `-D fold-any` implied by `-D warnings`
 /// ```
 #[cfg_attr(feature = "internal", allow(clippy::collapsible_span_lint_calls))]
-pub fn span_lint_and_sugg<'a, T: LintContext>(
+pub fn span_lint_and_sugg<'a, T: LintLevelContext>(
     cx: &'a T,
     lint: &'static Lint,
     sp: Span,
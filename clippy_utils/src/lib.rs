@@ -78,7 +78,7 @@ use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::unhash::UnhashMap;
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
-use rustc_hir::def_id::{CrateNum, DefId, LocalDefId, CRATE_DEF_ID};
+use rustc_hir::def_id::{CrateNum, DefId, LocalDefId, CRATE_DEF_ID, LOCAL_CRATE};
 use rustc_hir::hir_id::{HirIdMap, HirIdSet};
 use rustc_hir::intravisit::{walk_expr, FnKind, Visitor};
 use rustc_hir::LangItem::{OptionNone, ResultErr, ResultOk};
@@ -91,6 +91,7 @@ use rustc_hir::{
 use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::{LateContext, Level, Lint, LintContext};
 use rustc_middle::hir::place::PlaceBase;
+use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty as rustc_ty;
 use rustc_middle::ty::adjustment::{Adjust, Adjustment, AutoBorrow};
 use rustc_middle::ty::binding::BindingMode;
@@ -568,6 +569,49 @@ pub fn def_path_res(cx: &LateContext<'_>, path: &[&str]) -> Res {
     Res::Err
 }
 
+/// Returns the path of `def_id` beginning with the name of its crate, including for the local
+/// crate, to match it against the paths written in the configuration.
+///
+/// The items of other crates have the shortest path they are exported at, like `tokio::spawn`
+/// rather than `tokio::task::spawn`.
+pub fn def_path_with_crate(cx: &LateContext<'_>, def_id: DefId) -> String {
+    let path = cx.tcx.def_path_str(def_id);
+    if !def_id.is_local() {
+        path
+    } else if path.is_empty() {
+        cx.tcx.crate_name(LOCAL_CRATE).to_string()
+    } else {
+        format!("{}::{}", cx.tcx.crate_name(LOCAL_CRATE), path)
+    }
+}
+
+/// A path or a method call, which uses the item it resolves to, see [`used_def`].
+#[derive(Clone, Copy)]
+pub enum DefUse<'tcx> {
+    Path(&'tcx Path<'tcx>),
+    MethodCall(&'tcx Expr<'tcx>),
+}
+
+/// Returns the item `def_use` uses, with the span of the path or of the name of the method. The
+/// methods have no resolved path, unlike the associated functions whose type is used through a
+/// path.
+///
+/// The paths and method calls written in an external macro are left out, they are the business of
+/// the crate of the macro.
+pub fn used_def(cx: &LateContext<'_>, def_use: DefUse<'_>) -> Option<(DefId, Span)> {
+    let (def_id, span) = match def_use {
+        DefUse::Path(path) => (path.res.opt_def_id()?, path.span),
+        DefUse::MethodCall(expr) => match expr.kind {
+            ExprKind::MethodCall(segment, ..) => (
+                cx.typeck_results().type_dependent_def_id(expr.hir_id)?,
+                segment.ident.span,
+            ),
+            _ => return None,
+        },
+    };
+    (!in_external_macro(cx.sess(), span)).then_some((def_id, span))
+}
+
 /// Convenience function to get the `DefId` of a trait by path.
 /// It could be a trait or trait alias.
 pub fn get_trait_def_id(cx: &LateContext<'_>, path: &[&str]) -> Option<DefId> {
@@ -236,6 +236,61 @@ pub fn count_match_end(str1: &str, str2: &str) -> StrCount {
         })
 }
 
+/// Returns whether `text` matches the glob `pattern`, where both are made of segments delimited
+/// by `separator`.
+///
+/// In a segment, `*` matches any sequence of characters and `?` matches a single character. A `**`
+/// segment matches any number of segments, including none.
+///
+/// ```rust,ignore
+/// assert!(glob_match("src/**/*.rs", "src/a/b/c.rs", "/"));
+/// assert!(glob_match("std::*::Vec", "std::vec::Vec", "::"));
+/// assert!(!glob_match("std::*", "std::vec::Vec", "::"));
+/// ```
+#[must_use]
+pub fn glob_match(pattern: &str, text: &str, separator: &str) -> bool {
+    let pattern: Vec<_> = pattern.split(separator).collect();
+    let text: Vec<_> = text.split(separator).collect();
+    segments_match(&pattern, &text)
+}
+
+/// Returns whether `text` matches `pattern`, where `*` matches any sequence of characters and `?`
+/// matches a single character.
+///
+/// ```rust,ignore
+/// assert!(wildcard_match("/*", "/etc/passwd"));
+/// assert!(!wildcard_match("?", "ab"));
+/// ```
+#[must_use]
+pub fn wildcard_match(pattern: &str, text: &str) -> bool {
+    segment_match(pattern.as_bytes(), text.as_bytes())
+}
+
+fn segments_match(pattern: &[&str], text: &[&str]) -> bool {
+    match (pattern, text) {
+        ([], []) => true,
+        (["**", rest @ ..], _) => (0..=text.len()).any(|skip| segments_match(rest, &text[skip..])),
+        ([first, rest @ ..], [segment, text_rest @ ..]) => {
+            segment_match(first.as_bytes(), segment.as_bytes()) && segments_match(rest, text_rest)
+        },
+        _ => false,
+    }
+}
+
+fn segment_match(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern, text) {
+        ([], []) => true,
+        ([b'*', rest @ ..], _) => (0..=text.len()).any(|skip| segment_match(rest, &text[skip..])),
+        ([b'?', rest @ ..], [_, text_rest @ ..]) => {
+            // `?` matches a whole character, skip its continuation bytes
+            let len = text_rest.iter().take_while(|&&b| b & 0xC0 == 0x80).count();
+            segment_match(rest, &text_rest[len..])
+        },
+        ([p, rest @ ..], [t, text_rest @ ..]) => p == t && segment_match(rest, text_rest),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -322,4 +377,35 @@ mod test {
             vec!["\u{f6}\u{f6}", "Aab", "A", "Bcd"]
         );
     }
+
+    #[test]
+    fn glob_match_segments() {
+        assert!(glob_match("src/main.rs", "src/main.rs", "/"));
+        assert!(glob_match("src/*.rs", "src/main.rs", "/"));
+        assert!(glob_match("src/ma?n.rs", "src/main.rs", "/"));
+        assert!(glob_match("src/\u{f6}?.rs", "src/\u{f6}\u{f6}.rs", "/"));
+        assert!(!glob_match("src/*.rs", "src/bin/main.rs", "/"));
+        assert!(!glob_match("src/*.rs", "src/main.rs.bak", "/"));
+        assert!(!glob_match("src", "src/main.rs", "/"));
+    }
+
+    #[test]
+    fn glob_match_recursive() {
+        assert!(glob_match("src/**", "src/bin/main.rs", "/"));
+        assert!(glob_match("src/**/*.rs", "src/main.rs", "/"));
+        assert!(glob_match("src/**/*.rs", "src/bin/x/main.rs", "/"));
+        assert!(glob_match("**/tests/**", "/home/crate/tests/ui/a.rs", "/"));
+        assert!(!glob_match("src/**/*.rs", "tests/main.rs", "/"));
+        assert!(glob_match("core::**", "core::ptr::null", "::"));
+        assert!(!glob_match("core::*", "core::ptr::null", "::"));
+    }
+
+    #[test]
+    fn wildcard() {
+        assert!(wildcard_match("/*", "/etc/passwd"));
+        assert!(wildcard_match("sh", "sh"));
+        assert!(wildcard_match("*sh", "bash"));
+        assert!(!wildcard_match("sh", "bash"));
+        assert!(!wildcard_match("?", "ab"));
+    }
 }
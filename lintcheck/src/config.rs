@@ -16,6 +16,12 @@ fn get_clap_config() -> ArgMatches {
                 .value_name("CRATES-SOURCES-TOML-PATH")
                 .long("crates-toml")
                 .help("Set the path for a crates.toml where lintcheck should read the sources from"),
+            Arg::new("vendor-dir")
+                .action(ArgAction::Set)
+                .value_name("DIR")
+                .long("vendor-dir")
+                .value_parser(clap::value_parser!(PathBuf))
+                .help("Read the crates from a `cargo vendor` directory or a local registry, without network"),
             Arg::new("threads")
                 .action(ArgAction::Set)
                 .value_name("N")
@@ -26,6 +32,9 @@ fn get_clap_config() -> ArgMatches {
             Arg::new("fix")
                 .long("fix")
                 .help("Runs cargo clippy --fix and checks if all suggestions apply"),
+            Arg::new("verify-fix")
+                .long("verify-fix")
+                .help("Applies the suggestions to a copy of each crate, then reports the ones that break the build, add warnings or need another pass"),
             Arg::new("filter")
                 .long("filter")
                 .action(ArgAction::Append)
@@ -34,6 +43,20 @@ fn get_clap_config() -> ArgMatches {
             Arg::new("markdown")
                 .long("markdown")
                 .help("Change the reports table to use markdown links"),
+            Arg::new("time-threshold")
+                .long("time-threshold")
+                .action(ArgAction::Set)
+                .value_name("PERCENT")
+                .value_parser(clap::value_parser!(u64))
+                .default_value("25")
+                .help("Warn about the crates clippy got more than PERCENT slower on since the previous run"),
+            Arg::new("diff")
+                .long("diff")
+                .action(ArgAction::Set)
+                .number_of_values(2)
+                .value_names(&["OLD", "NEW"])
+                .value_parser(clap::value_parser!(PathBuf))
+                .help("Print the warnings added and removed between two JSON logs as markdown, without running clippy"),
         ])
         .get_matches()
 }
@@ -46,14 +69,30 @@ pub(crate) struct LintcheckConfig {
     pub sources_toml_path: PathBuf,
     /// we save the clippy lint results here
     pub lintcheck_results_path: PathBuf,
+    /// we save each warning as JSON here, to compare them with the ones of the next run
+    pub lintcheck_json_path: PathBuf,
+    /// we save the warnings added and removed since the previous run here, as markdown
+    pub lintcheck_diff_path: PathBuf,
+    /// we save the time and memory clippy took on each crate here, to compare them with the ones
+    /// of the next run
+    pub lintcheck_timings_path: PathBuf,
+    /// The `cargo vendor` directory or local registry to read the crates from instead of
+    /// downloading them
+    pub vendor_dir: Option<PathBuf>,
     /// Check only a specified package
     pub only: Option<String>,
     /// whether to just run --fix and not collect all the warnings
     pub fix: bool,
+    /// whether to check the code fixed by --fix instead of collecting the warnings
+    pub verify_fix: bool,
     /// A list of lints that this lintcheck run should focus on
     pub lint_filter: Vec<String>,
     /// Indicate if the output should support markdown syntax
     pub markdown: bool,
+    /// The increase of the time clippy takes on a crate, in percent, above which it is reported
+    pub time_threshold: u64,
+    /// The JSON logs to compare instead of running clippy
+    pub diff: Option<(PathBuf, PathBuf)>,
 }
 
 impl LintcheckConfig {
@@ -82,6 +121,9 @@ impl LintcheckConfig {
             filename.display(),
             if markdown { "md" } else { "txt" }
         ));
+        let lintcheck_json_path = PathBuf::from(format!("lintcheck-logs/{}_logs.json", filename.display()));
+        let lintcheck_diff_path = PathBuf::from(format!("lintcheck-logs/{}_diff.md", filename.display()));
+        let lintcheck_timings_path = PathBuf::from(format!("lintcheck-logs/{}_timings.json", filename.display()));
 
         // look at the --threads arg, if 0 is passed, ask rayon rayon how many threads it would spawn and
         // use half of that for the physical core count
@@ -111,14 +153,34 @@ impl LintcheckConfig {
             })
             .unwrap_or_default();
 
+        // cargo runs in the directories of the crates, so the vendor dir must be absolute
+        let vendor_dir = clap_config.get_one::<PathBuf>("vendor-dir").map(|dir| {
+            dir.canonicalize().unwrap_or_else(|e| {
+                eprintln!("ERROR: could not find the vendor dir {}: {}", dir.display(), e);
+                std::process::exit(1);
+            })
+        });
+
+        let diff = clap_config.get_many::<PathBuf>("diff").map(|mut paths| {
+            let old = paths.next().unwrap().clone();
+            (old, paths.next().unwrap().clone())
+        });
+
         LintcheckConfig {
             max_jobs,
             sources_toml_path,
             lintcheck_results_path,
+            lintcheck_json_path,
+            lintcheck_diff_path,
+            lintcheck_timings_path,
+            vendor_dir,
             only: clap_config.get_one::<String>("only").map(String::from),
             fix: clap_config.contains_id("fix"),
+            verify_fix: clap_config.contains_id("verify-fix"),
             lint_filter,
             markdown,
+            time_threshold: *clap_config.get_one::<u64>("time-threshold").unwrap(),
+            diff,
         }
     }
 }
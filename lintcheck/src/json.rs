@@ -0,0 +1,167 @@
+//! Stores the warnings of a run as JSON, and compares the warnings of two runs one by one rather
+//! than by lint counts, so that a change that swaps a warning for another one shows up.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::ClippyWarning;
+
+/// Writes the warnings of a run to the JSON log at `path`.
+pub(crate) fn write_warnings(path: &Path, warnings: &[ClippyWarning]) {
+    let json = serde_json::to_string_pretty(warnings).unwrap();
+    std::fs::write(path, json).unwrap_or_else(|e| panic!("failed to write {}: {}", path.display(), e));
+}
+
+/// Reads the warnings of a previous run from the JSON log at `path`, if there is one.
+pub(crate) fn read_warnings(path: &Path) -> Option<Vec<ClippyWarning>> {
+    let content = std::fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(warnings) => Some(warnings),
+        Err(e) => {
+            eprintln!(
+                "WARNING: could not read the previous warnings from {}: {}",
+                path.display(),
+                e
+            );
+            None
+        },
+    }
+}
+
+/// The warnings added and removed between two runs.
+pub(crate) struct Diff<'a> {
+    pub added: Vec<&'a ClippyWarning>,
+    pub removed: Vec<&'a ClippyWarning>,
+}
+
+/// Identifies a warning across runs. The position isn't part of it, so that the warnings of the
+/// lines moved by a change aren't reported.
+type Key<'a> = (&'a str, &'a str, &'a str, &'a str);
+
+fn key(warning: &ClippyWarning) -> Key<'_> {
+    (&warning.crate_name, &warning.file, &warning.lint_type, &warning.message)
+}
+
+fn group(warnings: &[ClippyWarning]) -> BTreeMap<Key<'_>, Vec<&ClippyWarning>> {
+    let mut groups: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for warning in warnings {
+        groups.entry(key(warning)).or_default().push(warning);
+    }
+    for group in groups.values_mut() {
+        group.sort_by_key(|warning| (warning.line, warning.column));
+    }
+    groups
+}
+
+/// Compares the warnings of the `old` and `new` runs. When a key has more warnings in one run than
+/// in the other, the last ones are the ones added or removed.
+pub(crate) fn diff<'a>(old: &'a [ClippyWarning], new: &'a [ClippyWarning]) -> Diff<'a> {
+    let old_groups = group(old);
+    let new_groups = group(new);
+    let mut diff = Diff {
+        added: Vec::new(),
+        removed: Vec::new(),
+    };
+    for (key, new_group) in &new_groups {
+        let old_len = old_groups.get(key).map_or(0, Vec::len);
+        diff.added.extend(new_group.iter().skip(old_len));
+    }
+    for (key, old_group) in &old_groups {
+        let new_len = new_groups.get(key).map_or(0, Vec::len);
+        diff.removed.extend(old_group.iter().skip(new_len));
+    }
+    diff
+}
+
+fn write_section(markdown: &mut String, title: &str, mut warnings: Vec<&ClippyWarning>) {
+    if warnings.is_empty() {
+        return;
+    }
+    warnings.sort_by(|a, b| {
+        (&a.lint_type, &a.crate_name, &a.file, a.line, a.column).cmp(&(
+            &b.lint_type,
+            &b.crate_name,
+            &b.file,
+            b.line,
+            b.column,
+        ))
+    });
+    let _ = writeln!(markdown, "\n## {} ({})", title, warnings.len());
+    for warning in warnings {
+        // the markdown file is written to `lintcheck-logs`
+        let mut link = warning.file.clone();
+        if !link.starts_with('$') {
+            link.insert_str(0, "../");
+        }
+        let _ = writeln!(
+            markdown,
+            "\n### `{}` in `{}`\n\n[`{}:{}:{}`]({}#L{})\n\n```\n{}\n```",
+            warning.lint_type,
+            warning.crate_name,
+            warning.file,
+            warning.line,
+            warning.column,
+            link,
+            warning.line,
+            warning.rendered.trim_end()
+        );
+    }
+}
+
+/// Describes `diff` in markdown, with the source snippets of the warnings, to be pasted into a
+/// review.
+pub(crate) fn to_markdown(diff: Diff<'_>) -> String {
+    let mut markdown = String::from("# Lintcheck diff\n\n");
+    if diff.added.is_empty() && diff.removed.is_empty() {
+        markdown.push_str("No warning was added or removed.\n");
+        return markdown;
+    }
+    let _ = writeln!(
+        markdown,
+        "{} warning(s) added, {} warning(s) removed.",
+        diff.added.len(),
+        diff.removed.len()
+    );
+    write_section(&mut markdown, "Added", diff.added);
+    write_section(&mut markdown, "Removed", diff.removed);
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff;
+    use crate::ClippyWarning;
+
+    fn warning(line: usize, lint_type: &str, message: &str) -> ClippyWarning {
+        ClippyWarning {
+            crate_name: "krate".into(),
+            file: "src/lib.rs".into(),
+            line,
+            column: 5,
+            lint_type: lint_type.into(),
+            message: message.into(),
+            rendered: String::new(),
+        }
+    }
+
+    #[test]
+    fn per_warning_diff() {
+        let old = [
+            warning(1, "clippy::needless_return", "unneeded `return` statement"),
+            warning(8, "clippy::needless_return", "unneeded `return` statement"),
+            warning(12, "clippy::unwrap_used", "used `unwrap()` on an `Option` value"),
+        ];
+        // the same counts by lint, with the lines moved
+        let new = [
+            warning(3, "clippy::needless_return", "unneeded `return` statement"),
+            warning(10, "clippy::needless_return", "unneeded `return` statement"),
+            warning(14, "clippy::unwrap_used", "used `unwrap()` on a `Result` value"),
+        ];
+        let diff = diff(&old, &new);
+        let added: Vec<_> = diff.added.iter().map(|w| (w.line, w.message.as_str())).collect();
+        let removed: Vec<_> = diff.removed.iter().map(|w| (w.line, w.message.as_str())).collect();
+        assert_eq!(added, [(14, "used `unwrap()` on a `Result` value")]);
+        assert_eq!(removed, [(12, "used `unwrap()` on an `Option` value")]);
+    }
+}
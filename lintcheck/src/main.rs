@@ -8,6 +8,9 @@
 #![allow(clippy::collapsible_else_if)]
 
 mod config;
+mod json;
+mod timing;
+mod verify_fix;
 
 use config::LintcheckConfig;
 
@@ -22,7 +25,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread;
 use std::time::Duration;
 
-use cargo_metadata::diagnostic::DiagnosticLevel;
+use cargo_metadata::diagnostic::{Diagnostic, DiagnosticLevel};
 use cargo_metadata::Message;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -40,6 +43,8 @@ const CARGO_CLIPPY_PATH: &str = "target/debug/cargo-clippy.exe";
 
 const LINTCHECK_DOWNLOADS: &str = "target/lintcheck/downloads";
 const LINTCHECK_SOURCES: &str = "target/lintcheck/sources";
+/// The cargo configuration of the directories the crates are checked in
+const LINTCHECK_CARGO_CONFIG: &str = "target/lintcheck/.cargo/config.toml";
 
 /// List of sources to check, loaded from a .toml file
 #[derive(Debug, Serialize, Deserialize)]
@@ -79,6 +84,15 @@ enum CrateSource {
         path: PathBuf,
         options: Option<Vec<String>>,
     },
+    /// A crate from crates.io or git read from a `cargo vendor` directory or a local registry, to
+    /// run offline
+    Vendor {
+        name: String,
+        /// `None` for a git source, since the vendored copy doesn't record the commit
+        version: Option<String>,
+        vendor_dir: PathBuf,
+        options: Option<Vec<String>>,
+    },
 }
 
 /// Represents the actual source code of a crate that we ran "cargo clippy" on
@@ -92,7 +106,7 @@ struct Crate {
 }
 
 /// A single warning that clippy issued while checking a `Crate`
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 struct ClippyWarning {
     crate_name: String,
     file: String,
@@ -100,7 +114,25 @@ struct ClippyWarning {
     column: usize,
     lint_type: String,
     message: String,
-    is_ice: bool,
+    /// the diagnostic as printed by rustc, with the source snippets
+    rendered: String,
+}
+
+/// A panic of clippy while checking a `Crate`
+#[derive(Debug)]
+struct ClippyIce {
+    crate_name: String,
+    /// the lint pass that panicked, `None` if the panic happened outside of the lint passes
+    lint_pass: Option<String>,
+    /// the panic message, with its location in clippy's sources
+    message: String,
+}
+
+/// What running clippy on a `Crate` produced
+struct CrateResults {
+    warnings: Vec<ClippyWarning>,
+    ices: Vec<ClippyIce>,
+    timing: timing::CrateTiming,
 }
 
 #[allow(unused)]
@@ -135,7 +167,7 @@ impl ClippyWarning {
             column: span.column_start,
             lint_type,
             message: diag.message,
-            is_ice: diag.level == DiagnosticLevel::Ice,
+            rendered: diag.rendered.unwrap_or_default(),
         })
     }
 
@@ -160,6 +192,55 @@ impl ClippyWarning {
     }
 }
 
+impl ClippyIce {
+    /// Reads the warning that `--catch-panics` emits instead of an ICE when a lint pass panics
+    fn from_caught_panic(diag: &Diagnostic, krate: &Crate) -> Option<Self> {
+        // the lint pass `{pass}` panicked in `{method}`, ...
+        let (lint_pass, _) = diag
+            .message
+            .strip_prefix("the lint pass `")?
+            .split_once("` panicked in `")?;
+        // the note holds the panic message, followed by the backtrace if there is one
+        let note = diag
+            .children
+            .iter()
+            .find(|child| child.level == DiagnosticLevel::Note)?;
+        Some(Self {
+            crate_name: krate.name.clone(),
+            lint_pass: Some(lint_pass.to_string()),
+            message: note.message.lines().next().unwrap_or_default().to_string(),
+        })
+    }
+
+    /// Reads an internal compiler error emitted as a diagnostic, like the ones of `span_bug`
+    fn from_ice_diagnostic(diag: &Diagnostic, krate: &Crate) -> Option<Self> {
+        (diag.level == DiagnosticLevel::Ice).then(|| Self {
+            crate_name: krate.name.clone(),
+            lint_pass: None,
+            message: diag.message.clone(),
+        })
+    }
+
+    /// Reads the message printed on stderr by a panic outside of the lint passes, which aborts the
+    /// compilation with an ICE
+    fn from_stderr_line(line: &str, krate: &Crate) -> Option<Self> {
+        // thread 'rustc' panicked at 'explicit panic', clippy_utils/src/lib.rs:42:5
+        let (_, message) = line.strip_prefix("thread '")?.split_once("' panicked at ")?;
+        Some(Self {
+            crate_name: krate.name.clone(),
+            lint_pass: None,
+            message: format!("panicked at {}", message),
+        })
+    }
+
+    fn to_output(&self) -> String {
+        match &self.lint_pass {
+            Some(lint_pass) => format!("{}: the lint pass `{}` {}\n", self.crate_name, lint_pass, self.message),
+            None => format!("{}: {}\n", self.crate_name, self.message),
+        }
+    }
+}
+
 fn get(path: &str) -> Result<ureq::Response, ureq::Error> {
     const MAX_RETRIES: u8 = 4;
     let mut retries = 0;
@@ -261,9 +342,6 @@ impl CrateSource {
                 }
             },
             CrateSource::Path { name, path, options } => {
-                // copy path into the dest_crate_root but skip directories that contain a CACHEDIR.TAG file.
-                // The target/ directory contains a CACHEDIR.TAG file so it is the most commonly skipped directory
-                // as a result of this filter.
                 let dest_crate_root = PathBuf::from(LINTCHECK_SOURCES).join(name);
                 if dest_crate_root.exists() {
                     println!("Deleting existing directory at {:?}", dest_crate_root);
@@ -271,29 +349,52 @@ impl CrateSource {
                 }
 
                 println!("Copying {:?} to {:?}", path, dest_crate_root);
+                copy_dir(path, &dest_crate_root);
 
-                fn is_cache_dir(entry: &DirEntry) -> bool {
-                    std::fs::read(entry.path().join("CACHEDIR.TAG"))
-                        .map(|x| x.starts_with(b"Signature: 8a477f597d28d172789f06886806bc55"))
-                        .unwrap_or(false)
+                Crate {
+                    version: String::from("local"),
+                    name: name.clone(),
+                    path: dest_crate_root,
+                    options: options.clone(),
                 }
-
-                for entry in WalkDir::new(path).into_iter().filter_entry(|e| !is_cache_dir(e)) {
-                    let entry = entry.unwrap();
-                    let entry_path = entry.path();
-                    let relative_entry_path = entry_path.strip_prefix(path).unwrap();
-                    let dest_path = dest_crate_root.join(relative_entry_path);
-                    let metadata = entry_path.symlink_metadata().unwrap();
-
-                    if metadata.is_dir() {
-                        std::fs::create_dir(dest_path).unwrap();
-                    } else if metadata.is_file() {
-                        std::fs::copy(entry_path, dest_path).unwrap();
-                    }
+            },
+            CrateSource::Vendor {
+                name,
+                version,
+                vendor_dir,
+                options,
+            } => {
+                let extract_dir = PathBuf::from(LINTCHECK_SOURCES);
+                create_dirs(&PathBuf::from(LINTCHECK_DOWNLOADS), &extract_dir);
+
+                // use the same directory as a download or a clone of the crate
+                let label = version.as_deref().unwrap_or("git");
+                let dest_crate_root = extract_dir.join(format!("{}-{}", name, label));
+                println!("Copying {} {} from {}", name, label, vendor_dir.display());
+
+                match find_vendored(vendor_dir, name, version.as_deref()) {
+                    Some(Vendored::Dir(dir)) => copy_dir(&dir, &dest_crate_root),
+                    Some(Vendored::Archive(krate_file_path)) => {
+                        if dest_crate_root.exists() {
+                            std::fs::remove_dir_all(&dest_crate_root).unwrap();
+                        }
+                        let ungz_tar = flate2::read::GzDecoder::new(std::fs::File::open(&krate_file_path).unwrap());
+                        let mut archive = tar::Archive::new(ungz_tar);
+                        archive.unpack(&extract_dir).expect("Failed to extract!");
+                    },
+                    None => {
+                        eprintln!(
+                            "ERROR: could not find {} {} in the vendor dir {}",
+                            name,
+                            label,
+                            vendor_dir.display()
+                        );
+                        std::process::exit(1);
+                    },
                 }
 
                 Crate {
-                    version: String::from("local"),
+                    version: label.to_string(),
                     name: name.clone(),
                     path: dest_crate_root,
                     options: options.clone(),
@@ -303,17 +404,86 @@ impl CrateSource {
     }
 }
 
+/// Where a crate is found in the vendor dir
+#[derive(Debug, PartialEq, Eq)]
+enum Vendored {
+    /// a directory of `cargo vendor`
+    Dir(PathBuf),
+    /// a `.crate` archive of a local registry, like the ones downloaded from crates.io
+    Archive(PathBuf),
+}
+
+/// Finds the crate `name` in the vendor dir, at `version` unless it's from git
+fn find_vendored(vendor_dir: &Path, name: &str, version: Option<&str>) -> Option<Vendored> {
+    // `cargo vendor --versioned-dirs` adds the version to the directories
+    if let Some(version) = version {
+        let versioned_dir = vendor_dir.join(format!("{}-{}", name, version));
+        if versioned_dir.is_dir() {
+            return Some(Vendored::Dir(versioned_dir));
+        }
+    }
+    let dir = vendor_dir.join(name);
+    if dir.is_dir() && (version.is_none() || vendored_version(&dir).as_deref() == version) {
+        return Some(Vendored::Dir(dir));
+    }
+    let krate_file_path = vendor_dir.join(format!("{}-{}.crate", name, version?));
+    krate_file_path.is_file().then_some(Vendored::Archive(krate_file_path))
+}
+
+/// Reads the version of the crate vendored in `dir`
+fn vendored_version(dir: &Path) -> Option<String> {
+    let manifest: toml::Value = toml::from_str(&std::fs::read_to_string(dir.join("Cargo.toml")).ok()?).ok()?;
+    Some(manifest.get("package")?.get("version")?.as_str()?.to_string())
+}
+
+/// Makes cargo resolve the dependencies of the crates from the vendor dir, without using the
+/// network, or undoes it when there is no vendor dir
+fn configure_vendor_dir(vendor_dir: Option<&Path>) {
+    let config_path = Path::new(LINTCHECK_CARGO_CONFIG);
+    let vendor_dir = match vendor_dir {
+        Some(dir) => dir,
+        None => {
+            std::fs::remove_file(config_path).unwrap_or_else(|err| {
+                if err.kind() != ErrorKind::NotFound {
+                    panic!("cannot remove {}", config_path.display());
+                }
+            });
+            return;
+        },
+    };
+
+    std::fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+    write(config_path, cargo_config(vendor_dir)).unwrap();
+}
+
+/// The cargo config replacing crates.io with the vendor dir
+fn cargo_config(vendor_dir: &Path) -> String {
+    let kind = if vendor_dir.join("index").is_dir() {
+        "local-registry"
+    } else {
+        "directory"
+    };
+    let vendor_dir = toml::Value::from(vendor_dir.to_string_lossy().into_owned());
+    format!(
+        "[source.crates-io]\n\
+         replace-with = \"lintcheck-vendor\"\n\n\
+         [source.lintcheck-vendor]\n\
+         {} = {}\n\n\
+         [net]\n\
+         offline = true\n",
+        kind, vendor_dir
+    )
+}
+
 impl Crate {
-    /// Run `cargo clippy` on the `Crate` and collect and return all the lint warnings that clippy
-    /// issued
-    fn run_clippy_lints(
+    /// Prints the progress of the run, and returns the target dir to use for the `Crate`
+    fn start(
         &self,
-        cargo_clippy_path: &Path,
+        action: &str,
         target_dir_index: &AtomicUsize,
         total_crates_to_lint: usize,
         config: &LintcheckConfig,
-        lint_filter: &Vec<String>,
-    ) -> Vec<ClippyWarning> {
+    ) -> PathBuf {
         // advance the atomic index by one
         let index = target_dir_index.fetch_add(1, Ordering::SeqCst);
         // "loop" the index within 0..thread_limit
@@ -322,29 +492,27 @@ impl Crate {
 
         if config.max_jobs == 1 {
             println!(
-                "{}/{} {}% Linting {} {}",
-                index, total_crates_to_lint, perc, &self.name, &self.version
+                "{}/{} {}% {} {} {}",
+                index, total_crates_to_lint, perc, action, &self.name, &self.version
             );
         } else {
             println!(
-                "{}/{} {}% Linting {} {} in target dir {:?}",
-                index, total_crates_to_lint, perc, &self.name, &self.version, thread_index
+                "{}/{} {}% {} {} {} in target dir {:?}",
+                index, total_crates_to_lint, perc, action, &self.name, &self.version, thread_index
             );
         }
 
-        let cargo_clippy_path = std::fs::canonicalize(cargo_clippy_path).unwrap();
-
         let shared_target_dir = clippy_project_root().join("target/lintcheck/shared_target_dir");
+        // use the looping index to create individual target dirs
+        shared_target_dir.join(format!("_{:?}", thread_index))
+    }
 
-        let mut args = if config.fix {
-            vec!["--fix", "--"]
-        } else {
-            vec!["--", "--message-format=json", "--"]
-        };
-
+    /// The arguments passed to clippy-driver for the `Crate`
+    fn lint_args<'a>(&'a self, lint_filter: &'a [String]) -> Vec<&'a str> {
+        let mut args = Vec::new();
         if let Some(options) = &self.options {
             for opt in options {
-                args.push(opt);
+                args.push(opt.as_str());
             }
         } else {
             args.extend(&["-Wclippy::pedantic", "-Wclippy::cargo"])
@@ -356,26 +524,46 @@ impl Crate {
             args.push("--cap-lints=allow");
             args.extend(lint_filter.iter().map(|filter| filter.as_str()))
         }
+        args
+    }
+
+    /// Run `cargo clippy` on the `Crate` and collect and return all the lint warnings that clippy
+    /// issued
+    fn run_clippy_lints(
+        &self,
+        cargo_clippy_path: &Path,
+        target_dir_index: &AtomicUsize,
+        total_crates_to_lint: usize,
+        config: &LintcheckConfig,
+        lint_filter: &Vec<String>,
+    ) -> CrateResults {
+        let target_dir = self.start("Linting", target_dir_index, total_crates_to_lint, config);
 
-        let all_output = std::process::Command::new(&cargo_clippy_path)
-            // use the looping index to create individual target dirs
-            .env(
-                "CARGO_TARGET_DIR",
-                shared_target_dir.join(format!("_{:?}", thread_index)),
-            )
+        let cargo_clippy_path = std::fs::canonicalize(cargo_clippy_path).unwrap();
+
+        let mut args = if config.fix {
+            vec!["--fix", "--"]
+        } else {
+            // keep linting when a lint pass panics, and report the panic as a warning
+            vec!["--", "--message-format=json", "--catch-panics", "--"]
+        };
+        args.extend(self.lint_args(lint_filter));
+
+        let mut command = std::process::Command::new(&cargo_clippy_path);
+        command
+            .env("CARGO_TARGET_DIR", target_dir)
             // lint warnings will look like this:
             // src/cargo/ops/cargo_compile.rs:127:35: warning: usage of `FromIterator::from_iter`
             .args(&args)
-            .current_dir(&self.path)
-            .output()
-            .unwrap_or_else(|error| {
-                panic!(
-                    "Encountered error:\n{:?}\ncargo_clippy_path: {}\ncrate path:{}\n",
-                    error,
-                    &cargo_clippy_path.display(),
-                    &self.path.display()
-                );
-            });
+            .current_dir(&self.path);
+        let (all_output, timing) = timing::run(&mut command, &self.name, &self.version).unwrap_or_else(|error| {
+            panic!(
+                "Encountered error:\n{:?}\ncargo_clippy_path: {}\ncrate path:{}\n",
+                error,
+                &cargo_clippy_path.display(),
+                &self.path.display()
+            );
+        });
         let stdout = String::from_utf8_lossy(&all_output.stdout);
         let stderr = String::from_utf8_lossy(&all_output.stderr);
         let status = &all_output.status;
@@ -399,15 +587,53 @@ impl Crate {
                 );
             }
             // fast path, we don't need the warnings anyway
-            return Vec::new();
+            return CrateResults {
+                warnings: Vec::new(),
+                ices: Vec::new(),
+                timing,
+            };
         }
 
-        // get all clippy warnings and ICEs
-        let warnings: Vec<ClippyWarning> = Message::parse_stream(stdout.as_bytes())
-            .filter_map(|msg| ClippyWarning::new(msg.unwrap(), &self))
+        let messages: Vec<Message> = Message::parse_stream(stdout.as_bytes())
+            .map(|msg| msg.unwrap())
             .collect();
 
-        warnings
+        // get all clippy ICEs, the ones of the lint passes are caught, the other ones abort
+        let mut ices: Vec<ClippyIce> = messages
+            .iter()
+            .filter_map(|msg| match msg {
+                Message::CompilerMessage(message) => ClippyIce::from_caught_panic(&message.message, self)
+                    .or_else(|| ClippyIce::from_ice_diagnostic(&message.message, self)),
+                _ => None,
+            })
+            .collect();
+        ices.extend(
+            stderr
+                .lines()
+                .filter_map(|line| ClippyIce::from_stderr_line(line, self)),
+        );
+
+        // get all clippy warnings
+        let warnings: Vec<ClippyWarning> = messages
+            .into_iter()
+            .filter_map(|msg| ClippyWarning::new(msg, &self))
+            .collect();
+
+        CrateResults { warnings, ices, timing }
+    }
+
+    /// Applies clippy's suggestions to a copy of the `Crate`, and returns the problems found in the
+    /// fixed code
+    fn verify_fix(
+        &self,
+        cargo_clippy_path: &Path,
+        target_dir_index: &AtomicUsize,
+        total_crates_to_lint: usize,
+        config: &LintcheckConfig,
+        lint_filter: &[String],
+    ) -> Vec<verify_fix::Problem> {
+        let target_dir = self.start("Verifying the fixes of", target_dir_index, total_crates_to_lint, config);
+        verify_fix::verify(self, cargo_clippy_path, &target_dir, &self.lint_args(lint_filter))
     }
 }
 
@@ -424,7 +650,7 @@ fn build_clippy() {
 }
 
 /// Read a `toml` file and return a list of `CrateSources` that we want to check with clippy
-fn read_crates(toml_path: &Path) -> Vec<CrateSource> {
+fn read_crates(toml_path: &Path, vendor_dir: Option<&Path>) -> Vec<CrateSource> {
     let toml_content: String =
         std::fs::read_to_string(&toml_path).unwrap_or_else(|_| panic!("Failed to read {}", toml_path.display()));
     let crate_list: SourceList =
@@ -449,12 +675,27 @@ fn read_crates(toml_path: &Path) -> Vec<CrateSource> {
         } else if let Some(ref versions) = tk.versions {
             // if we have multiple versions, save each one
             versions.iter().for_each(|ver| {
-                crate_sources.push(CrateSource::CratesIo {
-                    name: tk.name.clone(),
-                    version: ver.to_string(),
-                    options: tk.options.clone(),
+                crate_sources.push(match vendor_dir {
+                    Some(vendor_dir) => CrateSource::Vendor {
+                        name: tk.name.clone(),
+                        version: Some(ver.to_string()),
+                        vendor_dir: vendor_dir.to_path_buf(),
+                        options: tk.options.clone(),
+                    },
+                    None => CrateSource::CratesIo {
+                        name: tk.name.clone(),
+                        version: ver.to_string(),
+                        options: tk.options.clone(),
+                    },
                 });
             })
+        } else if let (Some(vendor_dir), Some(_), Some(_)) = (vendor_dir, &tk.git_url, &tk.git_hash) {
+            crate_sources.push(CrateSource::Vendor {
+                name: tk.name.clone(),
+                version: None,
+                vendor_dir: vendor_dir.to_path_buf(),
+                options: tk.options.clone(),
+            });
         } else if tk.git_url.is_some() && tk.git_hash.is_some() {
             // otherwise, we should have a git source
             crate_sources.push(CrateSource::Git {
@@ -551,6 +792,19 @@ fn main() {
 
     let config = LintcheckConfig::new();
 
+    // compare the warnings of two previous runs without running clippy
+    if let Some((old_path, new_path)) = &config.diff {
+        let read = |path: &Path| {
+            json::read_warnings(path).unwrap_or_else(|| {
+                eprintln!("ERROR: could not read the warnings from {}", path.display());
+                std::process::exit(1);
+            })
+        };
+        let (old_warnings, new_warnings) = (read(old_path), read(new_path));
+        print!("{}", json::to_markdown(json::diff(&old_warnings, &new_warnings)));
+        return;
+    }
+
     println!("Compiling clippy...");
     build_clippy();
     println!("Done compiling");
@@ -589,8 +843,11 @@ fn main() {
     // download and extract the crates, then run clippy on them and collect clippy's warnings
     // flatten into one big list of warnings
 
-    let crates = read_crates(&config.sources_toml_path);
+    configure_vendor_dir(config.vendor_dir.as_deref());
+    let crates = read_crates(&config.sources_toml_path, config.vendor_dir.as_deref());
     let old_stats = read_stats_from_file(&config.lintcheck_results_path);
+    let old_warnings = json::read_warnings(&config.lintcheck_json_path);
+    let old_timings = timing::read_timings(&config.lintcheck_timings_path);
 
     let counter = AtomicUsize::new(1);
     let lint_filter: Vec<String> = config
@@ -610,7 +867,8 @@ fn main() {
                 let name = match krate {
                     CrateSource::CratesIo { name, .. }
                     | CrateSource::Git { name, .. }
-                    | CrateSource::Path { name, .. } => name,
+                    | CrateSource::Path { name, .. }
+                    | CrateSource::Vendor { name, .. } => name,
                 };
 
                 name == only_one_crate
@@ -639,9 +897,18 @@ fn main() {
         .build_global()
         .unwrap();
 
-    let clippy_warnings: Vec<ClippyWarning> = crates
+    if config.verify_fix {
+        let problems: Vec<verify_fix::Problem> = crates
+            .par_iter()
+            .flat_map(|krate| krate.verify_fix(&cargo_clippy_path, &counter, crates.len(), &config, &lint_filter))
+            .collect();
+        print!("{}", verify_fix::report(&problems));
+        return;
+    }
+
+    let results: Vec<CrateResults> = crates
         .par_iter()
-        .flat_map(|krate| krate.run_clippy_lints(&cargo_clippy_path, &counter, crates.len(), &config, &lint_filter))
+        .map(|krate| krate.run_clippy_lints(&cargo_clippy_path, &counter, crates.len(), &config, &lint_filter))
         .collect();
 
     // if we are in --fix mode, don't change the log files, terminate here
@@ -649,16 +916,18 @@ fn main() {
         return;
     }
 
+    let mut clippy_warnings = Vec::new();
+    let mut ices = Vec::new();
+    let mut timings = Vec::new();
+    for result in results {
+        clippy_warnings.extend(result.warnings);
+        ices.extend(result.ices);
+        timings.push(result.timing);
+    }
+
     // generate some stats
     let (stats_formatted, new_stats) = gather_stats(&clippy_warnings);
 
-    // grab crashes/ICEs, save the crate name and the ice message
-    let ices: Vec<(&String, &String)> = clippy_warnings
-        .iter()
-        .filter(|warning| warning.is_ice)
-        .map(|w| (&w.crate_name, &w.message))
-        .collect();
-
     let mut all_msgs: Vec<String> = clippy_warnings
         .iter()
         .map(|warn| warn.to_output(config.markdown))
@@ -676,15 +945,49 @@ fn main() {
     }
     write!(text, "{}", all_msgs.join("")).unwrap();
     text.push_str("\n\n### ICEs:\n");
-    for (cratename, msg) in ices.iter() {
-        let _ = write!(text, "{}: '{}'", cratename, msg);
+    for ice in &ices {
+        text.push_str(&ice.to_output());
     }
 
     println!("Writing logs to {}", config.lintcheck_results_path.display());
     std::fs::create_dir_all(config.lintcheck_results_path.parent().unwrap()).unwrap();
     write(&config.lintcheck_results_path, text).unwrap();
+    json::write_warnings(&config.lintcheck_json_path, &clippy_warnings);
+
+    if let Some(old_warnings) = &old_warnings {
+        let diff = json::diff(old_warnings, &clippy_warnings);
+        println!(
+            "\n{} warning(s) added and {} removed since the previous run, writing the diff to {}",
+            diff.added.len(),
+            diff.removed.len(),
+            config.lintcheck_diff_path.display()
+        );
+        write(&config.lintcheck_diff_path, json::to_markdown(diff)).unwrap();
+    }
+
+    timing::write_timings(&config.lintcheck_timings_path, &timings);
 
     print_stats(old_stats, new_stats, &config.lint_filter);
+
+    println!("\nTimings:\n\n{}", timing::table(&timings));
+    let regressions = timing::regressions(&old_timings, &timings, config.time_threshold);
+    if !regressions.is_empty() {
+        println!(
+            "WARNING: clippy got more than {}% slower on {} crate(s):",
+            config.time_threshold,
+            regressions.len()
+        );
+        for regression in &regressions {
+            println!("    {}", regression);
+        }
+    }
+
+    if !ices.is_empty() {
+        println!("\nERROR: clippy panicked {} time(s):", ices.len());
+        for ice in &ices {
+            print!("    {}", ice.to_output());
+        }
+    }
 }
 
 /// read the previous stats from the lintcheck-log file
@@ -763,6 +1066,35 @@ fn print_stats(old_stats: HashMap<String, usize>, new_stats: HashMap<&String, us
         });
 }
 
+/// Copies the directory `src` to `dest`, replacing it if it exists, but skips the directories that
+/// contain a CACHEDIR.TAG file. The target/ directory contains a CACHEDIR.TAG file so it is the
+/// most commonly skipped directory as a result of this filter.
+fn copy_dir(src: &Path, dest: &Path) {
+    fn is_cache_dir(entry: &DirEntry) -> bool {
+        std::fs::read(entry.path().join("CACHEDIR.TAG"))
+            .map(|x| x.starts_with(b"Signature: 8a477f597d28d172789f06886806bc55"))
+            .unwrap_or(false)
+    }
+
+    if dest.exists() {
+        std::fs::remove_dir_all(dest).unwrap();
+    }
+
+    for entry in WalkDir::new(src).into_iter().filter_entry(|e| !is_cache_dir(e)) {
+        let entry = entry.unwrap();
+        let entry_path = entry.path();
+        let relative_entry_path = entry_path.strip_prefix(src).unwrap();
+        let dest_path = dest.join(relative_entry_path);
+        let metadata = entry_path.symlink_metadata().unwrap();
+
+        if metadata.is_dir() {
+            std::fs::create_dir_all(dest_path).unwrap();
+        } else if metadata.is_file() {
+            std::fs::copy(entry_path, dest_path).unwrap();
+        }
+    }
+}
+
 /// Create necessary directories to run the lintcheck tool.
 ///
 /// # Panics
@@ -792,6 +1124,41 @@ fn clippy_project_root() -> &'static Path {
     Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
 }
 
+#[test]
+fn caught_panic_format() {
+    // the output of clippy's test of `--catch-panics`, so that a change of its format breaks this test
+    let stderr = std::fs::read_to_string(clippy_project_root().join("tests/ui-internal/catch_panics.stderr")).unwrap();
+    let message = stderr.lines().find_map(|line| line.strip_prefix("warning: ")).unwrap();
+    let note = stderr
+        .lines()
+        .find_map(|line| line.trim_start().strip_prefix("= note: "))
+        .unwrap();
+    let diag: Diagnostic = serde_json::from_value(serde_json::json!({
+        "message": message,
+        "code": null,
+        "level": "warning",
+        "spans": [],
+        "children": [
+            { "message": note, "code": null, "level": "note", "spans": [], "children": [], "rendered": null },
+        ],
+        "rendered": null,
+    }))
+    .unwrap();
+    let krate = Crate {
+        version: String::from("1.0.0"),
+        name: String::from("krate"),
+        path: PathBuf::new(),
+        options: None,
+    };
+
+    let ice = ClippyIce::from_caught_panic(&diag, &krate).unwrap();
+    assert_eq!(
+        ice.to_output(),
+        "krate: the lint pass `ProduceIce` panicked at 'Would you like some help with that?', \
+         clippy_lints/src/utils/internal_lints.rs\n"
+    );
+}
+
 #[test]
 fn lintcheck_test() {
     let args = [
@@ -812,3 +1179,82 @@ fn lintcheck_test() {
 
     assert!(status.unwrap().success());
 }
+
+/// A new empty directory in the temporary dir, for the tests of the vendor dir
+#[cfg(test)]
+fn temp_vendor_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("lintcheck-{}-{}", name, std::process::id()));
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn find_vendored_crates() {
+    let vendor_dir = temp_vendor_dir("find-vendored");
+    let vendor = |name: &str, version: &str| {
+        let dir = vendor_dir.join(name);
+        std::fs::create_dir(&dir).unwrap();
+        let manifest = format!("[package]\nname = \"{}\"\nversion = \"{}\"\n", name, version);
+        write(dir.join("Cargo.toml"), manifest).unwrap();
+        dir
+    };
+
+    // `cargo vendor --versioned-dirs`
+    let versioned = vendor("versioned-1.0.0", "1.0.0");
+    assert_eq!(
+        find_vendored(&vendor_dir, "versioned", Some("1.0.0")),
+        Some(Vendored::Dir(versioned))
+    );
+    assert_eq!(find_vendored(&vendor_dir, "versioned", Some("2.0.0")), None);
+
+    // `cargo vendor`, which only keeps one version of a crate in its plain dir
+    let plain = vendor("plain", "1.0.0");
+    assert_eq!(
+        find_vendored(&vendor_dir, "plain", Some("1.0.0")),
+        Some(Vendored::Dir(plain.clone()))
+    );
+    assert_eq!(find_vendored(&vendor_dir, "plain", Some("2.0.0")), None);
+    // a git dependency, whose vendored copy doesn't record the commit
+    assert_eq!(find_vendored(&vendor_dir, "plain", None), Some(Vendored::Dir(plain)));
+
+    // a local registry
+    let archive = vendor_dir.join("archived-1.0.0.crate");
+    write(&archive, "").unwrap();
+    assert_eq!(
+        find_vendored(&vendor_dir, "archived", Some("1.0.0")),
+        Some(Vendored::Archive(archive))
+    );
+    assert_eq!(find_vendored(&vendor_dir, "archived", Some("2.0.0")), None);
+    assert_eq!(find_vendored(&vendor_dir, "archived", None), None);
+
+    std::fs::remove_dir_all(&vendor_dir).unwrap();
+}
+
+#[test]
+fn vendor_dir_cargo_config() {
+    let vendor_dir = temp_vendor_dir("cargo-config");
+    let expected = |kind: &str| {
+        format!(
+            "[source.crates-io]\n\
+             replace-with = \"lintcheck-vendor\"\n\n\
+             [source.lintcheck-vendor]\n\
+             {} = \"{}\"\n\n\
+             [net]\n\
+             offline = true\n",
+            kind,
+            vendor_dir.display()
+        )
+    };
+
+    // `cargo vendor`
+    assert_eq!(cargo_config(&vendor_dir), expected("directory"));
+
+    // `cargo local-registry`, which writes an index of the crates
+    std::fs::create_dir(vendor_dir.join("index")).unwrap();
+    assert_eq!(cargo_config(&vendor_dir), expected("local-registry"));
+
+    std::fs::remove_dir_all(&vendor_dir).unwrap();
+}
@@ -0,0 +1,169 @@
+//! Measures the time and memory clippy takes to check each crate, and compares them with the ones
+//! of the previous run to spot the crates that became slower.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use std::process::{Command, Output};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+/// Differences shorter than this are noise, even if they exceed the threshold
+const MIN_REGRESSION_SECONDS: f64 = 1.0;
+
+/// The resources clippy used to check a crate
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CrateTiming {
+    pub crate_name: String,
+    pub version: String,
+    /// the wall time of `cargo clippy`, in seconds
+    pub seconds: f64,
+    /// the peak memory of the largest process of `cargo clippy`, in KiB, if it can be measured on
+    /// this platform
+    pub peak_memory_kib: Option<u64>,
+}
+
+/// Runs `command` like `Command::output`, and measures the resources it used
+pub(crate) fn run(command: &mut Command, crate_name: &str, version: &str) -> io::Result<(Output, CrateTiming)> {
+    let start = Instant::now();
+    let (output, peak_memory_kib) = output_with_peak_memory(command)?;
+    let timing = CrateTiming {
+        crate_name: crate_name.to_string(),
+        version: version.to_string(),
+        seconds: start.elapsed().as_secs_f64(),
+        peak_memory_kib,
+    };
+    Ok((output, timing))
+}
+
+#[cfg(unix)]
+fn output_with_peak_memory(command: &mut Command) -> io::Result<(Output, Option<u64>)> {
+    use std::io::Read;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::{ExitStatus, Stdio};
+
+    let mut child = command.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+    // read both pipes while the child runs, so that it doesn't block on a full one
+    let mut stderr_pipe = child.stderr.take().unwrap();
+    let stderr_thread = std::thread::spawn(move || {
+        let mut stderr = Vec::new();
+        stderr_pipe.read_to_end(&mut stderr).map(|_| stderr)
+    });
+    let mut stdout = Vec::new();
+    child.stdout.take().unwrap().read_to_end(&mut stdout)?;
+    let stderr = stderr_thread.join().unwrap()?;
+
+    // unlike `Child::wait`, `wait4` returns the resources used by the child, whose peak memory is
+    // the one of the largest of the child and the processes it waited for, like clippy-driver
+    let mut status = 0;
+    // SAFETY: `rusage` is a plain C struct, for which zeroes are valid
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    // SAFETY: the pointers are valid for the duration of the call, and the child isn't waited for
+    // anywhere else
+    if unsafe { libc::wait4(child.id() as libc::pid_t, &mut status, 0, &mut usage) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // `ru_maxrss` is in bytes on macOS and in KiB elsewhere
+    let peak_memory_kib = if cfg!(target_os = "macos") {
+        usage.ru_maxrss as u64 / 1024
+    } else {
+        usage.ru_maxrss as u64
+    };
+
+    let output = Output {
+        status: ExitStatus::from_raw(status),
+        stdout,
+        stderr,
+    };
+    Ok((output, Some(peak_memory_kib)))
+}
+
+#[cfg(not(unix))]
+fn output_with_peak_memory(command: &mut Command) -> io::Result<(Output, Option<u64>)> {
+    command.output().map(|output| (output, None))
+}
+
+/// Writes the timings of a run to the JSON log at `path`
+pub(crate) fn write_timings(path: &Path, timings: &[CrateTiming]) {
+    let json = serde_json::to_string_pretty(timings).unwrap();
+    std::fs::write(path, json).unwrap_or_else(|e| panic!("failed to write {}: {}", path.display(), e));
+}
+
+/// Reads the timings of the previous run from the JSON log at `path`, if there is one
+pub(crate) fn read_timings(path: &Path) -> Vec<CrateTiming> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Describes the crates that clippy took more than `threshold` percent longer to check than in the
+/// `old` run
+pub(crate) fn regressions(old: &[CrateTiming], new: &[CrateTiming], threshold: u64) -> Vec<String> {
+    new.iter()
+        .filter_map(|timing| {
+            let previous = old
+                .iter()
+                .find(|previous| previous.crate_name == timing.crate_name && previous.version == timing.version)?;
+            let increase = (timing.seconds - previous.seconds) / previous.seconds * 100.0;
+            (timing.seconds - previous.seconds >= MIN_REGRESSION_SECONDS && increase > threshold as f64).then(|| {
+                format!(
+                    "{} {}: {:.1}s, {:.0}% more than the {:.1}s of the previous run",
+                    timing.crate_name, timing.version, timing.seconds, increase, previous.seconds
+                )
+            })
+        })
+        .collect()
+}
+
+/// Formats the timings as a table, the slowest crates first
+pub(crate) fn table(timings: &[CrateTiming]) -> String {
+    let mut timings: Vec<&CrateTiming> = timings.iter().collect();
+    timings.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+
+    let mut table = String::from("| crate                                    |     time | peak memory |\n");
+    table.push_str("| ---------------------------------------- | -------- | ----------- |\n");
+    for timing in timings {
+        let memory = timing
+            .peak_memory_kib
+            .map_or_else(|| String::from("-"), |kib| format!("{} MiB", kib / 1024));
+        let _ = writeln!(
+            table,
+            "| {:<40} | {:>7.1}s | {:>11} |",
+            format!("{} {}", timing.crate_name, timing.version),
+            timing.seconds,
+            memory
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{regressions, CrateTiming};
+
+    fn timing(crate_name: &str, seconds: f64) -> CrateTiming {
+        CrateTiming {
+            crate_name: crate_name.into(),
+            version: "1.0.0".into(),
+            seconds,
+            peak_memory_kib: None,
+        }
+    }
+
+    #[test]
+    fn time_regressions() {
+        let old = [timing("slower", 10.0), timing("noisy", 0.5), timing("same", 20.0)];
+        let new = [
+            timing("slower", 13.0),
+            timing("noisy", 1.0),
+            timing("same", 21.0),
+            timing("new", 30.0),
+        ];
+        assert_eq!(
+            regressions(&old, &new, 25),
+            ["slower 1.0.0: 13.0s, 30% more than the 10.0s of the previous run"]
+        );
+    }
+}
@@ -0,0 +1,544 @@
+//! Checks the code fixed by `cargo clippy --fix`: it must still build, and clippy must neither emit
+//! new warnings for it nor have more suggestions to apply to it.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use cargo_metadata::diagnostic::{Applicability, Diagnostic, DiagnosticLevel, DiagnosticSpan};
+use cargo_metadata::Message;
+
+use crate::{copy_dir, Crate};
+
+/// The copies of the crates the suggestions are applied to
+const LINTCHECK_FIX: &str = "target/lintcheck/fix";
+
+/// What went wrong with the suggestions of a lint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ProblemKind {
+    /// the fixed code doesn't build
+    BrokeBuild,
+    /// clippy emits warnings for the fixed code that it didn't emit for the original code
+    NewWarnings,
+    /// clippy still has suggestions to apply to the fixed code
+    NotConverged,
+}
+
+/// A problem found in the code fixed by the suggestions
+#[derive(Debug)]
+pub(crate) struct Problem {
+    crate_name: String,
+    crate_version: String,
+    /// the lint whose suggestions cause the problem, `None` if it only occurs when the suggestions
+    /// of all the lints are applied together
+    lint: Option<String>,
+    kind: ProblemKind,
+    /// the copy of the crate the suggestions were applied to
+    fixed_path: PathBuf,
+    /// the diagnostics showing the problem, one per line
+    notes: Vec<String>,
+}
+
+struct Verifier<'a> {
+    krate: &'a Crate,
+    cargo_clippy_path: &'a Path,
+    target_dir: &'a Path,
+    lint_args: &'a [&'a str],
+}
+
+/// Applies the suggestions to a copy of `krate` and checks the fixed code. When it has a problem,
+/// the suggestions of each lint are applied on their own, to find the lints causing it.
+pub(crate) fn verify(krate: &Crate, cargo_clippy_path: &Path, target_dir: &Path, lint_args: &[&str]) -> Vec<Problem> {
+    let verifier = Verifier {
+        krate,
+        cargo_clippy_path,
+        target_dir,
+        lint_args,
+    };
+    let original = verifier.clippy(&krate.path);
+    let fixable: BTreeSet<&str> = original
+        .iter()
+        .filter(|diag| has_fix(diag))
+        .filter_map(lint_name)
+        .collect();
+    bisect(&fixable, |lint| verifier.fix(lint, &original, &fixable))
+}
+
+/// Applies the suggestions of all the `fixable` lints together with `fix`, then, if that causes a
+/// problem, the suggestions of each lint on its own. The problems of all the lints together are
+/// only returned when none of the lints causes a problem on its own.
+fn bisect<'a>(fixable: &BTreeSet<&'a str>, mut fix: impl FnMut(Option<&'a str>) -> Vec<Problem>) -> Vec<Problem> {
+    if fixable.is_empty() {
+        return Vec::new();
+    }
+    let problems = fix(None);
+    if problems.is_empty() {
+        return problems;
+    }
+    let lint_problems: Vec<Problem> = fixable.iter().flat_map(|&lint| fix(Some(lint))).collect();
+    if lint_problems.is_empty() {
+        problems
+    } else {
+        lint_problems
+    }
+}
+
+impl Verifier<'_> {
+    /// Applies the suggestions of `lint`, or of all the `fixable` lints, to a new copy of the crate
+    /// and checks the fixed code.
+    fn fix(&self, lint: Option<&str>, original: &[Diagnostic], fixable: &BTreeSet<&str>) -> Vec<Problem> {
+        let fixed_path = PathBuf::from(LINTCHECK_FIX)
+            .join(format!("{}-{}", self.krate.name, self.krate.version))
+            .join(lint.map_or("all", |lint| lint.trim_start_matches("clippy::")));
+        copy_dir(&self.krate.path, &fixed_path);
+
+        let mut args = vec!["--"];
+        match lint {
+            Some(lint) => args.extend(["--fix-only", lint]),
+            None => args.push("--fix"),
+        }
+        // keep the suggestions that break the build, so that `cargo check` reports the errors
+        args.extend(["--allow-dirty", "--allow-no-vcs", "--broken-code", "--"]);
+        args.extend(self.lint_args);
+        self.run(Command::new(self.cargo_clippy_path).args(&args), &fixed_path);
+
+        let check = self.run(
+            Command::new("cargo").args(["check", "--all-targets", "--message-format=json"]),
+            &fixed_path,
+        );
+        classify(lint, original, fixable, &check, || self.clippy(&fixed_path))
+            .into_iter()
+            .map(|(kind, notes)| Problem {
+                crate_name: self.krate.name.clone(),
+                crate_version: self.krate.version.clone(),
+                lint: lint.map(String::from),
+                kind,
+                fixed_path: fixed_path.clone(),
+                notes,
+            })
+            .collect()
+    }
+
+    /// Runs clippy on the crate at `path` and returns its diagnostics
+    fn clippy(&self, path: &Path) -> Vec<Diagnostic> {
+        self.run(
+            Command::new(self.cargo_clippy_path)
+                .args(["--", "--message-format=json", "--"])
+                .args(self.lint_args),
+            path,
+        )
+    }
+
+    /// Runs `command` in `dir`, and returns the diagnostics it printed as JSON
+    fn run(&self, command: &mut Command, dir: &Path) -> Vec<Diagnostic> {
+        let output = command
+            .env("CARGO_TARGET_DIR", self.target_dir)
+            .current_dir(dir)
+            .output()
+            .unwrap_or_else(|error| panic!("failed to run {:?} in {}: {}", command, dir.display(), error));
+        Message::parse_stream(output.stdout.as_slice())
+            .filter_map(|message| match message.unwrap() {
+                Message::CompilerMessage(message) => Some(message.message),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Finds the problems of the code fixed by the suggestions of `lint`, or of all the `fixable`
+/// lints, from the diagnostics of `cargo check` on the fixed code, and of clippy if the fixed code
+/// builds
+fn classify(
+    lint: Option<&str>,
+    original: &[Diagnostic],
+    fixable: &BTreeSet<&str>,
+    check: &[Diagnostic],
+    fixed: impl FnOnce() -> Vec<Diagnostic>,
+) -> Vec<(ProblemKind, Vec<String>)> {
+    let is_fixed = |diag: &&Diagnostic| {
+        lint_name(diag).map_or(false, |name| lint.map_or(fixable.contains(name), |lint| lint == name))
+    };
+
+    let errors: Vec<String> = check
+        .iter()
+        .filter(|diag| diag.level == DiagnosticLevel::Error)
+        .filter_map(|diag| {
+            let span = diag.spans.iter().find(|span| span.is_primary)?;
+            Some(format!("error at {}: {}", location(span), diag.message))
+        })
+        .collect();
+    if !errors.is_empty() {
+        // the suggestions are located in the original code, the errors in the fixed one
+        let suggestions = original.iter().filter(is_fixed).flat_map(|diag| {
+            fix_spans(diag).map(move |span| {
+                format!(
+                    "suggestion of `{}` at {}",
+                    lint_name(diag).unwrap_or_default(),
+                    location(span)
+                )
+            })
+        });
+        return vec![(ProblemKind::BrokeBuild, errors.into_iter().chain(suggestions).collect())];
+    }
+
+    let fixed = fixed();
+    let mut problems = Vec::new();
+
+    let not_converged: Vec<String> = fixed
+        .iter()
+        .filter(is_fixed)
+        .filter(|diag| has_fix(diag))
+        .map(describe)
+        .collect();
+    if !not_converged.is_empty() {
+        problems.push((ProblemKind::NotConverged, not_converged));
+    }
+
+    // the warnings are compared without their position, which the suggestions can change
+    let mut counts: BTreeMap<_, usize> = BTreeMap::new();
+    for diag in original {
+        *counts.entry(key(diag)).or_default() += 1;
+    }
+    let new_warnings: Vec<String> = fixed
+        .iter()
+        .filter(|diag| match counts.get_mut(&key(diag)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            },
+            _ => lint_name(diag).is_some(),
+        })
+        .map(describe)
+        .collect();
+    if !new_warnings.is_empty() {
+        problems.push((ProblemKind::NewWarnings, new_warnings));
+    }
+
+    problems
+}
+
+fn lint_name(diag: &Diagnostic) -> Option<&str> {
+    let code = &diag.code.as_ref()?.code;
+    code.starts_with("clippy::").then_some(code.as_str())
+}
+
+/// The spans of the suggestions of `diag` that `cargo fix` applies
+fn fix_spans(diag: &Diagnostic) -> impl Iterator<Item = &DiagnosticSpan> {
+    diag.spans
+        .iter()
+        .chain(diag.children.iter().flat_map(|child| &child.spans))
+        .filter(|span| span.suggestion_applicability == Some(Applicability::MachineApplicable))
+}
+
+fn has_fix(diag: &Diagnostic) -> bool {
+    fix_spans(diag).next().is_some()
+}
+
+fn location(span: &DiagnosticSpan) -> String {
+    format!("{}:{}:{}", span.file_name, span.line_start, span.column_start)
+}
+
+fn key(diag: &Diagnostic) -> (Option<&str>, Option<&str>, &str) {
+    let file = diag
+        .spans
+        .iter()
+        .find(|span| span.is_primary)
+        .map(|span| span.file_name.as_str());
+    (lint_name(diag), file, &diag.message)
+}
+
+fn describe(diag: &Diagnostic) -> String {
+    let location = diag
+        .spans
+        .iter()
+        .find(|span| span.is_primary)
+        .map_or_else(String::new, |span| format!(" at {}", location(span)));
+    format!(
+        "`{}`{}: {}",
+        lint_name(diag).unwrap_or_default(),
+        location,
+        diag.message
+    )
+}
+
+/// Describes the problems found, with the lints causing them
+pub(crate) fn report(problems: &[Problem]) -> String {
+    if problems.is_empty() {
+        return String::from("\nThe fixed code of every crate builds, without new warnings or suggestions left\n");
+    }
+    let mut report = String::new();
+    for problem in problems {
+        let lints = problem
+            .lint
+            .as_ref()
+            .map_or_else(|| String::from("the lints together"), |lint| format!("`{}`", lint));
+        let what = match problem.kind {
+            ProblemKind::BrokeBuild => "broke the build of",
+            ProblemKind::NewWarnings => "introduced new warnings in",
+            ProblemKind::NotConverged => "didn't converge in",
+        };
+        let _ = writeln!(
+            report,
+            "\nERROR: the suggestions of {} {} {} {} (fixed code in {}):",
+            lints,
+            what,
+            problem.crate_name,
+            problem.crate_version,
+            problem.fixed_path.display()
+        );
+        for note in &problem.notes {
+            let _ = writeln!(report, "    {}", note);
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bisect, classify, report, Problem, ProblemKind};
+    use cargo_metadata::diagnostic::Diagnostic;
+    use serde_json::json;
+    use std::cell::RefCell;
+    use std::collections::BTreeSet;
+    use std::path::PathBuf;
+
+    /// A diagnostic at `line` of `src/lib.rs`, with a machine applicable suggestion if `fix` is set
+    fn diag(level: &str, code: Option<&str>, message: &str, line: usize, fix: Option<&str>) -> Diagnostic {
+        let span = |replacement: Option<&str>| {
+            json!({
+                "file_name": "src/lib.rs",
+                "byte_start": 0,
+                "byte_end": 0,
+                "line_start": line,
+                "line_end": line,
+                "column_start": 5,
+                "column_end": 18,
+                "is_primary": true,
+                "text": [],
+                "label": null,
+                "suggested_replacement": replacement,
+                "suggestion_applicability": replacement.map(|_| "MachineApplicable"),
+                "expansion": null,
+            })
+        };
+        let children: Vec<_> = fix
+            .map(|fix| {
+                json!({
+                    "message": "try",
+                    "code": null,
+                    "level": "help",
+                    "spans": [span(Some(fix))],
+                    "children": [],
+                    "rendered": null,
+                })
+            })
+            .into_iter()
+            .collect();
+        serde_json::from_value(json!({
+            "message": message,
+            "code": code.map(|code| json!({ "code": code, "explanation": null })),
+            "level": level,
+            "spans": [span(None)],
+            "children": children,
+            "rendered": null,
+        }))
+        .unwrap()
+    }
+
+    fn needless_return(line: usize) -> Diagnostic {
+        diag(
+            "warning",
+            Some("clippy::needless_return"),
+            "unneeded `return` statement",
+            line,
+            Some("x * 2"),
+        )
+    }
+
+    fn problem(lint: Option<&str>, kind: ProblemKind, notes: &[&str]) -> Problem {
+        Problem {
+            crate_name: "krate".into(),
+            crate_version: "1.0.0".into(),
+            lint: lint.map(String::from),
+            kind,
+            fixed_path: PathBuf::from("target/lintcheck/fix/krate-1.0.0/all"),
+            notes: notes.iter().map(|note| note.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn broke_build() {
+        let original = [needless_return(2)];
+        let fixable = BTreeSet::from(["clippy::needless_return"]);
+        let check = [
+            diag("error", Some("E0308"), "mismatched types", 2, None),
+            // warnings of `cargo check` aren't problems
+            diag("warning", Some("unused_variables"), "unused variable: `y`", 3, None),
+        ];
+        let problems = classify(None, &original, &fixable, &check, || {
+            panic!("clippy must not run on code that doesn't build")
+        });
+        assert_eq!(
+            problems,
+            [(
+                ProblemKind::BrokeBuild,
+                vec![
+                    "error at src/lib.rs:2:5: mismatched types".to_string(),
+                    "suggestion of `clippy::needless_return` at src/lib.rs:2:5".to_string(),
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn new_warnings_and_not_converged() {
+        let unwrap = diag(
+            "warning",
+            Some("clippy::unwrap_used"),
+            "used `unwrap()` on an `Option` value",
+            5,
+            None,
+        );
+        let original = [needless_return(2), needless_return(6), unwrap.clone()];
+        let fixable = BTreeSet::from(["clippy::needless_return"]);
+        let fixed = vec![
+            // moved by the suggestions, but not new
+            diag(
+                "warning",
+                Some("clippy::unwrap_used"),
+                "used `unwrap()` on an `Option` value",
+                4,
+                None,
+            ),
+            // one suggestion left to apply
+            needless_return(5),
+            // new
+            unwrap,
+            diag(
+                "warning",
+                Some("clippy::let_and_return"),
+                "returning the result of a `let` binding",
+                2,
+                None,
+            ),
+            // not a clippy lint
+            diag(
+                "warning",
+                Some("unused_mut"),
+                "variable does not need to be mutable",
+                3,
+                None,
+            ),
+        ];
+        let problems = classify(Some("clippy::needless_return"), &original, &fixable, &[], || fixed);
+        assert_eq!(
+            problems,
+            [
+                (
+                    ProblemKind::NotConverged,
+                    vec!["`clippy::needless_return` at src/lib.rs:5:5: unneeded `return` statement".to_string()]
+                ),
+                (
+                    ProblemKind::NewWarnings,
+                    vec![
+                        "`clippy::unwrap_used` at src/lib.rs:5:5: used `unwrap()` on an `Option` value".to_string(),
+                        "`clippy::let_and_return` at src/lib.rs:2:5: returning the result of a `let` binding"
+                            .to_string(),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn fixed() {
+        let original = [needless_return(2)];
+        let fixable = BTreeSet::from(["clippy::needless_return"]);
+        assert!(classify(None, &original, &fixable, &[], Vec::new).is_empty());
+    }
+
+    #[test]
+    fn bisect_lints() {
+        let fixable = BTreeSet::from(["clippy::a", "clippy::b", "clippy::c"]);
+
+        // only the lints with a problem on their own are reported
+        let calls = RefCell::new(Vec::new());
+        let problems = bisect(&fixable, |lint| {
+            calls.borrow_mut().push(lint);
+            match lint {
+                None | Some("clippy::b") => vec![problem(lint, ProblemKind::BrokeBuild, &[])],
+                _ => Vec::new(),
+            }
+        });
+        assert_eq!(
+            calls.into_inner(),
+            [None, Some("clippy::a"), Some("clippy::b"), Some("clippy::c")]
+        );
+        let lints: Vec<_> = problems.iter().map(|problem| problem.lint.as_deref()).collect();
+        assert_eq!(lints, [Some("clippy::b")]);
+
+        // the problems of the lints together when no lint has one on its own
+        let problems = bisect(&fixable, |lint| match lint {
+            None => vec![problem(lint, ProblemKind::NotConverged, &[])],
+            Some(_) => Vec::new(),
+        });
+        let lints: Vec<_> = problems.iter().map(|problem| problem.lint.as_deref()).collect();
+        assert_eq!(lints, [None]);
+
+        // the lints aren't fixed on their own when the lints together have no problem
+        let calls = RefCell::new(0);
+        assert!(
+            bisect(&fixable, |_| {
+                *calls.borrow_mut() += 1;
+                Vec::new()
+            })
+            .is_empty()
+        );
+        assert_eq!(calls.into_inner(), 1);
+
+        assert!(bisect(&BTreeSet::new(), |_| panic!("nothing to fix")).is_empty());
+    }
+
+    #[test]
+    fn report_problems() {
+        assert_eq!(
+            report(&[]),
+            "\nThe fixed code of every crate builds, without new warnings or suggestions left\n"
+        );
+        let problems = [
+            problem(
+                Some("clippy::needless_return"),
+                ProblemKind::BrokeBuild,
+                &[
+                    "error at src/lib.rs:2:5: mismatched types",
+                    "suggestion of `clippy::needless_return` at src/lib.rs:2:5",
+                ],
+            ),
+            problem(
+                None,
+                ProblemKind::NewWarnings,
+                &["`clippy::let_and_return` at src/lib.rs:2:5: returning the result of a `let` binding"],
+            ),
+            problem(
+                Some("clippy::needless_return"),
+                ProblemKind::NotConverged,
+                &["`clippy::needless_return` at src/lib.rs:5:5: unneeded `return` statement"],
+            ),
+        ];
+        assert_eq!(
+            report(&problems),
+            "
+ERROR: the suggestions of `clippy::needless_return` broke the build of krate 1.0.0 (fixed code in target/lintcheck/fix/krate-1.0.0/all):
+    error at src/lib.rs:2:5: mismatched types
+    suggestion of `clippy::needless_return` at src/lib.rs:2:5
+
+ERROR: the suggestions of the lints together introduced new warnings in krate 1.0.0 (fixed code in target/lintcheck/fix/krate-1.0.0/all):
+    `clippy::let_and_return` at src/lib.rs:2:5: returning the result of a `let` binding
+
+ERROR: the suggestions of `clippy::needless_return` didn't converge in krate 1.0.0 (fixed code in target/lintcheck/fix/krate-1.0.0/all):
+    `clippy::needless_return` at src/lib.rs:5:5: unneeded `return` statement
+"
+        );
+    }
+}
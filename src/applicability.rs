@@ -0,0 +1,584 @@
+// autogenerated by the metadata collector. Run `cargo collect-metadata` to update it
+
+/// The applicability of the suggestions of the lints, sorted by name. `Unresolved` if the lint
+/// doesn't suggest anything or the metadata collector couldn't find out.
+pub static APPLICABILITY: &[(&str, &str)] = &[
+    ("absurd_extreme_comparisons", "Unresolved"),
+    ("alloc_instead_of_core", "Unresolved"),
+    ("allow_attributes_without_reason", "Unresolved"),
+    ("almost_complete_letter_range", "MaybeIncorrect"),
+    ("almost_swapped", "MaybeIncorrect"),
+    ("approx_constant", "Unresolved"),
+    ("arithmetic_side_effects", "Unresolved"),
+    ("as_conversions", "Unresolved"),
+    ("as_underscore", "MachineApplicable"),
+    ("assertions_on_constants", "Unresolved"),
+    ("assertions_on_result_states", "MachineApplicable"),
+    ("assign_op_pattern", "MachineApplicable"),
+    ("async_yields_async", "MaybeIncorrect"),
+    ("await_holding_invalid_type", "Unresolved"),
+    ("await_holding_lock", "Unresolved"),
+    ("await_holding_refcell_ref", "Unresolved"),
+    ("bad_bit_mask", "Unresolved"),
+    ("bind_instead_of_map", "MachineApplicable"),
+    ("blanket_clippy_restriction_lints", "Unresolved"),
+    ("blocks_in_if_conditions", "Unresolved"),
+    ("bool_assert_comparison", "MaybeIncorrect"),
+    ("bool_comparison", "Unresolved"),
+    ("bool_to_int_with_if", "MachineApplicable"),
+    ("borrow_as_ptr", "MachineApplicable"),
+    ("borrow_deref_ref", "MachineApplicable"),
+    ("borrow_interior_mutable_const", "Unresolved"),
+    ("borrowed_box", "Unspecified"),
+    ("box_collection", "Unresolved"),
+    ("boxed_local", "Unresolved"),
+    ("branches_sharing_code", "Unspecified"),
+    ("builtin_type_shadow", "Unresolved"),
+    ("bytes_count_to_len", "MachineApplicable"),
+    ("bytes_nth", "MachineApplicable"),
+    ("cargo_common_metadata", "Unresolved"),
+    ("case_sensitive_file_extension_comparisons", "Unresolved"),
+    ("cast_abs_to_unsigned", "MachineApplicable"),
+    ("cast_enum_constructor", "Unresolved"),
+    ("cast_enum_truncation", "Unresolved"),
+    ("cast_lossless", "MachineApplicable"),
+    ("cast_possible_truncation", "Unresolved"),
+    ("cast_possible_wrap", "Unresolved"),
+    ("cast_precision_loss", "Unresolved"),
+    ("cast_ptr_alignment", "Unresolved"),
+    ("cast_ref_to_mut", "Unresolved"),
+    ("cast_sign_loss", "Unresolved"),
+    ("cast_slice_different_sizes", "Unresolved"),
+    ("cast_slice_from_raw_parts", "MachineApplicable"),
+    ("char_lit_as_u8", "MachineApplicable"),
+    ("chars_last_cmp", "Unresolved"),
+    ("chars_next_cmp", "Unresolved"),
+    ("checked_conversions", "MachineApplicable"),
+    ("clone_double_ref", "MaybeIncorrect"),
+    ("clone_on_copy", "MachineApplicable"),
+    ("clone_on_ref_ptr", "Unspecified"),
+    ("cloned_instead_of_copied", "MachineApplicable"),
+    ("cmp_nan", "Unresolved"),
+    ("cmp_null", "Unresolved"),
+    ("cmp_owned", "MachineApplicable"),
+    ("cognitive_complexity", "Unresolved"),
+    ("collapsible_else_if", "MachineApplicable"),
+    ("collapsible_if", "MachineApplicable"),
+    ("collapsible_match", "Unresolved"),
+    ("collapsible_str_replace", "MachineApplicable"),
+    ("comparison_chain", "Unresolved"),
+    ("comparison_to_empty", "MachineApplicable"),
+    ("copy_iterator", "Unresolved"),
+    ("crate_in_macro_def", "MachineApplicable"),
+    ("create_dir", "MaybeIncorrect"),
+    ("crosspointer_transmute", "Unresolved"),
+    ("dbg_macro", "MachineApplicable"),
+    ("debug_assert_with_mut_call", "Unresolved"),
+    ("decimal_literal_representation", "MachineApplicable"),
+    ("declare_interior_mutable_const", "Unresolved"),
+    ("default_instead_of_iter_empty", "MachineApplicable"),
+    ("default_numeric_fallback", "MaybeIncorrect"),
+    ("default_trait_access", "Unspecified"),
+    ("default_union_representation", "Unresolved"),
+    ("deprecated_cfg_attr", "MachineApplicable"),
+    ("deprecated_semver", "Unresolved"),
+    ("deref_addrof", "MachineApplicable"),
+    ("deref_by_slicing", "Unresolved"),
+    ("derivable_impls", "MachineApplicable"),
+    ("derive_hash_xor_eq", "Unresolved"),
+    ("derive_ord_xor_partial_ord", "Unresolved"),
+    ("derive_partial_eq_without_eq", "MachineApplicable"),
+    ("disallowed_crates", "Unresolved"),
+    ("disallowed_macros", "Unresolved"),
+    ("disallowed_methods", "Unresolved"),
+    ("disallowed_modules", "Unresolved"),
+    ("disallowed_names", "Unresolved"),
+    ("disallowed_script_idents", "Unresolved"),
+    ("disallowed_trait_impls", "Unresolved"),
+    ("disallowed_types", "Unresolved"),
+    ("diverging_sub_expression", "Unresolved"),
+    ("doc_link_with_quotes", "Unresolved"),
+    ("doc_markdown", "MachineApplicable"),
+    ("double_comparisons", "MachineApplicable"),
+    ("double_must_use", "Unresolved"),
+    ("double_neg", "Unresolved"),
+    ("double_parens", "Unresolved"),
+    ("drop_copy", "Unresolved"),
+    ("drop_non_drop", "Unresolved"),
+    ("drop_ref", "Unresolved"),
+    ("duplicate_mod", "Unresolved"),
+    ("duplicate_underscore_argument", "Unresolved"),
+    ("duration_subsec", "MachineApplicable"),
+    ("else_if_without_else", "Unresolved"),
+    ("empty_drop", "MaybeIncorrect"),
+    ("empty_enum", "Unresolved"),
+    ("empty_line_after_outer_attr", "Unresolved"),
+    ("empty_loop", "Unresolved"),
+    ("empty_structs_with_brackets", "MachineApplicable"),
+    ("enum_clike_unportable_variant", "Unresolved"),
+    ("enum_glob_use", "MachineApplicable"),
+    ("enum_variant_names", "Unresolved"),
+    ("eq_op", "Unresolved"),
+    ("equatable_if_let", "MachineApplicable"),
+    ("erasing_op", "Unresolved"),
+    ("err_expect", "MachineApplicable"),
+    ("excessive_precision", "MachineApplicable"),
+    ("exhaustive_enums", "MaybeIncorrect"),
+    ("exhaustive_structs", "MaybeIncorrect"),
+    ("exit", "Unresolved"),
+    ("expect_fun_call", "MachineApplicable"),
+    ("expect_used", "Unresolved"),
+    ("expl_impl_clone_on_copy", "Unresolved"),
+    ("explicit_auto_deref", "MachineApplicable"),
+    ("explicit_counter_loop", "MaybeIncorrect"),
+    ("explicit_deref_methods", "MachineApplicable"),
+    ("explicit_into_iter_loop", "MachineApplicable"),
+    ("explicit_iter_loop", "MachineApplicable"),
+    ("explicit_write", "MachineApplicable"),
+    ("extend_with_drain", "MachineApplicable"),
+    ("extra_unused_lifetimes", "Unresolved"),
+    ("fallible_impl_from", "Unresolved"),
+    ("field_reassign_with_default", "Unresolved"),
+    ("filetype_is_file", "Unresolved"),
+    ("filter_map_identity", "MachineApplicable"),
+    ("filter_map_next", "Unresolved"),
+    ("filter_next", "Unresolved"),
+    ("flat_map_identity", "MachineApplicable"),
+    ("flat_map_option", "MachineApplicable"),
+    ("float_arithmetic", "Unresolved"),
+    ("float_cmp", "Unresolved"),
+    ("float_cmp_const", "Unresolved"),
+    ("float_equality_without_abs", "MaybeIncorrect"),
+    ("fn_address_comparisons", "Unresolved"),
+    ("fn_params_excessive_bools", "Unresolved"),
+    ("fn_to_numeric_cast", "MaybeIncorrect"),
+    ("fn_to_numeric_cast_any", "MaybeIncorrect"),
+    ("fn_to_numeric_cast_with_truncation", "MaybeIncorrect"),
+    ("for_kv_map", "Unresolved"),
+    ("for_loops_over_fallibles", "Unresolved"),
+    ("forget_copy", "Unresolved"),
+    ("forget_non_drop", "Unresolved"),
+    ("forget_ref", "Unresolved"),
+    ("format_in_format_args", "Unresolved"),
+    ("format_push_string", "Unresolved"),
+    ("from_iter_instead_of_collect", "MaybeIncorrect"),
+    ("from_over_into", "Unresolved"),
+    ("from_str_radix_10", "MaybeIncorrect"),
+    ("future_not_send", "Unresolved"),
+    ("get_first", "MachineApplicable"),
+    ("get_last_with_len", "MachineApplicable"),
+    ("get_unwrap", "MachineApplicable"),
+    ("identity_op", "MachineApplicable"),
+    ("if_let_mutex", "Unresolved"),
+    ("if_not_else", "Unresolved"),
+    ("if_same_then_else", "Unresolved"),
+    ("if_then_some_else_none", "Unresolved"),
+    ("ifs_same_cond", "Unresolved"),
+    ("implicit_clone", "MachineApplicable"),
+    ("implicit_hasher", "Unresolved"),
+    ("implicit_return", "MachineApplicable"),
+    ("implicit_saturating_sub", "MachineApplicable"),
+    ("imprecise_flops", "MachineApplicable"),
+    ("incompatible_msrv", "Unresolved"),
+    ("inconsistent_digit_grouping", "MachineApplicable"),
+    ("inconsistent_struct_constructor", "MachineApplicable"),
+    ("index_refutable_slice", "MaybeIncorrect"),
+    ("indexing_slicing", "Unresolved"),
+    ("ineffective_bit_mask", "Unresolved"),
+    ("inefficient_to_string", "MachineApplicable"),
+    ("infallible_destructuring_match", "MachineApplicable"),
+    ("infinite_iter", "Unresolved"),
+    ("inherent_to_string", "Unresolved"),
+    ("inherent_to_string_shadow_display", "Unresolved"),
+    ("init_numbered_fields", "MachineApplicable"),
+    ("inline_always", "Unresolved"),
+    ("inline_asm_x86_att_syntax", "Unresolved"),
+    ("inline_asm_x86_intel_syntax", "Unresolved"),
+    ("inline_fn_without_body", "MachineApplicable"),
+    ("inspect_for_each", "Unresolved"),
+    ("int_plus_one", "MachineApplicable"),
+    ("integer_arithmetic", "Unresolved"),
+    ("integer_division", "Unresolved"),
+    ("into_iter_on_ref", "MachineApplicable"),
+    ("invalid_null_ptr_usage", "MachineApplicable"),
+    ("invalid_regex", "Unresolved"),
+    ("invalid_upcast_comparisons", "Unresolved"),
+    ("invalid_utf8_in_unchecked", "Unresolved"),
+    ("invisible_characters", "MachineApplicable"),
+    ("is_digit_ascii_radix", "MachineApplicable"),
+    ("items_after_statements", "Unresolved"),
+    ("iter_cloned_collect", "MachineApplicable"),
+    ("iter_count", "MachineApplicable"),
+    ("iter_kv_map", "Unresolved"),
+    ("iter_next_loop", "Unresolved"),
+    ("iter_next_slice", "MachineApplicable"),
+    ("iter_not_returning_iterator", "Unresolved"),
+    ("iter_nth", "Unresolved"),
+    ("iter_nth_zero", "MachineApplicable"),
+    ("iter_on_empty_collections", "MaybeIncorrect"),
+    ("iter_on_single_items", "MaybeIncorrect"),
+    ("iter_overeager_cloned", "MachineApplicable"),
+    ("iter_skip_next", "MachineApplicable"),
+    ("iter_with_drain", "MaybeIncorrect"),
+    ("iterator_step_by_zero", "Unresolved"),
+    ("just_underscores_and_digits", "Unresolved"),
+    ("large_const_arrays", "MachineApplicable"),
+    ("large_digit_groups", "MachineApplicable"),
+    ("large_enum_variant", "MaybeIncorrect"),
+    ("large_include_file", "Unresolved"),
+    ("large_stack_arrays", "Unresolved"),
+    ("large_types_passed_by_value", "MaybeIncorrect"),
+    ("layer_violation", "Unresolved"),
+    ("len_without_is_empty", "Unresolved"),
+    ("len_zero", "MachineApplicable"),
+    ("let_and_return", "MachineApplicable"),
+    ("let_underscore_drop", "Unresolved"),
+    ("let_underscore_lock", "Unresolved"),
+    ("let_underscore_must_use", "Unresolved"),
+    ("let_unit_value", "MachineApplicable"),
+    ("linkedlist", "Unresolved"),
+    ("lossy_float_literal", "MachineApplicable"),
+    ("macro_use_imports", "MaybeIncorrect"),
+    ("main_recursion", "Unresolved"),
+    ("manual_assert", "MachineApplicable"),
+    ("manual_async_fn", "MachineApplicable"),
+    ("manual_bits", "MachineApplicable"),
+    ("manual_filter_map", "MachineApplicable"),
+    ("manual_find", "MachineApplicable"),
+    ("manual_find_map", "MachineApplicable"),
+    ("manual_flatten", "MaybeIncorrect"),
+    ("manual_instant_elapsed", "MachineApplicable"),
+    ("manual_map", "MachineApplicable"),
+    ("manual_memcpy", "Unspecified"),
+    ("manual_non_exhaustive", "Unspecified"),
+    ("manual_ok_or", "MachineApplicable"),
+    ("manual_range_contains", "MachineApplicable"),
+    ("manual_rem_euclid", "MachineApplicable"),
+    ("manual_retain", "MachineApplicable"),
+    ("manual_saturating_arithmetic", "MachineApplicable"),
+    ("manual_split_once", "MachineApplicable"),
+    ("manual_str_repeat", "MachineApplicable"),
+    ("manual_string_new", "MachineApplicable"),
+    ("manual_strip", "Unresolved"),
+    ("manual_swap", "MachineApplicable"),
+    ("manual_unwrap_or", "MachineApplicable"),
+    ("many_single_char_names", "Unresolved"),
+    ("map_clone", "MachineApplicable"),
+    ("map_collect_result_unit", "MachineApplicable"),
+    ("map_entry", "MachineApplicable"),
+    ("map_err_ignore", "Unresolved"),
+    ("map_flatten", "MachineApplicable"),
+    ("map_identity", "MachineApplicable"),
+    ("map_unwrap_or", "MachineApplicable"),
+    ("match_as_ref", "MachineApplicable"),
+    ("match_bool", "HasPlaceholders"),
+    ("match_like_matches_macro", "MaybeIncorrect"),
+    ("match_on_vec_items", "MaybeIncorrect"),
+    ("match_overlapping_arm", "Unresolved"),
+    ("match_ref_pats", "Unresolved"),
+    ("match_result_ok", "MachineApplicable"),
+    ("match_same_arms", "MaybeIncorrect"),
+    ("match_single_binding", "MachineApplicable"),
+    ("match_str_case_mismatch", "MachineApplicable"),
+    ("match_wild_err_arm", "Unresolved"),
+    ("match_wildcard_for_single_variants", "MaybeIncorrect"),
+    ("maybe_infinite_iter", "Unresolved"),
+    ("mem_forget", "Unresolved"),
+    ("mem_replace_option_with_none", "MachineApplicable"),
+    ("mem_replace_with_default", "MachineApplicable"),
+    ("mem_replace_with_uninit", "Unresolved"),
+    ("min_max", "Unresolved"),
+    ("mismatched_target_os", "MaybeIncorrect"),
+    ("mismatching_type_param_order", "Unresolved"),
+    ("misrefactored_assign_op", "MaybeIncorrect"),
+    ("missing_const_for_fn", "Unresolved"),
+    ("missing_docs_in_private_items", "Unresolved"),
+    ("missing_enforced_import_renames", "MachineApplicable"),
+    ("missing_errors_doc", "Unresolved"),
+    ("missing_inline_in_public_items", "Unresolved"),
+    ("missing_panics_doc", "Unresolved"),
+    ("missing_safety_doc", "Unresolved"),
+    ("missing_spin_loop", "MachineApplicable"),
+    ("mistyped_literal_suffixes", "MaybeIncorrect"),
+    ("mixed_case_hex_literals", "Unresolved"),
+    ("mixed_read_write_in_expression", "Unresolved"),
+    ("mod_module_files", "Unresolved"),
+    ("module_inception", "Unresolved"),
+    ("module_name_repetitions", "Unresolved"),
+    ("modulo_arithmetic", "Unresolved"),
+    ("modulo_one", "Unresolved"),
+    ("multi_assignments", "Unresolved"),
+    ("multiple_crate_versions", "Unresolved"),
+    ("multiple_inherent_impl", "Unresolved"),
+    ("must_use_candidate", "MachineApplicable"),
+    ("must_use_unit", "MachineApplicable"),
+    ("mut_from_ref", "Unresolved"),
+    ("mut_mut", "Unresolved"),
+    ("mut_mutex_lock", "MaybeIncorrect"),
+    ("mut_range_bound", "Unresolved"),
+    ("mutable_key_type", "Unresolved"),
+    ("mutex_atomic", "Unresolved"),
+    ("mutex_integer", "Unresolved"),
+    ("naive_bytecount", "MaybeIncorrect"),
+    ("needless_arbitrary_self_type", "MachineApplicable"),
+    ("needless_bitwise_bool", "MachineApplicable"),
+    ("needless_bool", "Unresolved"),
+    ("needless_borrow", "MachineApplicable"),
+    ("needless_borrowed_reference", "MachineApplicable"),
+    ("needless_collect", "MaybeIncorrect"),
+    ("needless_continue", "Unresolved"),
+    ("needless_doctest_main", "Unresolved"),
+    ("needless_for_each", "MachineApplicable"),
+    ("needless_late_init", "Unresolved"),
+    ("needless_lifetimes", "Unresolved"),
+    ("needless_match", "MachineApplicable"),
+    ("needless_option_as_deref", "MachineApplicable"),
+    ("needless_option_take", "MachineApplicable"),
+    ("needless_parens_on_range_literals", "MachineApplicable"),
+    ("needless_pass_by_value", "Unresolved"),
+    ("needless_question_mark", "MachineApplicable"),
+    ("needless_range_loop", "Unresolved"),
+    ("needless_return", "MachineApplicable"),
+    ("needless_splitn", "MachineApplicable"),
+    ("needless_update", "Unresolved"),
+    ("neg_cmp_op_on_partial_ord", "Unresolved"),
+    ("neg_multiply", "MachineApplicable"),
+    ("negative_feature_names", "Unresolved"),
+    ("never_loop", "Unspecified"),
+    ("new_ret_no_self", "Unresolved"),
+    ("new_without_default", "MaybeIncorrect"),
+    ("no_effect", "Unresolved"),
+    ("no_effect_replace", "Unresolved"),
+    ("no_effect_underscore_binding", "Unresolved"),
+    ("non_ascii_literal", "MachineApplicable"),
+    ("non_octal_unix_permissions", "MachineApplicable"),
+    ("non_send_fields_in_send_ty", "Unresolved"),
+    ("nonminimal_bool", "MachineApplicable"),
+    ("nonsensical_open_options", "Unresolved"),
+    ("nonstandard_macro_braces", "MachineApplicable"),
+    ("not_unsafe_ptr_arg_deref", "Unresolved"),
+    ("obfuscated_if_else", "MachineApplicable"),
+    ("octal_escapes", "MaybeIncorrect"),
+    ("ok_expect", "Unresolved"),
+    ("only_used_in_recursion", "MaybeIncorrect"),
+    ("op_ref", "MaybeIncorrect"),
+    ("option_as_ref_deref", "MachineApplicable"),
+    ("option_env_unwrap", "Unresolved"),
+    ("option_filter_map", "MachineApplicable"),
+    ("option_if_let_else", "MaybeIncorrect"),
+    ("option_map_or_none", "MachineApplicable"),
+    ("option_map_unit_fn", "MachineApplicable"),
+    ("option_option", "Unresolved"),
+    ("or_fun_call", "HasPlaceholders"),
+    ("or_then_unwrap", "MachineApplicable"),
+    ("out_of_bounds_indexing", "Unresolved"),
+    ("overflow_check_conditional", "Unresolved"),
+    ("overly_complex_bool_expr", "Unspecified"),
+    ("panic", "Unresolved"),
+    ("panic_in_result_fn", "Unresolved"),
+    ("panicking_unwrap", "Unresolved"),
+    ("partialeq_ne_impl", "Unresolved"),
+    ("partialeq_to_none", "MachineApplicable"),
+    ("path_buf_push_overwrite", "MachineApplicable"),
+    ("pattern_type_mismatch", "Unresolved"),
+    ("possible_missing_comma", "Unresolved"),
+    ("precedence", "MachineApplicable"),
+    ("print_in_format_impl", "HasPlaceholders"),
+    ("print_literal", "MachineApplicable"),
+    ("print_stderr", "Unresolved"),
+    ("print_stdout", "Unresolved"),
+    ("print_with_newline", "MachineApplicable"),
+    ("println_empty_string", "MachineApplicable"),
+    ("ptr_arg", "Unspecified"),
+    ("ptr_as_ptr", "MachineApplicable"),
+    ("ptr_eq", "MachineApplicable"),
+    ("ptr_offset_with_cast", "Unresolved"),
+    ("pub_use", "Unresolved"),
+    ("question_mark", "MachineApplicable"),
+    ("range_minus_one", "MachineApplicable"),
+    ("range_plus_one", "MachineApplicable"),
+    ("range_zip_with_len", "Unresolved"),
+    ("rc_buffer", "MachineApplicable"),
+    ("rc_clone_in_vec_init", "HasPlaceholders"),
+    ("rc_mutex", "Unresolved"),
+    ("read_zero_byte_vec", "Unresolved"),
+    ("recursive_format_impl", "Unresolved"),
+    ("redundant_allocation", "Unresolved"),
+    ("redundant_clone", "Unresolved"),
+    ("redundant_closure", "MachineApplicable"),
+    ("redundant_closure_call", "Unresolved"),
+    ("redundant_closure_for_method_calls", "MachineApplicable"),
+    ("redundant_else", "Unresolved"),
+    ("redundant_feature_names", "Unresolved"),
+    ("redundant_field_names", "MachineApplicable"),
+    ("redundant_pattern", "MachineApplicable"),
+    ("redundant_pattern_matching", "MaybeIncorrect"),
+    ("redundant_pub_crate", "MachineApplicable"),
+    ("redundant_slicing", "Unresolved"),
+    ("redundant_static_lifetimes", "MachineApplicable"),
+    ("ref_binding_to_reference", "Unresolved"),
+    ("ref_option_ref", "MaybeIncorrect"),
+    ("repeat_once", "MachineApplicable"),
+    ("rest_pat_in_fully_bound_structs", "Unresolved"),
+    ("result_large_err", "Unresolved"),
+    ("result_map_or_into_option", "MachineApplicable"),
+    ("result_map_unit_fn", "MachineApplicable"),
+    ("result_unit_err", "Unresolved"),
+    ("return_self_not_must_use", "Unresolved"),
+    ("reversed_empty_ranges", "MaybeIncorrect"),
+    ("same_functions_in_if_condition", "Unresolved"),
+    ("same_item_push", "Unresolved"),
+    ("same_name_method", "Unresolved"),
+    ("search_is_some", "MachineApplicable"),
+    ("self_assignment", "Unresolved"),
+    ("self_named_constructors", "Unresolved"),
+    ("self_named_module_files", "Unresolved"),
+    ("semicolon_if_nothing_returned", "MaybeIncorrect"),
+    ("separated_literal_suffix", "MachineApplicable"),
+    ("serde_api_misuse", "Unresolved"),
+    ("shadow_reuse", "Unresolved"),
+    ("shadow_same", "Unresolved"),
+    ("shadow_unrelated", "Unresolved"),
+    ("short_circuit_statement", "MachineApplicable"),
+    ("should_implement_trait", "Unresolved"),
+    ("significant_drop_in_scrutinee", "Unresolved"),
+    ("similar_names", "Unresolved"),
+    ("single_char_add_str", "MachineApplicable"),
+    ("single_char_lifetime_names", "Unresolved"),
+    ("single_char_pattern", "MachineApplicable"),
+    ("single_component_path_imports", "Unresolved"),
+    ("single_element_loop", "MachineApplicable"),
+    ("single_match", "HasPlaceholders"),
+    ("single_match_else", "HasPlaceholders"),
+    ("size_of_in_element_count", "Unresolved"),
+    ("skip_while_next", "Unresolved"),
+    ("slow_vector_initialization", "Unspecified"),
+    ("stable_sort_primitive", "MachineApplicable"),
+    ("std_instead_of_alloc", "Unresolved"),
+    ("std_instead_of_core", "Unresolved"),
+    ("str_to_string", "Unresolved"),
+    ("string_add", "Unresolved"),
+    ("string_add_assign", "Unresolved"),
+    ("string_extend_chars", "MachineApplicable"),
+    ("string_from_utf8_as_bytes", "MachineApplicable"),
+    ("string_lit_as_bytes", "MachineApplicable"),
+    ("string_slice", "Unresolved"),
+    ("string_to_string", "Unresolved"),
+    ("strlen_on_c_strings", "MachineApplicable"),
+    ("struct_excessive_bools", "Unresolved"),
+    ("suboptimal_flops", "MachineApplicable"),
+    ("suspicious_arithmetic_impl", "Unresolved"),
+    ("suspicious_assignment_formatting", "Unresolved"),
+    ("suspicious_else_formatting", "Unresolved"),
+    ("suspicious_map", "Unresolved"),
+    ("suspicious_op_assign_impl", "Unresolved"),
+    ("suspicious_operation_groupings", "Unresolved"),
+    ("suspicious_splitn", "Unresolved"),
+    ("suspicious_to_owned", "MaybeIncorrect"),
+    ("suspicious_unary_op_formatting", "Unresolved"),
+    ("swap_ptr_to_ref", "MachineApplicable"),
+    ("tabs_in_doc_comments", "MaybeIncorrect"),
+    ("temporary_assignment", "Unresolved"),
+    ("to_digit_is_some", "MachineApplicable"),
+    ("to_string_in_format_args", "MachineApplicable"),
+    ("todo", "Unresolved"),
+    ("too_many_arguments", "Unresolved"),
+    ("too_many_lines", "Unresolved"),
+    ("toplevel_ref_arg", "MachineApplicable"),
+    ("trailing_empty_array", "Unresolved"),
+    ("trait_duplication_in_bounds", "MachineApplicable"),
+    ("transmute_bytes_to_str", "MaybeIncorrect"),
+    ("transmute_float_to_int", "Unspecified"),
+    ("transmute_int_to_bool", "Unspecified"),
+    ("transmute_int_to_char", "Unspecified"),
+    ("transmute_int_to_float", "Unspecified"),
+    ("transmute_num_to_bytes", "Unspecified"),
+    ("transmute_ptr_to_ptr", "Unspecified"),
+    ("transmute_ptr_to_ref", "MachineApplicable"),
+    ("transmute_undefined_repr", "Unresolved"),
+    ("transmutes_expressible_as_ptr_casts", "MachineApplicable"),
+    ("transmuting_null", "Unresolved"),
+    ("trim_split_whitespace", "MachineApplicable"),
+    ("trivial_regex", "Unresolved"),
+    ("trivially_copy_pass_by_ref", "Unspecified"),
+    ("try_err", "MachineApplicable"),
+    ("type_complexity", "Unresolved"),
+    ("type_repetition_in_bounds", "Unresolved"),
+    ("undocumented_unsafe_blocks", "Unresolved"),
+    ("undropped_manually_drops", "Unresolved"),
+    ("unicode_not_nfc", "MachineApplicable"),
+    ("unimplemented", "Unresolved"),
+    ("uninit_assumed_init", "Unresolved"),
+    ("uninit_vec", "Unresolved"),
+    ("unit_arg", "MachineApplicable"),
+    ("unit_cmp", "Unresolved"),
+    ("unit_hash", "MaybeIncorrect"),
+    ("unit_return_expecting_ord", "Unresolved"),
+    ("unnecessary_cast", "MachineApplicable"),
+    ("unnecessary_filter_map", "Unresolved"),
+    ("unnecessary_find_map", "Unresolved"),
+    ("unnecessary_fold", "MachineApplicable"),
+    ("unnecessary_join", "MachineApplicable"),
+    ("unnecessary_lazy_evaluations", "MachineApplicable"),
+    ("unnecessary_mut_passed", "Unresolved"),
+    ("unnecessary_operation", "MachineApplicable"),
+    ("unnecessary_owned_empty_strings", "MachineApplicable"),
+    ("unnecessary_self_imports", "MaybeIncorrect"),
+    ("unnecessary_sort_by", "MachineApplicable"),
+    ("unnecessary_to_owned", "MachineApplicable"),
+    ("unnecessary_unwrap", "Unspecified"),
+    ("unnecessary_wraps", "MaybeIncorrect"),
+    ("unneeded_field_pattern", "Unresolved"),
+    ("unneeded_wildcard_pattern", "MachineApplicable"),
+    ("unnested_or_patterns", "MachineApplicable"),
+    ("unreachable", "Unresolved"),
+    ("unreadable_literal", "MachineApplicable"),
+    ("unsafe_derive_deserialize", "Unresolved"),
+    ("unsafe_removed_from_name", "Unresolved"),
+    ("unseparated_literal_suffix", "MachineApplicable"),
+    ("unsound_collection_transmute", "Unresolved"),
+    ("unused_async", "Unresolved"),
+    ("unused_io_amount", "Unresolved"),
+    ("unused_peekable", "Unresolved"),
+    ("unused_rounding", "MachineApplicable"),
+    ("unused_self", "Unresolved"),
+    ("unused_unit", "MachineApplicable"),
+    ("unusual_byte_groupings", "MachineApplicable"),
+    ("unwrap_in_result", "Unresolved"),
+    ("unwrap_or_else_default", "MachineApplicable"),
+    ("unwrap_used", "Unresolved"),
+    ("upper_case_acronyms", "MaybeIncorrect"),
+    ("use_debug", "Unresolved"),
+    ("use_self", "MachineApplicable"),
+    ("used_underscore_binding", "Unresolved"),
+    ("useless_asref", "MachineApplicable"),
+    ("useless_attribute", "MaybeIncorrect"),
+    ("useless_conversion", "MachineApplicable"),
+    ("useless_format", "Unresolved"),
+    ("useless_let_if_seq", "HasPlaceholders"),
+    ("useless_transmute", "Unspecified"),
+    ("useless_vec", "MachineApplicable"),
+    ("vec_box", "MachineApplicable"),
+    ("vec_init_then_push", "HasPlaceholders"),
+    ("vec_resize_to_zero", "MaybeIncorrect"),
+    ("verbose_bit_mask", "MaybeIncorrect"),
+    ("verbose_file_reads", "Unresolved"),
+    ("vtable_address_comparisons", "Unresolved"),
+    ("while_immutable_condition", "Unresolved"),
+    ("while_let_loop", "HasPlaceholders"),
+    ("while_let_on_iterator", "MachineApplicable"),
+    ("wildcard_dependencies", "Unresolved"),
+    ("wildcard_enum_match_arm", "MaybeIncorrect"),
+    ("wildcard_imports", "MachineApplicable"),
+    ("wildcard_in_or_patterns", "Unresolved"),
+    ("write_literal", "MachineApplicable"),
+    ("write_with_newline", "MachineApplicable"),
+    ("writeln_empty_string", "MachineApplicable"),
+    ("wrong_self_convention", "Unresolved"),
+    ("wrong_transmute", "Unresolved"),
+    ("zero_divided_by_zero", "Unresolved"),
+    ("zero_prefixed_literal", "MaybeIncorrect"),
+    ("zero_ptr", "MachineApplicable"),
+    ("zero_sized_map_values", "Unresolved"),
+    ("zst_offset", "Unresolved"),
+];
@@ -0,0 +1,155 @@
+//! Computes the lines changed relative to a git revision, see `cargo clippy --diff-base`.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs};
+
+/// Writes the lines of the working tree that changed since `rev` to a file read by
+/// `clippy-driver`, and returns its path.
+///
+/// Each line of the file is the absolute path of a changed file, followed by `:` and the ranges of
+/// its changed lines, like `10-12,20-20`. Untracked files have no ranges, as they are entirely new.
+/// The name of the file depends on its content, so that Cargo lints the crates again when the
+/// changed lines differ from the previous run.
+pub fn write_changed_lines(rev: &str) -> Result<PathBuf, String> {
+    let root = git(&["rev-parse", "--show-toplevel"])?;
+    let root = Path::new(root.trim_end());
+    let diff = git(&[
+        "-c",
+        "core.quotepath=off",
+        "diff",
+        "--unified=0",
+        "--no-color",
+        "--no-ext-diff",
+        "--no-prefix",
+        rev,
+        "--",
+    ])?;
+    let untracked = git(&[
+        "-c",
+        "core.quotepath=off",
+        "ls-files",
+        "--others",
+        "--exclude-standard",
+        "--full-name",
+        ":/",
+    ])?;
+
+    let mut changed = parse_diff(&diff);
+    for file in untracked.lines() {
+        changed.insert(file.to_string(), Vec::new());
+    }
+
+    let mut content = String::new();
+    for (file, ranges) in changed {
+        let ranges: Vec<_> = ranges.iter().map(|(start, end)| format!("{}-{}", start, end)).collect();
+        let _ = writeln!(content, "{}:{}", root.join(file).display(), ranges.join(","));
+    }
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    let path = env::temp_dir().join(format!("clippy-diff-{:016x}.txt", hasher.finish()));
+    fs::write(&path, content).map_err(|e| format!("error writing `{}`: {}", path.display(), e))?;
+    Ok(path)
+}
+
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| format!("could not run git: {}", e))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(format!(
+            "`git {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        ))
+    }
+}
+
+/// Returns the ranges of lines added or modified in each file of the output of `git diff
+/// --unified=0 --no-prefix`. The lines that were only removed aren't part of the new files.
+fn parse_diff(diff: &str) -> BTreeMap<String, Vec<(usize, usize)>> {
+    let mut changed = BTreeMap::<_, Vec<_>>::new();
+    let mut file = None;
+    let mut in_header = false;
+    for line in diff.lines() {
+        if line.starts_with("diff ") {
+            in_header = true;
+            file = None;
+        } else if let Some(path) = line.strip_prefix("+++ ").filter(|_| in_header) {
+            file = (path != "/dev/null").then_some(path);
+        } else if let Some(hunk) = line.strip_prefix("@@ ") {
+            in_header = false;
+            // `@@ -start[,count] +start[,count] @@`
+            let range = hunk
+                .split(' ')
+                .nth(1)
+                .and_then(|range| range.strip_prefix('+'))
+                .and_then(parse_range);
+            if let (Some(file), Some((start, count))) = (file, range) {
+                if count > 0 {
+                    changed
+                        .entry(file.to_string())
+                        .or_default()
+                        .push((start, start + count - 1));
+                }
+            }
+        }
+    }
+    changed
+}
+
+fn parse_range(range: &str) -> Option<(usize, usize)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_diff;
+
+    #[test]
+    fn diff() {
+        let diff = "\
+diff --git src/lib.rs src/lib.rs
+index 1111111..2222222 100644
+--- src/lib.rs
++++ src/lib.rs
+@@ -3 +3 @@ fn f() {
+-    let x = 1;
++    let x = 2;
+@@ -10,0 +11,3 @@ fn g() {
++++ a = b;
++
++}
+@@ -20,2 +23,0 @@ fn h() {
+-    h();
+-    h();
+diff --git src/old.rs src/old.rs
+deleted file mode 100644
+--- src/old.rs
++++ /dev/null
+@@ -1 +0,0 @@
+-fn old() {}
+diff --git src/new.rs src/new.rs
+new file mode 100644
+--- /dev/null
++++ src/new.rs
+@@ -0,0 +1,2 @@
++fn new() {}
++
+";
+        let changed = parse_diff(diff);
+        assert_eq!(changed.len(), 2);
+        assert_eq!(changed["src/lib.rs"], [(3, 3), (11, 13)]);
+        assert_eq!(changed["src/new.rs"], [(1, 2)]);
+    }
+}
@@ -6,591 +6,614 @@ macro_rules! include_lint {
     };
 }
 
+/// The documentation and metadata of a lint, the same as in the lint list.
+pub struct LintDoc {
+    pub name: &'static str,
+    pub group: &'static str,
+    /// The version the lint was added in.
+    pub version: &'static str,
+    /// The keys of the configuration file that configure the lint.
+    pub config: &'static [&'static str],
+    pub desc: &'static str,
+    pub docs: &'static str,
+}
+
 macro_rules! docs {
-    ($($lint_name: expr,)*) => {
-        pub fn explain(lint: &str) {
-            println!("{}", match lint {
-                $(
-                    $lint_name => include_lint!(concat!("docs/", concat!($lint_name, ".txt"))),
-                )*
-                _ => "unknown lint",
-            })
-        }
+    ($(($name: literal, $group: ident, $version: literal, [$($config: literal),*], $desc: literal),)*) => {
+        /// All the lints, sorted by name.
+        pub static LINTS: &[LintDoc] = &[
+            $(
+                LintDoc {
+                    name: $name,
+                    group: stringify!($group),
+                    version: $version,
+                    config: &[$($config),*],
+                    desc: $desc,
+                    docs: include_lint!(concat!("docs/", concat!($name, ".txt"))),
+                },
+            )*
+        ];
     }
 }
 
 docs! {
-    "absurd_extreme_comparisons",
-    "alloc_instead_of_core",
-    "allow_attributes_without_reason",
-    "almost_complete_letter_range",
-    "almost_swapped",
-    "approx_constant",
-    "arithmetic_side_effects",
-    "as_conversions",
-    "as_underscore",
-    "assertions_on_constants",
-    "assertions_on_result_states",
-    "assign_op_pattern",
-    "async_yields_async",
-    "await_holding_invalid_type",
-    "await_holding_lock",
-    "await_holding_refcell_ref",
-    "bad_bit_mask",
-    "bind_instead_of_map",
-    "blanket_clippy_restriction_lints",
-    "blocks_in_if_conditions",
-    "bool_assert_comparison",
-    "bool_comparison",
-    "bool_to_int_with_if",
-    "borrow_as_ptr",
-    "borrow_deref_ref",
-    "borrow_interior_mutable_const",
-    "borrowed_box",
-    "box_collection",
-    "boxed_local",
-    "branches_sharing_code",
-    "builtin_type_shadow",
-    "bytes_count_to_len",
-    "bytes_nth",
-    "cargo_common_metadata",
-    "case_sensitive_file_extension_comparisons",
-    "cast_abs_to_unsigned",
-    "cast_enum_constructor",
-    "cast_enum_truncation",
-    "cast_lossless",
-    "cast_possible_truncation",
-    "cast_possible_wrap",
-    "cast_precision_loss",
-    "cast_ptr_alignment",
-    "cast_ref_to_mut",
-    "cast_sign_loss",
-    "cast_slice_different_sizes",
-    "cast_slice_from_raw_parts",
-    "char_lit_as_u8",
-    "chars_last_cmp",
-    "chars_next_cmp",
-    "checked_conversions",
-    "clone_double_ref",
-    "clone_on_copy",
-    "clone_on_ref_ptr",
-    "cloned_instead_of_copied",
-    "cmp_nan",
-    "cmp_null",
-    "cmp_owned",
-    "cognitive_complexity",
-    "collapsible_else_if",
-    "collapsible_if",
-    "collapsible_match",
-    "collapsible_str_replace",
-    "comparison_chain",
-    "comparison_to_empty",
-    "copy_iterator",
-    "crate_in_macro_def",
-    "create_dir",
-    "crosspointer_transmute",
-    "dbg_macro",
-    "debug_assert_with_mut_call",
-    "decimal_literal_representation",
-    "declare_interior_mutable_const",
-    "default_instead_of_iter_empty",
-    "default_numeric_fallback",
-    "default_trait_access",
-    "default_union_representation",
-    "deprecated_cfg_attr",
-    "deprecated_semver",
-    "deref_addrof",
-    "deref_by_slicing",
-    "derivable_impls",
-    "derive_hash_xor_eq",
-    "derive_ord_xor_partial_ord",
-    "derive_partial_eq_without_eq",
-    "disallowed_methods",
-    "disallowed_names",
-    "disallowed_script_idents",
-    "disallowed_types",
-    "diverging_sub_expression",
-    "doc_link_with_quotes",
-    "doc_markdown",
-    "double_comparisons",
-    "double_must_use",
-    "double_neg",
-    "double_parens",
-    "drop_copy",
-    "drop_non_drop",
-    "drop_ref",
-    "duplicate_mod",
-    "duplicate_underscore_argument",
-    "duration_subsec",
-    "else_if_without_else",
-    "empty_drop",
-    "empty_enum",
-    "empty_line_after_outer_attr",
-    "empty_loop",
-    "empty_structs_with_brackets",
-    "enum_clike_unportable_variant",
-    "enum_glob_use",
-    "enum_variant_names",
-    "eq_op",
-    "equatable_if_let",
-    "erasing_op",
-    "err_expect",
-    "excessive_precision",
-    "exhaustive_enums",
-    "exhaustive_structs",
-    "exit",
-    "expect_fun_call",
-    "expect_used",
-    "expl_impl_clone_on_copy",
-    "explicit_auto_deref",
-    "explicit_counter_loop",
-    "explicit_deref_methods",
-    "explicit_into_iter_loop",
-    "explicit_iter_loop",
-    "explicit_write",
-    "extend_with_drain",
-    "extra_unused_lifetimes",
-    "fallible_impl_from",
-    "field_reassign_with_default",
-    "filetype_is_file",
-    "filter_map_identity",
-    "filter_map_next",
-    "filter_next",
-    "flat_map_identity",
-    "flat_map_option",
-    "float_arithmetic",
-    "float_cmp",
-    "float_cmp_const",
-    "float_equality_without_abs",
-    "fn_address_comparisons",
-    "fn_params_excessive_bools",
-    "fn_to_numeric_cast",
-    "fn_to_numeric_cast_any",
-    "fn_to_numeric_cast_with_truncation",
-    "for_kv_map",
-    "for_loops_over_fallibles",
-    "forget_copy",
-    "forget_non_drop",
-    "forget_ref",
-    "format_in_format_args",
-    "format_push_string",
-    "from_iter_instead_of_collect",
-    "from_over_into",
-    "from_str_radix_10",
-    "future_not_send",
-    "get_first",
-    "get_last_with_len",
-    "get_unwrap",
-    "identity_op",
-    "if_let_mutex",
-    "if_not_else",
-    "if_same_then_else",
-    "if_then_some_else_none",
-    "ifs_same_cond",
-    "implicit_clone",
-    "implicit_hasher",
-    "implicit_return",
-    "implicit_saturating_sub",
-    "imprecise_flops",
-    "inconsistent_digit_grouping",
-    "inconsistent_struct_constructor",
-    "index_refutable_slice",
-    "indexing_slicing",
-    "ineffective_bit_mask",
-    "inefficient_to_string",
-    "infallible_destructuring_match",
-    "infinite_iter",
-    "inherent_to_string",
-    "inherent_to_string_shadow_display",
-    "init_numbered_fields",
-    "inline_always",
-    "inline_asm_x86_att_syntax",
-    "inline_asm_x86_intel_syntax",
-    "inline_fn_without_body",
-    "inspect_for_each",
-    "int_plus_one",
-    "integer_arithmetic",
-    "integer_division",
-    "into_iter_on_ref",
-    "invalid_null_ptr_usage",
-    "invalid_regex",
-    "invalid_upcast_comparisons",
-    "invalid_utf8_in_unchecked",
-    "invisible_characters",
-    "is_digit_ascii_radix",
-    "items_after_statements",
-    "iter_cloned_collect",
-    "iter_count",
-    "iter_kv_map",
-    "iter_next_loop",
-    "iter_next_slice",
-    "iter_not_returning_iterator",
-    "iter_nth",
-    "iter_nth_zero",
-    "iter_on_empty_collections",
-    "iter_on_single_items",
-    "iter_overeager_cloned",
-    "iter_skip_next",
-    "iter_with_drain",
-    "iterator_step_by_zero",
-    "just_underscores_and_digits",
-    "large_const_arrays",
-    "large_digit_groups",
-    "large_enum_variant",
-    "large_include_file",
-    "large_stack_arrays",
-    "large_types_passed_by_value",
-    "len_without_is_empty",
-    "len_zero",
-    "let_and_return",
-    "let_underscore_drop",
-    "let_underscore_lock",
-    "let_underscore_must_use",
-    "let_unit_value",
-    "linkedlist",
-    "lossy_float_literal",
-    "macro_use_imports",
-    "main_recursion",
-    "manual_assert",
-    "manual_async_fn",
-    "manual_bits",
-    "manual_filter_map",
-    "manual_find",
-    "manual_find_map",
-    "manual_flatten",
-    "manual_instant_elapsed",
-    "manual_map",
-    "manual_memcpy",
-    "manual_non_exhaustive",
-    "manual_ok_or",
-    "manual_range_contains",
-    "manual_rem_euclid",
-    "manual_retain",
-    "manual_saturating_arithmetic",
-    "manual_split_once",
-    "manual_str_repeat",
-    "manual_string_new",
-    "manual_strip",
-    "manual_swap",
-    "manual_unwrap_or",
-    "many_single_char_names",
-    "map_clone",
-    "map_collect_result_unit",
-    "map_entry",
-    "map_err_ignore",
-    "map_flatten",
-    "map_identity",
-    "map_unwrap_or",
-    "match_as_ref",
-    "match_bool",
-    "match_like_matches_macro",
-    "match_on_vec_items",
-    "match_overlapping_arm",
-    "match_ref_pats",
-    "match_result_ok",
-    "match_same_arms",
-    "match_single_binding",
-    "match_str_case_mismatch",
-    "match_wild_err_arm",
-    "match_wildcard_for_single_variants",
-    "maybe_infinite_iter",
-    "mem_forget",
-    "mem_replace_option_with_none",
-    "mem_replace_with_default",
-    "mem_replace_with_uninit",
-    "min_max",
-    "mismatched_target_os",
-    "mismatching_type_param_order",
-    "misrefactored_assign_op",
-    "missing_const_for_fn",
-    "missing_docs_in_private_items",
-    "missing_enforced_import_renames",
-    "missing_errors_doc",
-    "missing_inline_in_public_items",
-    "missing_panics_doc",
-    "missing_safety_doc",
-    "missing_spin_loop",
-    "mistyped_literal_suffixes",
-    "mixed_case_hex_literals",
-    "mixed_read_write_in_expression",
-    "mod_module_files",
-    "module_inception",
-    "module_name_repetitions",
-    "modulo_arithmetic",
-    "modulo_one",
-    "multi_assignments",
-    "multiple_crate_versions",
-    "multiple_inherent_impl",
-    "must_use_candidate",
-    "must_use_unit",
-    "mut_from_ref",
-    "mut_mut",
-    "mut_mutex_lock",
-    "mut_range_bound",
-    "mutable_key_type",
-    "mutex_atomic",
-    "mutex_integer",
-    "naive_bytecount",
-    "needless_arbitrary_self_type",
-    "needless_bitwise_bool",
-    "needless_bool",
-    "needless_borrow",
-    "needless_borrowed_reference",
-    "needless_collect",
-    "needless_continue",
-    "needless_doctest_main",
-    "needless_for_each",
-    "needless_late_init",
-    "needless_lifetimes",
-    "needless_match",
-    "needless_option_as_deref",
-    "needless_option_take",
-    "needless_parens_on_range_literals",
-    "needless_pass_by_value",
-    "needless_question_mark",
-    "needless_range_loop",
-    "needless_return",
-    "needless_splitn",
-    "needless_update",
-    "neg_cmp_op_on_partial_ord",
-    "neg_multiply",
-    "negative_feature_names",
-    "never_loop",
-    "new_ret_no_self",
-    "new_without_default",
-    "no_effect",
-    "no_effect_replace",
-    "no_effect_underscore_binding",
-    "non_ascii_literal",
-    "non_octal_unix_permissions",
-    "non_send_fields_in_send_ty",
-    "nonminimal_bool",
-    "nonsensical_open_options",
-    "nonstandard_macro_braces",
-    "not_unsafe_ptr_arg_deref",
-    "obfuscated_if_else",
-    "octal_escapes",
-    "ok_expect",
-    "only_used_in_recursion",
-    "op_ref",
-    "option_as_ref_deref",
-    "option_env_unwrap",
-    "option_filter_map",
-    "option_if_let_else",
-    "option_map_or_none",
-    "option_map_unit_fn",
-    "option_option",
-    "or_fun_call",
-    "or_then_unwrap",
-    "out_of_bounds_indexing",
-    "overflow_check_conditional",
-    "overly_complex_bool_expr",
-    "panic",
-    "panic_in_result_fn",
-    "panicking_unwrap",
-    "partialeq_ne_impl",
-    "partialeq_to_none",
-    "path_buf_push_overwrite",
-    "pattern_type_mismatch",
-    "possible_missing_comma",
-    "precedence",
-    "print_in_format_impl",
-    "print_literal",
-    "print_stderr",
-    "print_stdout",
-    "print_with_newline",
-    "println_empty_string",
-    "ptr_arg",
-    "ptr_as_ptr",
-    "ptr_eq",
-    "ptr_offset_with_cast",
-    "pub_use",
-    "question_mark",
-    "range_minus_one",
-    "range_plus_one",
-    "range_zip_with_len",
-    "rc_buffer",
-    "rc_clone_in_vec_init",
-    "rc_mutex",
-    "read_zero_byte_vec",
-    "recursive_format_impl",
-    "redundant_allocation",
-    "redundant_clone",
-    "redundant_closure",
-    "redundant_closure_call",
-    "redundant_closure_for_method_calls",
-    "redundant_else",
-    "redundant_feature_names",
-    "redundant_field_names",
-    "redundant_pattern",
-    "redundant_pattern_matching",
-    "redundant_pub_crate",
-    "redundant_slicing",
-    "redundant_static_lifetimes",
-    "ref_binding_to_reference",
-    "ref_option_ref",
-    "repeat_once",
-    "rest_pat_in_fully_bound_structs",
-    "result_large_err",
-    "result_map_or_into_option",
-    "result_map_unit_fn",
-    "result_unit_err",
-    "return_self_not_must_use",
-    "reversed_empty_ranges",
-    "same_functions_in_if_condition",
-    "same_item_push",
-    "same_name_method",
-    "search_is_some",
-    "self_assignment",
-    "self_named_constructors",
-    "self_named_module_files",
-    "semicolon_if_nothing_returned",
-    "separated_literal_suffix",
-    "serde_api_misuse",
-    "shadow_reuse",
-    "shadow_same",
-    "shadow_unrelated",
-    "short_circuit_statement",
-    "should_implement_trait",
-    "significant_drop_in_scrutinee",
-    "similar_names",
-    "single_char_add_str",
-    "single_char_lifetime_names",
-    "single_char_pattern",
-    "single_component_path_imports",
-    "single_element_loop",
-    "single_match",
-    "single_match_else",
-    "size_of_in_element_count",
-    "skip_while_next",
-    "slow_vector_initialization",
-    "stable_sort_primitive",
-    "std_instead_of_alloc",
-    "std_instead_of_core",
-    "str_to_string",
-    "string_add",
-    "string_add_assign",
-    "string_extend_chars",
-    "string_from_utf8_as_bytes",
-    "string_lit_as_bytes",
-    "string_slice",
-    "string_to_string",
-    "strlen_on_c_strings",
-    "struct_excessive_bools",
-    "suboptimal_flops",
-    "suspicious_arithmetic_impl",
-    "suspicious_assignment_formatting",
-    "suspicious_else_formatting",
-    "suspicious_map",
-    "suspicious_op_assign_impl",
-    "suspicious_operation_groupings",
-    "suspicious_splitn",
-    "suspicious_to_owned",
-    "suspicious_unary_op_formatting",
-    "swap_ptr_to_ref",
-    "tabs_in_doc_comments",
-    "temporary_assignment",
-    "to_digit_is_some",
-    "to_string_in_format_args",
-    "todo",
-    "too_many_arguments",
-    "too_many_lines",
-    "toplevel_ref_arg",
-    "trailing_empty_array",
-    "trait_duplication_in_bounds",
-    "transmute_bytes_to_str",
-    "transmute_float_to_int",
-    "transmute_int_to_bool",
-    "transmute_int_to_char",
-    "transmute_int_to_float",
-    "transmute_num_to_bytes",
-    "transmute_ptr_to_ptr",
-    "transmute_ptr_to_ref",
-    "transmute_undefined_repr",
-    "transmutes_expressible_as_ptr_casts",
-    "transmuting_null",
-    "trim_split_whitespace",
-    "trivial_regex",
-    "trivially_copy_pass_by_ref",
-    "try_err",
-    "type_complexity",
-    "type_repetition_in_bounds",
-    "undocumented_unsafe_blocks",
-    "undropped_manually_drops",
-    "unicode_not_nfc",
-    "unimplemented",
-    "uninit_assumed_init",
-    "uninit_vec",
-    "unit_arg",
-    "unit_cmp",
-    "unit_hash",
-    "unit_return_expecting_ord",
-    "unnecessary_cast",
-    "unnecessary_filter_map",
-    "unnecessary_find_map",
-    "unnecessary_fold",
-    "unnecessary_join",
-    "unnecessary_lazy_evaluations",
-    "unnecessary_mut_passed",
-    "unnecessary_operation",
-    "unnecessary_owned_empty_strings",
-    "unnecessary_self_imports",
-    "unnecessary_sort_by",
-    "unnecessary_to_owned",
-    "unnecessary_unwrap",
-    "unnecessary_wraps",
-    "unneeded_field_pattern",
-    "unneeded_wildcard_pattern",
-    "unnested_or_patterns",
-    "unreachable",
-    "unreadable_literal",
-    "unsafe_derive_deserialize",
-    "unsafe_removed_from_name",
-    "unseparated_literal_suffix",
-    "unsound_collection_transmute",
-    "unused_async",
-    "unused_io_amount",
-    "unused_peekable",
-    "unused_rounding",
-    "unused_self",
-    "unused_unit",
-    "unusual_byte_groupings",
-    "unwrap_in_result",
-    "unwrap_or_else_default",
-    "unwrap_used",
-    "upper_case_acronyms",
-    "use_debug",
-    "use_self",
-    "used_underscore_binding",
-    "useless_asref",
-    "useless_attribute",
-    "useless_conversion",
-    "useless_format",
-    "useless_let_if_seq",
-    "useless_transmute",
-    "useless_vec",
-    "vec_box",
-    "vec_init_then_push",
-    "vec_resize_to_zero",
-    "verbose_bit_mask",
-    "verbose_file_reads",
-    "vtable_address_comparisons",
-    "while_immutable_condition",
-    "while_let_loop",
-    "while_let_on_iterator",
-    "wildcard_dependencies",
-    "wildcard_enum_match_arm",
-    "wildcard_imports",
-    "wildcard_in_or_patterns",
-    "write_literal",
-    "write_with_newline",
-    "writeln_empty_string",
-    "wrong_self_convention",
-    "wrong_transmute",
-    "zero_divided_by_zero",
-    "zero_prefixed_literal",
-    "zero_ptr",
-    "zero_sized_map_values",
-    "zst_offset",
+    ("absurd_extreme_comparisons", correctness, "pre 1.29.0", [], "a comparison with a maximum or minimum value that is always true or false"),
+    ("alloc_instead_of_core", restriction, "1.64.0", [], "type is imported from alloc when available in core"),
+    ("allow_attributes_without_reason", restriction, "1.61.0", [], "ensures that all `allow` and `expect` attributes have a reason"),
+    ("almost_complete_letter_range", suspicious, "1.63.0", [], "almost complete letter range"),
+    ("almost_swapped", correctness, "pre 1.29.0", [], "`foo = bar; bar = foo` sequence"),
+    ("approx_constant", correctness, "pre 1.29.0", ["msrv"], "the approximate of a known float constant (in `std::fXX::consts`)"),
+    ("arithmetic_side_effects", restriction, "1.64.0", ["arithmetic-side-effects-allowed"], "any arithmetic expression that can cause side effects like overflows or panics"),
+    ("as_conversions", restriction, "1.41.0", [], "using a potentially dangerous silent `as` conversion"),
+    ("as_underscore", restriction, "1.63.0", [], "detects `as _` conversion"),
+    ("assertions_on_constants", style, "1.34.0", [], "`assert!(true)` / `assert!(false)` will be optimized out by the compiler, and should probably be replaced by a `panic!()` or `unreachable!()`"),
+    ("assertions_on_result_states", restriction, "1.64.0", [], "`assert!(r.is_ok())`/`assert!(r.is_err())` gives worse error message than directly calling `r.unwrap()`/`r.unwrap_err()`"),
+    ("assign_op_pattern", style, "pre 1.29.0", [], "assigning the result of an operation on a variable to that same variable"),
+    ("async_yields_async", correctness, "1.48.0", [], "async blocks that return a type that can be awaited"),
+    ("await_holding_invalid_type", suspicious, "1.62.0", ["await-holding-invalid-types"], "holding a type across an await point which is not allowed to be held as per the configuration"),
+    ("await_holding_lock", suspicious, "1.45.0", [], "inside an async function, holding a `MutexGuard` while calling `await`"),
+    ("await_holding_refcell_ref", suspicious, "1.49.0", [], "inside an async function, holding a `RefCell` ref while calling `await`"),
+    ("bad_bit_mask", correctness, "pre 1.29.0", [], "expressions of the form `_ & mask == select` that will only ever return `true` or `false`"),
+    ("bind_instead_of_map", complexity, "1.45.0", [], "using `Option.and_then(|x| Some(y))`, which is more succinctly expressed as `map(|x| y)`"),
+    ("blanket_clippy_restriction_lints", suspicious, "1.47.0", [], "enabling the complete restriction group"),
+    ("blocks_in_if_conditions", style, "1.45.0", [], "useless or complex blocks that can be eliminated in conditions"),
+    ("bool_assert_comparison", style, "1.53.0", [], "Using a boolean as comparison value in an assert_* macro when there is no need"),
+    ("bool_comparison", complexity, "pre 1.29.0", [], "comparing a variable to a boolean, e.g., `if x == true` or `if x != true`"),
+    ("bool_to_int_with_if", style, "1.65.0", [], "using if to convert bool to int"),
+    ("borrow_as_ptr", pedantic, "1.60.0", ["msrv"], "borrowing just to cast to a raw pointer"),
+    ("borrow_deref_ref", complexity, "1.63.0", [], "deref on an immutable reference returns the same type as itself"),
+    ("borrow_interior_mutable_const", style, "pre 1.29.0", [], "referencing `const` with interior mutability"),
+    ("borrowed_box", complexity, "pre 1.29.0", [], "a borrow of a boxed type"),
+    ("box_collection", perf, "1.57.0", ["avoid-breaking-exported-api"], "usage of `Box<Vec<T>>`, vector elements are already on the heap"),
+    ("boxed_local", perf, "pre 1.29.0", ["too-large-for-stack"], "using `Box<T>` where unnecessary"),
+    ("branches_sharing_code", nursery, "1.53.0", [], "`if` statement with shared code in all blocks"),
+    ("builtin_type_shadow", style, "pre 1.29.0", [], "shadowing a builtin type"),
+    ("bytes_count_to_len", complexity, "1.62.0", [], "Using `bytes().count()` when `len()` performs the same functionality"),
+    ("bytes_nth", style, "1.52.0", [], "replace `.bytes().nth()` with `.as_bytes().get()`"),
+    ("cargo_common_metadata", cargo, "1.32.0", [], "common metadata is defined in `Cargo.toml`"),
+    ("case_sensitive_file_extension_comparisons", pedantic, "1.51.0", [], "Checks for calls to ends_with with case-sensitive file extensions"),
+    ("cast_abs_to_unsigned", suspicious, "1.62.0", ["msrv"], "casting the result of `abs()` to an unsigned integer can panic"),
+    ("cast_enum_constructor", suspicious, "1.61.0", [], "casts from an enum tuple constructor to an integer"),
+    ("cast_enum_truncation", suspicious, "1.61.0", [], "casts from an enum type to an integral type which will truncate the value"),
+    ("cast_lossless", pedantic, "pre 1.29.0", [], "casts using `as` that are known to be lossless, e.g., `x as u64` where `x: u8`"),
+    ("cast_possible_truncation", pedantic, "pre 1.29.0", [], "casts that may cause truncation of the value, e.g., `x as u8` where `x: u32`, or `x as i32` where `x: f32`"),
+    ("cast_possible_wrap", pedantic, "pre 1.29.0", [], "casts that may cause wrapping around the value, e.g., `x as i32` where `x: u32` and `x > i32::MAX`"),
+    ("cast_precision_loss", pedantic, "pre 1.29.0", [], "casts that cause loss of precision, e.g., `x as f32` where `x: u64`"),
+    ("cast_ptr_alignment", pedantic, "pre 1.29.0", [], "cast from a pointer to a more-strictly-aligned pointer"),
+    ("cast_ref_to_mut", correctness, "1.33.0", [], "a cast of reference to a mutable pointer"),
+    ("cast_sign_loss", pedantic, "pre 1.29.0", [], "casts from signed types to unsigned types, e.g., `x as u32` where `x: i32`"),
+    ("cast_slice_different_sizes", correctness, "1.61.0", [], "casting using `as` between raw pointers to slices of types with different sizes"),
+    ("cast_slice_from_raw_parts", suspicious, "1.64.0", [], "casting a slice created from a pointer and length to a slice pointer"),
+    ("char_lit_as_u8", complexity, "pre 1.29.0", [], "casting a character literal to `u8` truncates"),
+    ("chars_last_cmp", style, "pre 1.29.0", [], "using `.chars().last()` or `.chars().next_back()` to check if a string ends with a char"),
+    ("chars_next_cmp", style, "pre 1.29.0", [], "using `.chars().next()` to check if a string starts with a char"),
+    ("checked_conversions", pedantic, "1.37.0", ["msrv"], "`try_from` could replace manual bounds checking when casting"),
+    ("clone_double_ref", correctness, "pre 1.29.0", [], "using `clone` on `&&T`"),
+    ("clone_on_copy", complexity, "pre 1.29.0", [], "using `clone` on a `Copy` type"),
+    ("clone_on_ref_ptr", restriction, "pre 1.29.0", [], "using 'clone' on a ref-counted pointer"),
+    ("cloned_instead_of_copied", pedantic, "1.53.0", ["msrv"], "used `cloned` where `copied` could be used instead"),
+    ("cmp_nan", correctness, "pre 1.29.0", [], "comparisons to `NAN`, which will always return false, probably not intended"),
+    ("cmp_null", style, "pre 1.29.0", [], "comparing a pointer to a null pointer, suggesting to use `.is_null()` instead"),
+    ("cmp_owned", perf, "pre 1.29.0", [], "creating owned instances for comparing with others, e.g., `x == \"foo\".to_string()`"),
+    ("cognitive_complexity", nursery, "1.35.0", ["cognitive-complexity-threshold"], "functions that should be split up into multiple functions"),
+    ("collapsible_else_if", style, "1.51.0", [], "nested `else`-`if` expressions that can be collapsed (e.g., `else { if x { ... } }`)"),
+    ("collapsible_if", style, "pre 1.29.0", [], "nested `if`s that can be collapsed (e.g., `if x { if y { ... } }`"),
+    ("collapsible_match", style, "1.50.0", [], "Nested `match` or `if let` expressions where the patterns may be \"collapsed\" together."),
+    ("collapsible_str_replace", perf, "1.64.0", [], "collapse consecutive calls to str::replace (2 or more) into a single call"),
+    ("comparison_chain", style, "1.40.0", [], "`if`s that can be rewritten with `match` and `cmp`"),
+    ("comparison_to_empty", style, "1.49.0", [], "checking `x == \"\"` or `x == []` (or similar) when `.is_empty()` could be used instead"),
+    ("copy_iterator", pedantic, "1.30.0", [], "implementing `Iterator` on a `Copy` type"),
+    ("crate_in_macro_def", suspicious, "1.62.0", [], "using `crate` in a macro definition"),
+    ("create_dir", restriction, "1.48.0", [], "calling `std::fs::create_dir` instead of `std::fs::create_dir_all`"),
+    ("crosspointer_transmute", complexity, "pre 1.29.0", [], "transmutes that have to or from types that are a pointer to the other"),
+    ("dbg_macro", restriction, "1.34.0", ["allow-dbg-in-tests"], "`dbg!` macro is intended as a debugging tool"),
+    ("debug_assert_with_mut_call", nursery, "1.40.0", [], "mutable arguments in `debug_assert{,_ne,_eq}!`"),
+    ("decimal_literal_representation", restriction, "pre 1.29.0", ["literal-representation-threshold"], "using decimal representation when hexadecimal would be better"),
+    ("declare_interior_mutable_const", style, "pre 1.29.0", [], "declaring `const` with interior mutability"),
+    ("default_instead_of_iter_empty", style, "1.64.0", [], "check `std::iter::Empty::default()` and replace with `std::iter::empty()`"),
+    ("default_numeric_fallback", restriction, "1.52.0", [], "usage of unconstrained numeric literals which may cause default numeric fallback."),
+    ("default_trait_access", pedantic, "pre 1.29.0", [], "checks for literal calls to `Default::default()`"),
+    ("default_union_representation", restriction, "1.60.0", [], "unions without a `#[repr(C)]` attribute"),
+    ("deprecated_cfg_attr", complexity, "1.32.0", ["msrv"], "usage of `cfg_attr(rustfmt)` instead of tool attributes"),
+    ("deprecated_semver", correctness, "pre 1.29.0", [], "use of `#[deprecated(since = \"x\")]` where x is not semver"),
+    ("deref_addrof", complexity, "pre 1.29.0", [], "use of `*&` or `*&mut` in an expression"),
+    ("deref_by_slicing", restriction, "1.61.0", [], "slicing instead of dereferencing"),
+    ("derivable_impls", complexity, "1.57.0", [], "manual implementation of the `Default` trait which is equal to a derive"),
+    ("derive_hash_xor_eq", correctness, "pre 1.29.0", [], "deriving `Hash` but implementing `PartialEq` explicitly"),
+    ("derive_ord_xor_partial_ord", correctness, "1.47.0", [], "deriving `Ord` but implementing `PartialOrd` explicitly"),
+    ("derive_partial_eq_without_eq", style, "1.63.0", [], "deriving `PartialEq` on a type that can implement `Eq`, without implementing `Eq`"),
+    ("disallowed_crates", style, "1.65.0", ["disallowed-crates"], "use of an item from a disallowed crate"),
+    ("disallowed_macros", style, "1.65.0", ["disallowed-macros"], "use of a disallowed macro"),
+    ("disallowed_methods", style, "1.49.0", ["disallowed-methods"], "use of a disallowed method call"),
+    ("disallowed_modules", style, "1.65.0", ["disallowed-modules"], "use of an item from a disallowed module"),
+    ("disallowed_names", style, "pre 1.29.0", ["disallowed-names"], "usage of a disallowed/placeholder name"),
+    ("disallowed_script_idents", restriction, "1.55.0", ["allowed-scripts"], "usage of non-allowed Unicode scripts"),
+    ("disallowed_trait_impls", style, "1.65.0", ["disallowed-trait-impls"], "implementation of a disallowed trait"),
+    ("disallowed_types", style, "1.55.0", ["disallowed-types"], "use of disallowed types"),
+    ("diverging_sub_expression", complexity, "pre 1.29.0", [], "whether an expression contains a diverging sub expression"),
+    ("doc_link_with_quotes", pedantic, "1.63.0", [], "possible typo for an intra-doc link"),
+    ("doc_markdown", pedantic, "pre 1.29.0", ["doc-valid-idents"], "presence of `_`, `::` or camel-case outside backticks in documentation"),
+    ("double_comparisons", complexity, "pre 1.29.0", [], "unnecessary double comparisons that can be simplified"),
+    ("double_must_use", style, "1.40.0", [], "`#[must_use]` attribute on a `#[must_use]`-returning function / method"),
+    ("double_neg", style, "pre 1.29.0", [], "`--x`, which is a double negation of `x` and not a pre-decrement as in C/C++"),
+    ("double_parens", complexity, "pre 1.29.0", [], "Warn on unnecessary double parentheses"),
+    ("drop_copy", correctness, "pre 1.29.0", [], "calls to `std::mem::drop` with a value that implements Copy"),
+    ("drop_non_drop", suspicious, "1.62.0", [], "call to `std::mem::drop` with a value which does not implement `Drop`"),
+    ("drop_ref", correctness, "pre 1.29.0", [], "calls to `std::mem::drop` with a reference instead of an owned value"),
+    ("duplicate_mod", suspicious, "1.63.0", [], "file loaded as module multiple times"),
+    ("duplicate_underscore_argument", style, "pre 1.29.0", [], "function arguments having names which only differ by an underscore"),
+    ("duration_subsec", complexity, "pre 1.29.0", [], "checks for calculation of subsecond microseconds or milliseconds"),
+    ("else_if_without_else", restriction, "pre 1.29.0", [], "`if` expression with an `else if`, but without a final `else` branch"),
+    ("empty_drop", restriction, "1.62.0", [], "empty `Drop` implementations"),
+    ("empty_enum", pedantic, "pre 1.29.0", [], "enum with no variants"),
+    ("empty_line_after_outer_attr", nursery, "pre 1.29.0", [], "empty line after outer attribute"),
+    ("empty_loop", suspicious, "pre 1.29.0", [], "empty `loop {}`, which should block or sleep"),
+    ("empty_structs_with_brackets", restriction, "1.62.0", [], "finds struct declarations with empty brackets"),
+    ("enum_clike_unportable_variant", correctness, "pre 1.29.0", [], "C-like enums that are `repr(isize/usize)` and have values that don't fit into an `i32`"),
+    ("enum_glob_use", pedantic, "pre 1.29.0", [], "use items that import all variants of an enum"),
+    ("enum_variant_names", style, "pre 1.29.0", ["avoid-breaking-exported-api", "enum-variant-name-threshold"], "enums where all variants share a prefix/postfix"),
+    ("eq_op", correctness, "pre 1.29.0", [], "equal operands on both sides of a comparison or bitwise combination (e.g., `x == x`)"),
+    ("equatable_if_let", nursery, "1.57.0", [], "using pattern matching instead of equality"),
+    ("erasing_op", correctness, "pre 1.29.0", [], "using erasing operations, e.g., `x * 0` or `y & 0`"),
+    ("err_expect", style, "1.62.0", ["msrv"], "using `.err().expect(\"\")` when `.expect_err(\"\")` can be used"),
+    ("excessive_precision", style, "pre 1.29.0", [], "excessive precision for float literal"),
+    ("exhaustive_enums", restriction, "1.51.0", [], "detects exported enums that have not been marked #[non_exhaustive]"),
+    ("exhaustive_structs", restriction, "1.51.0", [], "detects exported structs that have not been marked #[non_exhaustive]"),
+    ("exit", restriction, "1.41.0", [], "`std::process::exit` is called, terminating the program"),
+    ("expect_fun_call", perf, "pre 1.29.0", [], "using any `expect` method with a function call"),
+    ("expect_used", restriction, "1.45.0", ["allow-expect-in-tests"], "using `.expect()` on `Result` or `Option`, which might be better handled"),
+    ("expl_impl_clone_on_copy", pedantic, "pre 1.29.0", [], "implementing `Clone` explicitly on `Copy` types"),
+    ("explicit_auto_deref", complexity, "1.64.0", [], "dereferencing when the compiler would automatically dereference"),
+    ("explicit_counter_loop", complexity, "pre 1.29.0", [], "for-looping with an explicit counter when `_.enumerate()` would do"),
+    ("explicit_deref_methods", pedantic, "1.44.0", [], "Explicit use of deref or deref_mut method while not in a method chain."),
+    ("explicit_into_iter_loop", pedantic, "pre 1.29.0", [], "for-looping over `_.into_iter()` when `_` would do"),
+    ("explicit_iter_loop", pedantic, "pre 1.29.0", [], "for-looping over `_.iter()` or `_.iter_mut()` when `&_` or `&mut _` would do"),
+    ("explicit_write", complexity, "pre 1.29.0", [], "using the `write!()` family of functions instead of the `print!()` family of functions, when using the latter would work"),
+    ("extend_with_drain", perf, "1.55.0", [], "using vec.append(&mut vec) to move the full range of a vector to another"),
+    ("extra_unused_lifetimes", complexity, "pre 1.29.0", [], "unused lifetimes in function definitions"),
+    ("fallible_impl_from", nursery, "pre 1.29.0", [], "Warn on impls of `From<..>` that contain `panic!()` or `unwrap()`"),
+    ("field_reassign_with_default", style, "1.49.0", [], "binding initialized with Default should have its fields set in the initializer"),
+    ("filetype_is_file", restriction, "1.42.0", [], "`FileType::is_file` is not recommended to test for readable file type"),
+    ("filter_map_identity", complexity, "1.52.0", [], "call to `filter_map` where `flatten` is sufficient"),
+    ("filter_map_next", pedantic, "1.36.0", ["msrv"], "using combination of `filter_map` and `next` which can usually be written as a single method call"),
+    ("filter_next", complexity, "pre 1.29.0", [], "using `filter(p).next()`, which is more succinctly expressed as `.find(p)`"),
+    ("flat_map_identity", complexity, "1.39.0", [], "call to `flat_map` where `flatten` is sufficient"),
+    ("flat_map_option", pedantic, "1.53.0", [], "used `flat_map` where `filter_map` could be used instead"),
+    ("float_arithmetic", restriction, "pre 1.29.0", [], "any floating-point arithmetic statement"),
+    ("float_cmp", pedantic, "pre 1.29.0", [], "using `==` or `!=` on float values instead of comparing difference with an epsilon"),
+    ("float_cmp_const", restriction, "pre 1.29.0", [], "using `==` or `!=` on float constants instead of comparing difference with an epsilon"),
+    ("float_equality_without_abs", suspicious, "1.48.0", [], "float equality check without `.abs()`"),
+    ("fn_address_comparisons", correctness, "1.44.0", [], "comparison with an address of a function item"),
+    ("fn_params_excessive_bools", pedantic, "1.43.0", ["max-fn-params-bools"], "using too many bools in function parameters"),
+    ("fn_to_numeric_cast", style, "pre 1.29.0", [], "casting a function pointer to a numeric type other than usize"),
+    ("fn_to_numeric_cast_any", restriction, "1.58.0", [], "casting a function pointer to any integer type"),
+    ("fn_to_numeric_cast_with_truncation", style, "pre 1.29.0", [], "casting a function pointer to a numeric type not wide enough to store the address"),
+    ("for_kv_map", style, "pre 1.29.0", [], "looping on a map using `iter` when `keys` or `values` would do"),
+    ("for_loops_over_fallibles", suspicious, "1.45.0", [], "for-looping over an `Option` or a `Result`, which is more clearly expressed as an `if let`"),
+    ("forget_copy", correctness, "pre 1.29.0", [], "calls to `std::mem::forget` with a value that implements Copy"),
+    ("forget_non_drop", suspicious, "1.62.0", [], "call to `std::mem::forget` with a value which does not implement `Drop`"),
+    ("forget_ref", correctness, "pre 1.29.0", [], "calls to `std::mem::forget` with a reference instead of an owned value"),
+    ("format_in_format_args", perf, "1.58.0", [], "`format!` used in a macro that does formatting"),
+    ("format_push_string", restriction, "1.62.0", [], "`format!(..)` appended to existing `String`"),
+    ("from_iter_instead_of_collect", pedantic, "1.49.0", [], "use `.collect()` instead of `::from_iter()`"),
+    ("from_over_into", style, "1.51.0", ["msrv"], "Warns on implementations of `Into<..>` to use `From<..>`"),
+    ("from_str_radix_10", style, "1.52.0", [], "from_str_radix with radix 10"),
+    ("future_not_send", nursery, "1.44.0", [], "public Futures must be Send"),
+    ("get_first", style, "1.63.0", [], "Using `x.get(0)` when `x.first()` is simpler"),
+    ("get_last_with_len", complexity, "1.37.0", [], "Using `x.get(x.len() - 1)` when `x.last()` is correct and simpler"),
+    ("get_unwrap", restriction, "pre 1.29.0", [], "using `.get().unwrap()` or `.get_mut().unwrap()` when using `[]` would work instead"),
+    ("identity_op", complexity, "pre 1.29.0", [], "using identity operations, e.g., `x + 0` or `y / 1`"),
+    ("if_let_mutex", correctness, "1.45.0", [], "locking a `Mutex` in an `if let` block can cause deadlocks"),
+    ("if_not_else", pedantic, "pre 1.29.0", [], "`if` branches that could be swapped so no negation operation is necessary on the condition"),
+    ("if_same_then_else", correctness, "pre 1.29.0", [], "`if` with the same `then` and `else` blocks"),
+    ("if_then_some_else_none", restriction, "1.53.0", ["msrv"], "Finds if-else that could be written using either `bool::then` or `bool::then_some`"),
+    ("ifs_same_cond", correctness, "pre 1.29.0", [], "consecutive `if`s with the same condition"),
+    ("implicit_clone", pedantic, "1.52.0", [], "implicitly cloning a value by invoking a function on its dereferenced type"),
+    ("implicit_hasher", pedantic, "pre 1.29.0", [], "missing generalization over different hashers"),
+    ("implicit_return", restriction, "1.33.0", [], "use a return statement like `return expr` instead of an expression"),
+    ("implicit_saturating_sub", pedantic, "1.44.0", [], "Perform saturating subtraction instead of implicitly checking lower bound of data type"),
+    ("imprecise_flops", nursery, "1.43.0", [], "usage of imprecise floating point operations"),
+    ("incompatible_msrv", pedantic, "1.65.0", ["msrv"], "using standard library items that are newer than the minimum supported Rust version"),
+    ("inconsistent_digit_grouping", style, "pre 1.29.0", [], "integer literals with digits grouped inconsistently"),
+    ("inconsistent_struct_constructor", pedantic, "1.52.0", [], "the order of the field init shorthand is inconsistent with the order in the struct definition"),
+    ("index_refutable_slice", nursery, "1.59.0", ["msrv", "max-suggested-slice-pattern-length"], "avoid indexing on slices which could be destructed"),
+    ("indexing_slicing", restriction, "pre 1.29.0", [], "indexing/slicing usage"),
+    ("ineffective_bit_mask", correctness, "pre 1.29.0", [], "expressions where a bit mask will be rendered useless by a comparison, e.g., `(x | 1) > 2`"),
+    ("inefficient_to_string", pedantic, "1.40.0", [], "using `to_string` on `&&T` where `T: ToString`"),
+    ("infallible_destructuring_match", style, "pre 1.29.0", [], "a `match` statement with a single infallible arm instead of a `let`"),
+    ("infinite_iter", correctness, "pre 1.29.0", [], "infinite iteration"),
+    ("inherent_to_string", style, "1.38.0", [], "type implements inherent method `to_string()`, but should instead implement the `Display` trait"),
+    ("inherent_to_string_shadow_display", correctness, "1.38.0", [], "type implements inherent method `to_string()`, which gets shadowed by the implementation of the `Display` trait"),
+    ("init_numbered_fields", style, "1.59.0", [], "numbered fields in tuple struct initializer"),
+    ("inline_always", pedantic, "pre 1.29.0", [], "use of `#[inline(always)]`"),
+    ("inline_asm_x86_att_syntax", restriction, "1.49.0", [], "prefer Intel x86 assembly syntax"),
+    ("inline_asm_x86_intel_syntax", restriction, "1.49.0", [], "prefer AT&T x86 assembly syntax"),
+    ("inline_fn_without_body", correctness, "pre 1.29.0", [], "use of `#[inline]` on trait methods without bodies"),
+    ("inspect_for_each", complexity, "1.51.0", [], "using `.inspect().for_each()`, which can be replaced with `.for_each()`"),
+    ("int_plus_one", complexity, "pre 1.29.0", [], "instead of using `x >= y + 1`, use `x > y`"),
+    ("integer_arithmetic", restriction, "pre 1.29.0", [], "any integer arithmetic expression which could overflow or panic"),
+    ("integer_division", restriction, "1.37.0", [], "integer division may cause loss of precision"),
+    ("into_iter_on_ref", style, "1.32.0", [], "using `.into_iter()` on a reference"),
+    ("invalid_null_ptr_usage", correctness, "1.53.0", [], "invalid usage of a null pointer, suggesting `NonNull::dangling()` instead"),
+    ("invalid_regex", correctness, "pre 1.29.0", [], "invalid regular expressions"),
+    ("invalid_upcast_comparisons", pedantic, "pre 1.29.0", [], "a comparison involving an upcast which is always true or false"),
+    ("invalid_utf8_in_unchecked", correctness, "1.64.0", [], "using a non UTF-8 literal in `std::std::from_utf8_unchecked`"),
+    ("invisible_characters", correctness, "1.49.0", [], "using an invisible character in a string literal, which is confusing"),
+    ("is_digit_ascii_radix", style, "1.62.0", [], "use of `char::is_digit(..)` with literal radix of 10 or 16"),
+    ("items_after_statements", pedantic, "pre 1.29.0", [], "blocks where an item comes after a statement"),
+    ("iter_cloned_collect", style, "pre 1.29.0", [], "using `.cloned().collect()` on slice to create a `Vec`"),
+    ("iter_count", complexity, "1.52.0", [], "replace `.iter().count()` with `.len()`"),
+    ("iter_kv_map", complexity, "1.65.0", [], "iterating on map using `iter` when `keys` or `values` would do"),
+    ("iter_next_loop", correctness, "pre 1.29.0", [], "for-looping over `_.next()` which is probably not intended"),
+    ("iter_next_slice", style, "1.46.0", [], "using `.iter().next()` on a sliced array, which can be shortened to just `.get()`"),
+    ("iter_not_returning_iterator", pedantic, "1.57.0", [], "methods named `iter` or `iter_mut` that do not return an `Iterator`"),
+    ("iter_nth", perf, "pre 1.29.0", [], "using `.iter().nth()` on a standard library type with O(1) element access"),
+    ("iter_nth_zero", style, "1.42.0", [], "replace `iter.nth(0)` with `iter.next()`"),
+    ("iter_on_empty_collections", nursery, "1.64.0", [], "Iterator for empty array"),
+    ("iter_on_single_items", nursery, "1.64.0", [], "Iterator for array of length 1"),
+    ("iter_overeager_cloned", perf, "1.60.0", [], "using `cloned()` early with `Iterator::iter()` can lead to some performance inefficiencies"),
+    ("iter_skip_next", style, "pre 1.29.0", [], "using `.skip(x).next()` on an iterator"),
+    ("iter_with_drain", nursery, "1.61.0", [], "replace `.drain(..)` with `.into_iter()`"),
+    ("iterator_step_by_zero", correctness, "pre 1.29.0", [], "using `Iterator::step_by(0)`, which will panic at runtime"),
+    ("just_underscores_and_digits", style, "pre 1.29.0", [], "unclear name"),
+    ("large_const_arrays", perf, "1.44.0", ["array-size-threshold"], "large non-scalar const array may cause performance overhead"),
+    ("large_digit_groups", pedantic, "pre 1.29.0", [], "grouping digits into groups that are too large"),
+    ("large_enum_variant", perf, "pre 1.29.0", ["enum-variant-size-threshold"], "large size difference between variants on an enum"),
+    ("large_include_file", restriction, "1.62.0", ["max-include-file-size"], "including a large file"),
+    ("large_stack_arrays", pedantic, "1.41.0", ["array-size-threshold"], "allocating large arrays on stack may cause stack overflow"),
+    ("large_types_passed_by_value", pedantic, "1.49.0", ["avoid-breaking-exported-api", "pass-by-value-size-limit"], "functions taking large arguments by value"),
+    ("layer_violation", style, "1.65.0", ["layers"], "use of an item from a layer above the one using it"),
+    ("len_without_is_empty", style, "pre 1.29.0", [], "traits or impls with a public `len` method but no corresponding `is_empty` method"),
+    ("len_zero", style, "pre 1.29.0", [], "checking `.len() == 0` or `.len() > 0` (or similar) when `.is_empty()` could be used instead"),
+    ("let_and_return", style, "pre 1.29.0", [], "creating a let-binding and then immediately returning it like `let x = expr; x` at the end of a block"),
+    ("let_underscore_drop", pedantic, "1.50.0", [], "non-binding let on a type that implements `Drop`"),
+    ("let_underscore_lock", correctness, "1.43.0", [], "non-binding let on a synchronization lock"),
+    ("let_underscore_must_use", restriction, "1.42.0", [], "non-binding let on a `#[must_use]` expression"),
+    ("let_unit_value", style, "pre 1.29.0", [], "creating a `let` binding to a value of unit type, which usually can't be used afterwards"),
+    ("linkedlist", pedantic, "pre 1.29.0", ["avoid-breaking-exported-api"], "usage of LinkedList, usually a vector is faster, or a more specialized data structure like a `VecDeque`"),
+    ("lossy_float_literal", restriction, "1.43.0", [], "lossy whole number float literals"),
+    ("macro_use_imports", pedantic, "1.44.0", [], "#[macro_use] is no longer needed"),
+    ("main_recursion", style, "1.38.0", [], "recursion using the entrypoint"),
+    ("manual_assert", pedantic, "1.57.0", [], "`panic!` and only a `panic!` in `if`-then statement"),
+    ("manual_async_fn", style, "1.45.0", [], "manual implementations of `async` functions can be simplified using the dedicated syntax"),
+    ("manual_bits", style, "1.60.0", ["msrv"], "manual implementation of `size_of::<T>() * 8` can be simplified with `T::BITS`"),
+    ("manual_filter_map", complexity, "1.51.0", [], "using `_.filter(_).map(_)` in a way that can be written more simply as `filter_map(_)`"),
+    ("manual_find", complexity, "1.64.0", [], "manual implementation of `Iterator::find`"),
+    ("manual_find_map", complexity, "1.51.0", [], "using `_.find(_).map(_)` in a way that can be written more simply as `find_map(_)`"),
+    ("manual_flatten", complexity, "1.52.0", [], "for loops over `Option`s or `Result`s with a single expression can be simplified"),
+    ("manual_instant_elapsed", pedantic, "1.64.0", [], "subtraction between `Instant::now()` and previous `Instant`"),
+    ("manual_map", style, "1.52.0", [], "reimplementation of `map`"),
+    ("manual_memcpy", perf, "pre 1.29.0", [], "manually copying items between slices"),
+    ("manual_non_exhaustive", style, "1.45.0", ["msrv"], "manual implementations of the non-exhaustive pattern can be simplified using #[non_exhaustive]"),
+    ("manual_ok_or", pedantic, "1.49.0", [], "finds patterns that can be encoded more concisely with `Option::ok_or`"),
+    ("manual_range_contains", style, "1.49.0", ["msrv"], "manually reimplementing {`Range`, `RangeInclusive`}`::contains`"),
+    ("manual_rem_euclid", complexity, "1.64.0", [], "manually reimplementing `rem_euclid`"),
+    ("manual_retain", perf, "1.64.0", [], "`retain()` is simpler and the same functionalitys"),
+    ("manual_saturating_arithmetic", style, "1.39.0", [], "`.checked_add/sub(x).unwrap_or(MAX/MIN)`"),
+    ("manual_split_once", complexity, "1.57.0", ["msrv"], "replace `.splitn(2, pat)` with `.split_once(pat)`"),
+    ("manual_str_repeat", perf, "1.54.0", ["msrv"], "manual implementation of `str::repeat`"),
+    ("manual_string_new", pedantic, "1.65.0", [], "empty String is being created manually"),
+    ("manual_strip", complexity, "1.48.0", ["msrv"], "suggests using `strip_{prefix,suffix}` over `str::{starts,ends}_with` and slicing"),
+    ("manual_swap", complexity, "pre 1.29.0", [], "manual swap of two variables"),
+    ("manual_unwrap_or", complexity, "1.49.0", [], "finds patterns that can be encoded more concisely with `Option::unwrap_or` or `Result::unwrap_or`"),
+    ("many_single_char_names", pedantic, "pre 1.29.0", ["single-char-binding-names-threshold"], "too many single character bindings"),
+    ("map_clone", style, "pre 1.29.0", ["msrv"], "using `iterator.map(|x| x.clone())`, or dereferencing closures for `Copy` types"),
+    ("map_collect_result_unit", style, "1.49.0", [], "using `.map(_).collect::<Result<(),_>()`, which can be replaced with `try_for_each`"),
+    ("map_entry", perf, "pre 1.29.0", [], "use of `contains_key` followed by `insert` on a `HashMap` or `BTreeMap`"),
+    ("map_err_ignore", restriction, "1.48.0", [], "`map_err` should not ignore the original error"),
+    ("map_flatten", complexity, "1.31.0", [], "using combinations of `flatten` and `map` which can usually be written as a single method call"),
+    ("map_identity", complexity, "1.47.0", [], "using iterator.map(|x| x)"),
+    ("map_unwrap_or", pedantic, "1.45.0", ["msrv"], "using `.map(f).unwrap_or(a)` or `.map(f).unwrap_or_else(func)`, which are more succinctly expressed as `map_or(a, f)` or `map_or_else(a, f)`"),
+    ("match_as_ref", complexity, "pre 1.29.0", [], "a `match` on an Option value instead of using `as_ref()` or `as_mut`"),
+    ("match_bool", pedantic, "pre 1.29.0", [], "a `match` on a boolean expression instead of an `if..else` block"),
+    ("match_like_matches_macro", style, "1.47.0", ["msrv"], "a match that could be written with the matches! macro"),
+    ("match_on_vec_items", pedantic, "1.45.0", [], "matching on vector elements can panic"),
+    ("match_overlapping_arm", style, "pre 1.29.0", [], "a `match` with overlapping arms"),
+    ("match_ref_pats", style, "pre 1.29.0", [], "a `match` or `if let` with all arms prefixed with `&` instead of deref-ing the match expression"),
+    ("match_result_ok", style, "1.57.0", [], "usage of `ok()` in `let Some(pat)` statements is unnecessary, match on `Ok(pat)` instead"),
+    ("match_same_arms", pedantic, "pre 1.29.0", [], "`match` with identical arm bodies"),
+    ("match_single_binding", complexity, "1.43.0", [], "a match with a single binding instead of using `let` statement"),
+    ("match_str_case_mismatch", correctness, "1.58.0", [], "creation of a case altering match expression with non-compliant arms"),
+    ("match_wild_err_arm", pedantic, "pre 1.29.0", [], "a `match` with `Err(_)` arm and take drastic actions"),
+    ("match_wildcard_for_single_variants", pedantic, "1.45.0", [], "a wildcard enum match for a single variant"),
+    ("maybe_infinite_iter", pedantic, "pre 1.29.0", [], "possible infinite iteration"),
+    ("mem_forget", restriction, "pre 1.29.0", [], "`mem::forget` usage on `Drop` types, likely to cause memory leaks"),
+    ("mem_replace_option_with_none", style, "1.31.0", [], "replacing an `Option` with `None` instead of `take()`"),
+    ("mem_replace_with_default", style, "1.42.0", ["msrv"], "replacing a value of type `T` with `T::default()` instead of using `std::mem::take`"),
+    ("mem_replace_with_uninit", correctness, "1.39.0", [], "`mem::replace(&mut _, mem::uninitialized())` or `mem::replace(&mut _, mem::zeroed())`"),
+    ("min_max", correctness, "pre 1.29.0", [], "`min(_, max(_, _))` (or vice versa) with bounds clamping the result to a constant"),
+    ("mismatched_target_os", correctness, "1.45.0", [], "usage of `cfg(operating_system)` instead of `cfg(target_os = \"operating_system\")`"),
+    ("mismatching_type_param_order", pedantic, "1.63.0", [], "type parameter positioned inconsistently between type def and impl block"),
+    ("misrefactored_assign_op", suspicious, "pre 1.29.0", [], "having a variable on both sides of an assign op"),
+    ("missing_const_for_fn", nursery, "1.34.0", ["msrv"], "Lint functions definitions that could be made `const fn`"),
+    ("missing_docs_in_private_items", restriction, "pre 1.29.0", [], "detects missing documentation for public and private members"),
+    ("missing_enforced_import_renames", restriction, "1.55.0", ["enforced-import-renames"], "enforce import renames"),
+    ("missing_errors_doc", pedantic, "1.41.0", [], "`pub fn` returns `Result` without `# Errors` in doc comment"),
+    ("missing_inline_in_public_items", restriction, "pre 1.29.0", [], "detects missing `#[inline]` attribute for public callables (functions, trait methods, methods...)"),
+    ("missing_panics_doc", pedantic, "1.51.0", [], "`pub fn` may panic without `# Panics` in doc comment"),
+    ("missing_safety_doc", style, "1.39.0", [], "`pub unsafe fn` without `# Safety` docs"),
+    ("missing_spin_loop", perf, "1.61.0", [], "An empty busy waiting loop"),
+    ("mistyped_literal_suffixes", correctness, "1.30.0", [], "mistyped literal suffix"),
+    ("mixed_case_hex_literals", style, "pre 1.29.0", [], "hex literals whose letter digits are not consistently upper- or lowercased"),
+    ("mixed_read_write_in_expression", restriction, "pre 1.29.0", [], "whether a variable read occurs before a write depends on sub-expression evaluation order"),
+    ("mod_module_files", restriction, "1.57.0", [], "checks that module layout is consistent"),
+    ("module_inception", style, "pre 1.29.0", [], "modules that have the same name as their parent module"),
+    ("module_name_repetitions", pedantic, "1.33.0", [], "type names prefixed/postfixed with their containing module's name"),
+    ("modulo_arithmetic", restriction, "1.42.0", [], "any modulo arithmetic statement"),
+    ("modulo_one", correctness, "pre 1.29.0", [], "taking a number modulo +/-1, which can either panic/overflow or always returns 0"),
+    ("multi_assignments", suspicious, "1.65.0", [], "instead of using `a = b = c;` use `a = c; b = c;`"),
+    ("multiple_crate_versions", cargo, "pre 1.29.0", [], "multiple versions of the same crate being used"),
+    ("multiple_inherent_impl", restriction, "pre 1.29.0", [], "Multiple inherent impl that could be grouped"),
+    ("must_use_candidate", pedantic, "1.40.0", [], "function or method that could take a `#[must_use]` attribute"),
+    ("must_use_unit", style, "1.40.0", [], "`#[must_use]` attribute on a unit-returning function / method"),
+    ("mut_from_ref", correctness, "pre 1.29.0", [], "fns that create mutable refs from immutable ref args"),
+    ("mut_mut", pedantic, "pre 1.29.0", [], "usage of double-mut refs, e.g., `&mut &mut ...`"),
+    ("mut_mutex_lock", style, "1.49.0", [], "`&mut Mutex::lock` does unnecessary locking"),
+    ("mut_range_bound", suspicious, "pre 1.29.0", [], "for loop over a range where one of the bounds is a mutable variable"),
+    ("mutable_key_type", suspicious, "1.42.0", [], "Check for mutable `Map`/`Set` key type"),
+    ("mutex_atomic", nursery, "pre 1.29.0", [], "using a mutex where an atomic value could be used instead"),
+    ("mutex_integer", nursery, "pre 1.29.0", [], "using a mutex for an integer type"),
+    ("naive_bytecount", pedantic, "pre 1.29.0", [], "use of naive `<slice>.filter(|&x| x == y).count()` to count byte values"),
+    ("needless_arbitrary_self_type", complexity, "1.47.0", [], "type of `self` parameter is already by default `Self`"),
+    ("needless_bitwise_bool", pedantic, "1.54.0", [], "Boolean expressions that use bitwise rather than lazy operators"),
+    ("needless_bool", complexity, "pre 1.29.0", [], "if-statements with plain booleans in the then- and else-clause, e.g., `if p { true } else { false }`"),
+    ("needless_borrow", style, "pre 1.29.0", [], "taking a reference that is going to be automatically dereferenced"),
+    ("needless_borrowed_reference", complexity, "pre 1.29.0", [], "destructuring a reference and borrowing the inner value"),
+    ("needless_collect", perf, "1.30.0", [], "collecting an iterator when collect is not needed"),
+    ("needless_continue", pedantic, "pre 1.29.0", [], "`continue` statements that can be replaced by a rearrangement of code"),
+    ("needless_doctest_main", style, "1.40.0", [], "presence of `fn main() {` in code examples"),
+    ("needless_for_each", pedantic, "1.53.0", [], "using `for_each` where a `for` loop would be simpler"),
+    ("needless_late_init", style, "1.59.0", [], "late initializations that can be replaced by a `let` statement with an initializer"),
+    ("needless_lifetimes", complexity, "pre 1.29.0", [], "using explicit lifetimes for references in function arguments when elision rules would allow omitting them"),
+    ("needless_match", complexity, "1.61.0", [], "`match` or match-like `if let` that are unnecessary"),
+    ("needless_option_as_deref", complexity, "1.57.0", [], "no-op use of `deref` or `deref_mut` method to `Option`."),
+    ("needless_option_take", complexity, "1.62.0", [], "using `.as_ref().take()` on a temporary value"),
+    ("needless_parens_on_range_literals", style, "1.63.0", [], "needless parenthesis on range literals can be removed"),
+    ("needless_pass_by_value", pedantic, "pre 1.29.0", [], "functions taking arguments by value, but not consuming them in its body"),
+    ("needless_question_mark", complexity, "1.51.0", [], "Suggest `value.inner_option` instead of `Some(value.inner_option?)`. The same goes for `Result<T, E>`."),
+    ("needless_range_loop", style, "pre 1.29.0", [], "for-looping over a range of indices where an iterator over items would do"),
+    ("needless_return", style, "pre 1.29.0", [], "using a return statement like `return expr;` where an expression would suffice"),
+    ("needless_splitn", complexity, "1.59.0", [], "usages of `str::splitn` that can be replaced with `str::split`"),
+    ("needless_update", complexity, "pre 1.29.0", [], "using `Foo { ..base }` when there are no missing fields"),
+    ("neg_cmp_op_on_partial_ord", complexity, "pre 1.29.0", [], "The use of negated comparison operators on partially ordered types may produce confusing code."),
+    ("neg_multiply", style, "pre 1.29.0", [], "multiplying integers by `-1`"),
+    ("negative_feature_names", cargo, "1.57.0", [], "usage of a negative feature name"),
+    ("never_loop", correctness, "pre 1.29.0", [], "any loop that will always `break` or `return`"),
+    ("new_ret_no_self", style, "pre 1.29.0", [], "not returning type containing `Self` in a `new` method"),
+    ("new_without_default", style, "pre 1.29.0", [], "`pub fn new() -> Self` method without `Default` implementation"),
+    ("no_effect", complexity, "pre 1.29.0", [], "statements with no effect"),
+    ("no_effect_replace", suspicious, "1.63.0", [], "replace with no effect"),
+    ("no_effect_underscore_binding", pedantic, "1.58.0", [], "binding to `_` prefixed variable with no side-effect"),
+    ("non_ascii_literal", restriction, "pre 1.29.0", [], "using any literal non-ASCII chars in a string literal instead of using the `\\u` escape"),
+    ("non_octal_unix_permissions", correctness, "1.53.0", [], "use of non-octal value to set unix file permissions, which will be translated into octal"),
+    ("non_send_fields_in_send_ty", nursery, "1.57.0", ["enable-raw-pointer-heuristic-for-send"], "there is a field that is not safe to be sent to another thread in a `Send` struct"),
+    ("nonminimal_bool", complexity, "pre 1.29.0", [], "boolean expressions that can be written more concisely"),
+    ("nonsensical_open_options", correctness, "pre 1.29.0", [], "nonsensical combination of options for opening a file"),
+    ("nonstandard_macro_braces", nursery, "1.55.0", ["standard-macro-braces"], "check consistent use of braces in macro"),
+    ("not_unsafe_ptr_arg_deref", correctness, "pre 1.29.0", [], "public functions dereferencing raw pointer arguments but not marked `unsafe`"),
+    ("obfuscated_if_else", style, "1.64.0", [], "use of `.then_some(..).unwrap_or(..)` can be written more clearly with `if .. else ..`"),
+    ("octal_escapes", suspicious, "1.59.0", [], "string escape sequences looking like octal characters"),
+    ("ok_expect", style, "pre 1.29.0", [], "using `ok().expect()`, which gives worse error messages than calling `expect` directly on the Result"),
+    ("only_used_in_recursion", complexity, "1.61.0", [], "arguments that is only used in recursion can be removed"),
+    ("op_ref", style, "pre 1.29.0", [], "taking a reference to satisfy the type constraints on `==`"),
+    ("option_as_ref_deref", complexity, "1.42.0", ["msrv"], "using `as_ref().map(Deref::deref)`, which is more succinctly expressed as `as_deref()`"),
+    ("option_env_unwrap", correctness, "1.43.0", [], "using `option_env!(...).unwrap()` to get environment variable"),
+    ("option_filter_map", complexity, "1.53.0", [], "filtering `Option` for `Some` then force-unwrapping, which can be one type-safe operation"),
+    ("option_if_let_else", nursery, "1.47.0", [], "reimplementation of Option::map_or"),
+    ("option_map_or_none", style, "pre 1.29.0", [], "using `Option.map_or(None, f)`, which is more succinctly expressed as `and_then(f)`"),
+    ("option_map_unit_fn", complexity, "pre 1.29.0", [], "using `option.map(f)`, where `f` is a function or closure that returns `()`"),
+    ("option_option", pedantic, "pre 1.29.0", ["avoid-breaking-exported-api"], "usage of `Option<Option<T>>`"),
+    ("or_fun_call", perf, "pre 1.29.0", [], "using any `*or` method with a function call, which suggests `*or_else`"),
+    ("or_then_unwrap", complexity, "1.61.0", [], "checks for `.or(…).unwrap()` calls to Options and Results."),
+    ("out_of_bounds_indexing", correctness, "pre 1.29.0", [], "out of bounds constant indexing"),
+    ("overflow_check_conditional", complexity, "pre 1.29.0", [], "overflow checks inspired by C which are likely to panic"),
+    ("overly_complex_bool_expr", correctness, "pre 1.29.0", [], "boolean expressions that contain terminals which can be eliminated"),
+    ("panic", restriction, "1.40.0", [], "usage of the `panic!` macro"),
+    ("panic_in_result_fn", restriction, "1.48.0", [], "functions of type `Result<..>` that contain `panic!()`, `todo!()`, `unreachable()`, `unimplemented()` or assertion"),
+    ("panicking_unwrap", correctness, "pre 1.29.0", [], "checks for calls of `unwrap[_err]()` that will always fail"),
+    ("partialeq_ne_impl", complexity, "pre 1.29.0", [], "re-implementing `PartialEq::ne`"),
+    ("partialeq_to_none", style, "1.64.0", [], "Binary comparison to `Option<T>::None` relies on `T: PartialEq`, which is unneeded"),
+    ("path_buf_push_overwrite", nursery, "1.36.0", [], "calling `push` with file system root on `PathBuf` can overwrite it"),
+    ("pattern_type_mismatch", restriction, "1.47.0", [], "type of pattern does not match the expression type"),
+    ("possible_missing_comma", correctness, "pre 1.29.0", [], "possible missing comma in array"),
+    ("precedence", complexity, "pre 1.29.0", [], "operations where precedence may be unclear"),
+    ("print_in_format_impl", suspicious, "1.61.0", [], "use of a print macro in a formatting trait impl"),
+    ("print_literal", style, "pre 1.29.0", [], "printing a literal with a format string"),
+    ("print_stderr", restriction, "1.50.0", [], "printing on stderr"),
+    ("print_stdout", restriction, "pre 1.29.0", [], "printing on stdout"),
+    ("print_with_newline", style, "pre 1.29.0", [], "using `print!()` with a format string that ends in a single newline"),
+    ("println_empty_string", style, "pre 1.29.0", [], "using `println!(\"\")` with an empty string"),
+    ("ptr_arg", style, "pre 1.29.0", [], "fn arguments of the type `&Vec<...>` or `&String`, suggesting to use `&[...]` or `&str` instead, respectively"),
+    ("ptr_as_ptr", pedantic, "1.51.0", ["msrv"], "casting using `as` from and to raw pointers that doesn't change its mutability, where `pointer::cast` could take the place of `as`"),
+    ("ptr_eq", style, "1.49.0", [], "use `std::ptr::eq` when comparing raw pointers"),
+    ("ptr_offset_with_cast", complexity, "1.30.0", [], "unneeded pointer offset cast"),
+    ("pub_use", restriction, "1.62.0", [], "restricts the usage of `pub use`"),
+    ("question_mark", style, "pre 1.29.0", [], "checks for expressions that could be replaced by the question mark operator"),
+    ("range_minus_one", pedantic, "pre 1.29.0", [], "`x..=(y-1)` reads better as `x..y`"),
+    ("range_plus_one", pedantic, "pre 1.29.0", [], "`x..(y+1)` reads better as `x..=y`"),
+    ("range_zip_with_len", complexity, "pre 1.29.0", [], "zipping iterator with a range when `enumerate()` would do"),
+    ("rc_buffer", restriction, "1.48.0", ["avoid-breaking-exported-api"], "shared ownership of a buffer type"),
+    ("rc_clone_in_vec_init", suspicious, "1.63.0", [], "initializing reference-counted pointer in `vec![elem; len]`"),
+    ("rc_mutex", restriction, "1.55.0", ["avoid-breaking-exported-api"], "usage of `Rc<Mutex<T>>`"),
+    ("read_zero_byte_vec", correctness, "1.63.0", [], "checks for reads into a zero-length `Vec`"),
+    ("recursive_format_impl", correctness, "1.48.0", [], "Format trait method called while implementing the same Format trait"),
+    ("redundant_allocation", perf, "1.44.0", ["avoid-breaking-exported-api"], "redundant allocation"),
+    ("redundant_clone", perf, "1.32.0", [], "`clone()` of an owned value that is going to be dropped immediately"),
+    ("redundant_closure", style, "pre 1.29.0", [], "redundant closures, i.e., `|a| foo(a)` (which can be written as just `foo`)"),
+    ("redundant_closure_call", complexity, "pre 1.29.0", [], "throwaway closures called in the expression they are defined"),
+    ("redundant_closure_for_method_calls", pedantic, "1.35.0", [], "redundant closures for method calls"),
+    ("redundant_else", pedantic, "1.50.0", [], "`else` branch that can be removed without changing semantics"),
+    ("redundant_feature_names", cargo, "1.57.0", [], "usage of a redundant feature name"),
+    ("redundant_field_names", style, "pre 1.29.0", ["msrv"], "checks for fields in struct literals where shorthands could be used"),
+    ("redundant_pattern", style, "pre 1.29.0", [], "using `name @ _` in a pattern"),
+    ("redundant_pattern_matching", style, "1.31.0", [], "use the proper utility function avoiding an `if let`"),
+    ("redundant_pub_crate", nursery, "1.44.0", [], "Using `pub(crate)` visibility on items that are not crate visible due to the visibility of the module that contains them."),
+    ("redundant_slicing", complexity, "1.51.0", [], "redundant slicing of the whole range of a type"),
+    ("redundant_static_lifetimes", style, "1.37.0", ["msrv"], "Using explicit `'static` lifetime for constants or statics when elision rules would allow omitting them."),
+    ("ref_binding_to_reference", pedantic, "1.54.0", [], "`ref` binding to a reference"),
+    ("ref_option_ref", pedantic, "1.49.0", [], "use `Option<&T>` instead of `&Option<&T>`"),
+    ("repeat_once", complexity, "1.47.0", [], "using `.repeat(1)` instead of `String.clone()`, `str.to_string()` or `slice.to_vec()` "),
+    ("rest_pat_in_fully_bound_structs", restriction, "1.43.0", [], "a match on a struct that binds all fields but still uses the wildcard pattern"),
+    ("result_large_err", perf, "1.64.0", ["large-error-threshold"], "function returning `Result` with large `Err` type"),
+    ("result_map_or_into_option", style, "1.44.0", [], "using `Result.map_or(None, Some)`, which is more succinctly expressed as `ok()`"),
+    ("result_map_unit_fn", complexity, "pre 1.29.0", [], "using `result.map(f)`, where `f` is a function or closure that returns `()`"),
+    ("result_unit_err", style, "1.49.0", [], "public function returning `Result` with an `Err` type of `()`"),
+    ("return_self_not_must_use", pedantic, "1.59.0", [], "missing `#[must_use]` annotation on a method returning `Self`"),
+    ("reversed_empty_ranges", correctness, "1.45.0", [], "reversing the limits of range expressions, resulting in empty ranges"),
+    ("same_functions_in_if_condition", pedantic, "1.41.0", [], "consecutive `if`s with the same function call"),
+    ("same_item_push", style, "1.47.0", [], "the same item is pushed inside of a for loop"),
+    ("same_name_method", restriction, "1.57.0", [], "two method with same name"),
+    ("search_is_some", complexity, "pre 1.29.0", [], "using an iterator or string search followed by `is_some()` or `is_none()`, which is more succinctly expressed as a call to `any()` or `contains()` (with negation in case of `is_none()`)"),
+    ("self_assignment", correctness, "1.48.0", [], "explicit self-assignment"),
+    ("self_named_constructors", style, "1.55.0", [], "method should not have the same name as the type it is implemented for"),
+    ("self_named_module_files", restriction, "1.57.0", [], "checks that module layout is consistent"),
+    ("semicolon_if_nothing_returned", pedantic, "1.52.0", [], "add a semicolon if nothing is returned"),
+    ("separated_literal_suffix", restriction, "1.58.0", [], "literals whose suffix is separated by an underscore"),
+    ("serde_api_misuse", correctness, "pre 1.29.0", [], "various things that will negatively affect your serde experience"),
+    ("shadow_reuse", restriction, "pre 1.29.0", [], "rebinding a name to an expression that re-uses the original value, e.g., `let x = x + 1`"),
+    ("shadow_same", restriction, "pre 1.29.0", [], "rebinding a name to itself, e.g., `let mut x = &mut x`"),
+    ("shadow_unrelated", restriction, "pre 1.29.0", [], "rebinding a name without even using the original value"),
+    ("short_circuit_statement", complexity, "pre 1.29.0", [], "using a short circuit boolean condition as a statement"),
+    ("should_implement_trait", style, "pre 1.29.0", [], "defining a method that should be implementing a std trait"),
+    ("significant_drop_in_scrutinee", nursery, "1.60.0", [], "warns when a temporary of a type with a drop with a significant side-effect might have a surprising lifetime"),
+    ("similar_names", pedantic, "pre 1.29.0", [], "similarly named items and bindings"),
+    ("single_char_add_str", style, "1.49.0", [], "`push_str()` or `insert_str()` used with a single-character string literal as parameter"),
+    ("single_char_lifetime_names", restriction, "1.60.0", [], "warns against single-character lifetime names"),
+    ("single_char_pattern", perf, "pre 1.29.0", [], "using a single-character str where a char could be used, e.g., `_.split(\"x\")`"),
+    ("single_component_path_imports", style, "1.43.0", [], "imports with single component path are redundant"),
+    ("single_element_loop", complexity, "1.49.0", [], "there is no reason to have a single element loop"),
+    ("single_match", style, "pre 1.29.0", [], "a `match` statement with a single nontrivial arm (i.e., where the other arm is `_ => {}`) instead of `if let`"),
+    ("single_match_else", pedantic, "pre 1.29.0", [], "a `match` statement with two arms where the second arm's pattern is a placeholder instead of a specific match pattern"),
+    ("size_of_in_element_count", correctness, "1.50.0", [], "using `size_of::<T>` or `size_of_val::<T>` where a count of elements of `T` is expected"),
+    ("skip_while_next", complexity, "1.42.0", [], "using `skip_while(p).next()`, which is more succinctly expressed as `.find(!p)`"),
+    ("slow_vector_initialization", perf, "1.32.0", [], "slow vector initialization"),
+    ("stable_sort_primitive", pedantic, "1.47.0", [], "use of sort() when sort_unstable() is equivalent"),
+    ("std_instead_of_alloc", restriction, "1.64.0", [], "type is imported from std when available in alloc"),
+    ("std_instead_of_core", restriction, "1.64.0", [], "type is imported from std when available in core"),
+    ("str_to_string", restriction, "pre 1.29.0", [], "using `to_string()` on a `&str`, which should be `to_owned()`"),
+    ("string_add", restriction, "pre 1.29.0", [], "using `x + ..` where x is a `String` instead of `push_str()`"),
+    ("string_add_assign", pedantic, "pre 1.29.0", [], "using `x = x + ..` where x is a `String` instead of `push_str()`"),
+    ("string_extend_chars", style, "pre 1.29.0", [], "using `x.extend(s.chars())` where s is a `&str` or `String`"),
+    ("string_from_utf8_as_bytes", complexity, "1.50.0", [], "casting string slices to byte slices and back"),
+    ("string_lit_as_bytes", nursery, "pre 1.29.0", [], "calling `as_bytes` on a string literal instead of using a byte string literal"),
+    ("string_slice", restriction, "1.58.0", [], "slicing a string"),
+    ("string_to_string", restriction, "pre 1.29.0", [], "using `to_string()` on a `String`, which should be `clone()`"),
+    ("strlen_on_c_strings", complexity, "1.55.0", [], "using `libc::strlen` on a `CString` or `CStr` value, while `as_bytes().len()` or `to_bytes().len()` respectively can be used instead"),
+    ("struct_excessive_bools", pedantic, "1.43.0", ["max-struct-bools"], "using too many bools in a struct"),
+    ("suboptimal_flops", nursery, "1.43.0", [], "usage of sub-optimal floating point operations"),
+    ("suspicious_arithmetic_impl", suspicious, "pre 1.29.0", [], "suspicious use of operators in impl of arithmetic trait"),
+    ("suspicious_assignment_formatting", suspicious, "pre 1.29.0", [], "suspicious formatting of `*=`, `-=` or `!=`"),
+    ("suspicious_else_formatting", suspicious, "pre 1.29.0", [], "suspicious formatting of `else`"),
+    ("suspicious_map", suspicious, "1.39.0", [], "suspicious usage of map"),
+    ("suspicious_op_assign_impl", suspicious, "pre 1.29.0", [], "suspicious use of operators in impl of OpAssign trait"),
+    ("suspicious_operation_groupings", nursery, "1.50.0", [], "groupings of binary operations that look suspiciously like typos"),
+    ("suspicious_splitn", correctness, "1.54.0", [], "checks for `.splitn(0, ..)` and `.splitn(1, ..)`"),
+    ("suspicious_to_owned", suspicious, "1.65.0", [], "calls to `to_owned` on a `Cow<'_, _>` might not do what they are expected"),
+    ("suspicious_unary_op_formatting", suspicious, "1.40.0", [], "suspicious formatting of unary `-` or `!` on the RHS of a BinOp"),
+    ("swap_ptr_to_ref", suspicious, "1.63.0", [], "call to `mem::swap` using pointer derived references"),
+    ("tabs_in_doc_comments", style, "1.41.0", [], "using tabs in doc comments is not recommended"),
+    ("temporary_assignment", complexity, "pre 1.29.0", [], "assignments to temporaries"),
+    ("to_digit_is_some", style, "1.41.0", [], "`char.is_digit()` is clearer"),
+    ("to_string_in_format_args", perf, "1.58.0", [], "`to_string` applied to a type that implements `Display` in format args"),
+    ("todo", restriction, "1.40.0", [], "`todo!` should not be present in production code"),
+    ("too_many_arguments", complexity, "pre 1.29.0", ["too-many-arguments-threshold"], "functions with too many arguments"),
+    ("too_many_lines", pedantic, "1.34.0", ["too-many-lines-threshold"], "functions with too many lines"),
+    ("toplevel_ref_arg", style, "pre 1.29.0", [], "an entire binding declared as `ref`, in a function argument or a `let` statement"),
+    ("trailing_empty_array", nursery, "1.58.0", [], "struct with a trailing zero-sized array but without `#[repr(C)]` or another `repr` attribute"),
+    ("trait_duplication_in_bounds", nursery, "1.47.0", [], "check if the same trait bounds are specified more than once during a generic declaration"),
+    ("transmute_bytes_to_str", complexity, "pre 1.29.0", [], "transmutes from a `&[u8]` to a `&str`"),
+    ("transmute_float_to_int", complexity, "1.41.0", [], "transmutes from a float to an integer"),
+    ("transmute_int_to_bool", complexity, "pre 1.29.0", [], "transmutes from an integer to a `bool`"),
+    ("transmute_int_to_char", complexity, "pre 1.29.0", [], "transmutes from an integer to a `char`"),
+    ("transmute_int_to_float", complexity, "pre 1.29.0", [], "transmutes from an integer to a float"),
+    ("transmute_num_to_bytes", complexity, "1.58.0", [], "transmutes from a number to an array of `u8`"),
+    ("transmute_ptr_to_ptr", pedantic, "pre 1.29.0", [], "transmutes from a pointer to a pointer / a reference to a reference"),
+    ("transmute_ptr_to_ref", complexity, "pre 1.29.0", [], "transmutes from a pointer to a reference type"),
+    ("transmute_undefined_repr", nursery, "1.60.0", [], "transmute to or from a type with an undefined representation"),
+    ("transmutes_expressible_as_ptr_casts", complexity, "1.47.0", [], "transmutes that could be a pointer cast"),
+    ("transmuting_null", correctness, "1.35.0", [], "transmutes from a null pointer to a reference, which is undefined behavior"),
+    ("trim_split_whitespace", style, "1.62.0", [], "using `str::trim()` or alike before `str::split_whitespace`"),
+    ("trivial_regex", nursery, "pre 1.29.0", [], "trivial regular expressions"),
+    ("trivially_copy_pass_by_ref", pedantic, "pre 1.29.0", ["avoid-breaking-exported-api", "trivial-copy-size-limit"], "functions taking small copyable arguments by reference"),
+    ("try_err", restriction, "1.38.0", [], "return errors explicitly rather than hiding them behind a `?`"),
+    ("type_complexity", complexity, "pre 1.29.0", ["type-complexity-threshold"], "usage of very complex types that might be better factored into `type` definitions"),
+    ("type_repetition_in_bounds", nursery, "1.38.0", ["max-trait-bounds"], "types are repeated unnecessary in trait bounds use `+` instead of using `T: _, T: _`"),
+    ("undocumented_unsafe_blocks", restriction, "1.58.0", [], "creating an unsafe block without explaining why it is safe"),
+    ("undropped_manually_drops", correctness, "1.49.0", [], "use of safe `std::mem::drop` function to drop a std::mem::ManuallyDrop, which will not drop the inner value"),
+    ("unicode_not_nfc", pedantic, "pre 1.29.0", [], "using a Unicode literal not in NFC normal form (see [Unicode tr15](http://www.unicode.org/reports/tr15/) for further information)"),
+    ("unimplemented", restriction, "pre 1.29.0", [], "`unimplemented!` should not be present in production code"),
+    ("uninit_assumed_init", correctness, "1.39.0", [], "`MaybeUninit::uninit().assume_init()`"),
+    ("uninit_vec", correctness, "1.58.0", [], "Vec with uninitialized data"),
+    ("unit_arg", complexity, "pre 1.29.0", [], "passing unit to a function"),
+    ("unit_cmp", correctness, "pre 1.29.0", [], "comparing unit values"),
+    ("unit_hash", correctness, "1.58.0", [], "hashing a unit value, which does nothing"),
+    ("unit_return_expecting_ord", correctness, "1.47.0", [], "fn arguments of type Fn(...) -> Ord returning the unit type ()."),
+    ("unnecessary_cast", complexity, "pre 1.29.0", [], "cast to the same type, e.g., `x as i32` where `x: i32`"),
+    ("unnecessary_filter_map", complexity, "1.31.0", [], "using `filter_map` when a more succinct alternative exists"),
+    ("unnecessary_find_map", complexity, "1.61.0", [], "using `find_map` when a more succinct alternative exists"),
+    ("unnecessary_fold", style, "pre 1.29.0", [], "using `fold` when a more succinct alternative exists"),
+    ("unnecessary_join", pedantic, "1.61.0", [], "using `.collect::<Vec<String>>().join(\"\")` on an iterator"),
+    ("unnecessary_lazy_evaluations", style, "1.48.0", [], "using unnecessary lazy evaluation, which can be replaced with simpler eager evaluation"),
+    ("unnecessary_mut_passed", style, "pre 1.29.0", [], "an argument passed as a mutable reference although the callee only demands an immutable reference"),
+    ("unnecessary_operation", complexity, "pre 1.29.0", [], "outer expressions with no effect"),
+    ("unnecessary_owned_empty_strings", style, "1.62.0", [], "detects cases of references to owned empty strings being passed as an argument to a function expecting `&str`"),
+    ("unnecessary_self_imports", restriction, "1.53.0", [], "imports ending in `::{self}`, which can be omitted"),
+    ("unnecessary_sort_by", complexity, "1.46.0", [], "Use of `Vec::sort_by` when `Vec::sort_by_key` or `Vec::sort` would be clearer"),
+    ("unnecessary_to_owned", perf, "1.59.0", [], "unnecessary calls to `to_owned`-like functions"),
+    ("unnecessary_unwrap", complexity, "pre 1.29.0", [], "checks for calls of `unwrap[_err]()` that cannot fail"),
+    ("unnecessary_wraps", pedantic, "1.50.0", ["avoid-breaking-exported-api"], "functions that only return `Ok` or `Some`"),
+    ("unneeded_field_pattern", restriction, "pre 1.29.0", [], "struct fields bound to a wildcard instead of using `..`"),
+    ("unneeded_wildcard_pattern", complexity, "1.40.0", [], "tuple patterns with a wildcard pattern (`_`) is next to a rest pattern (`..`)"),
+    ("unnested_or_patterns", pedantic, "1.46.0", ["msrv"], "unnested or-patterns, e.g., `Foo(Bar) | Foo(Baz) instead of `Foo(Bar | Baz)`"),
+    ("unreachable", restriction, "1.40.0", [], "usage of the `unreachable!` macro"),
+    ("unreadable_literal", pedantic, "pre 1.29.0", ["unreadable-literal-lint-fractions"], "long literal without underscores"),
+    ("unsafe_derive_deserialize", pedantic, "1.45.0", [], "deriving `serde::Deserialize` on a type that has methods using `unsafe`"),
+    ("unsafe_removed_from_name", style, "pre 1.29.0", [], "`unsafe` removed from API names on import"),
+    ("unseparated_literal_suffix", restriction, "pre 1.29.0", [], "literals whose suffix is not separated by an underscore"),
+    ("unsound_collection_transmute", correctness, "1.40.0", [], "transmute between collections of layout-incompatible types"),
+    ("unused_async", pedantic, "1.54.0", [], "finds async functions with no await statements"),
+    ("unused_io_amount", correctness, "pre 1.29.0", [], "unused written/read amount"),
+    ("unused_peekable", suspicious, "1.64.0", [], "creating a peekable iterator without using any of its methods"),
+    ("unused_rounding", nursery, "1.63.0", [], "Uselessly rounding a whole number floating-point literal"),
+    ("unused_self", pedantic, "1.40.0", ["avoid-breaking-exported-api"], "methods that contain a `self` argument but don't use it"),
+    ("unused_unit", style, "1.31.0", [], "needless unit expression"),
+    ("unusual_byte_groupings", style, "1.49.0", [], "binary or hex literals that aren't grouped by four"),
+    ("unwrap_in_result", restriction, "1.48.0", [], "functions of type `Result<..>` or `Option`<...> that contain `expect()` or `unwrap()`"),
+    ("unwrap_or_else_default", style, "1.56.0", [], "using `.unwrap_or_else(Default::default)`, which is more succinctly expressed as `.unwrap_or_default()`"),
+    ("unwrap_used", restriction, "1.45.0", ["allow-unwrap-in-tests"], "using `.unwrap()` on `Result` or `Option`, which should at least get a better message using `expect()`"),
+    ("upper_case_acronyms", style, "1.51.0", ["avoid-breaking-exported-api", "upper-case-acronyms-aggressive"], "capitalized acronyms are against the naming convention"),
+    ("use_debug", restriction, "pre 1.29.0", [], "use of `Debug`-based formatting"),
+    ("use_self", nursery, "pre 1.29.0", ["msrv"], "unnecessary structure name repetition whereas `Self` is applicable"),
+    ("used_underscore_binding", pedantic, "pre 1.29.0", [], "using a binding which is prefixed with an underscore"),
+    ("useless_asref", complexity, "pre 1.29.0", [], "using `as_ref` where the types before and after the call are the same"),
+    ("useless_attribute", correctness, "pre 1.29.0", [], "use of lint attributes on `extern crate` items"),
+    ("useless_conversion", complexity, "1.45.0", [], "calls to `Into`, `TryInto`, `From`, `TryFrom`, or `IntoIter` which perform useless conversions to the same type"),
+    ("useless_format", complexity, "pre 1.29.0", [], "useless use of `format!`"),
+    ("useless_let_if_seq", nursery, "pre 1.29.0", [], "unidiomatic `let mut` declaration followed by initialization in `if`"),
+    ("useless_transmute", complexity, "pre 1.29.0", [], "transmutes that have the same to and from types or could be a cast/coercion"),
+    ("useless_vec", perf, "pre 1.29.0", ["too-large-for-stack"], "useless `vec!`"),
+    ("vec_box", complexity, "1.33.0", ["avoid-breaking-exported-api", "vec-box-size-threshold"], "usage of `Vec<Box<T>>` where T: Sized, vector elements are already on the heap"),
+    ("vec_init_then_push", perf, "1.51.0", [], "`push` immediately after `Vec` creation"),
+    ("vec_resize_to_zero", correctness, "1.46.0", [], "emptying a vector with `resize(0, an_int)` instead of `clear()` is probably an argument inversion mistake"),
+    ("verbose_bit_mask", pedantic, "pre 1.29.0", ["verbose-bit-mask-threshold"], "expressions where a bit mask is less readable than the corresponding method call"),
+    ("verbose_file_reads", restriction, "1.44.0", [], "use of `File::read_to_end` or `File::read_to_string`"),
+    ("vtable_address_comparisons", correctness, "1.44.0", [], "comparison with an address of a trait vtable"),
+    ("while_immutable_condition", correctness, "pre 1.29.0", [], "variables used within while expression are not mutated in the body"),
+    ("while_let_loop", complexity, "pre 1.29.0", [], "`loop { if let { ... } else break }`, which can be written as a `while let` loop"),
+    ("while_let_on_iterator", style, "pre 1.29.0", [], "using a `while let` loop instead of a for loop on an iterator"),
+    ("wildcard_dependencies", cargo, "1.32.0", [], "wildcard dependencies being used"),
+    ("wildcard_enum_match_arm", restriction, "1.34.0", [], "a wildcard enum match arm using `_`"),
+    ("wildcard_imports", pedantic, "1.43.0", ["warn-on-all-wildcard-imports"], "lint `use _::*` statements"),
+    ("wildcard_in_or_patterns", complexity, "1.42.0", [], "a wildcard pattern used with others patterns in same match arm"),
+    ("write_literal", style, "pre 1.29.0", [], "writing a literal with a format string"),
+    ("write_with_newline", style, "pre 1.29.0", [], "using `write!()` with a format string that ends in a single newline"),
+    ("writeln_empty_string", style, "pre 1.29.0", [], "using `writeln!(buf, \"\")` with an empty string"),
+    ("wrong_self_convention", style, "pre 1.29.0", ["avoid-breaking-exported-api"], "defining a method named with an established prefix (like \"into_\") that takes `self` with the wrong convention"),
+    ("wrong_transmute", correctness, "pre 1.29.0", [], "transmutes that are confusing at best, undefined behavior at worst and always useless"),
+    ("zero_divided_by_zero", complexity, "pre 1.29.0", [], "usage of `0.0 / 0.0` to obtain NaN instead of `f32::NAN` or `f64::NAN`"),
+    ("zero_prefixed_literal", complexity, "pre 1.29.0", [], "integer literals starting with `0`"),
+    ("zero_ptr", style, "pre 1.29.0", [], "using `0 as *{const, mut} T`"),
+    ("zero_sized_map_values", pedantic, "1.50.0", [], "usage of map with zero-sized value type"),
+    ("zst_offset", correctness, "1.41.0", [], "Check for offset calculations on raw pointers to zero-sized types"),
 
 }
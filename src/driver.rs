@@ -14,8 +14,10 @@ extern crate rustc_interface;
 extern crate rustc_session;
 extern crate rustc_span;
 
-use rustc_interface::interface;
+use rustc_interface::{interface, Queries};
+use rustc_session::config::ErrorOutputType;
 use rustc_session::parse::ParseSess;
+use rustc_session::DiagnosticOutput;
 use rustc_span::symbol::Symbol;
 use rustc_tools_util::VersionInfo;
 
@@ -91,16 +93,63 @@ impl rustc_driver::Callbacks for RustcCallbacks {
 
 struct ClippyCallbacks {
     clippy_args_var: Option<String>,
+    print_effective_config: bool,
+    baseline: Option<clippy_lints::BaselineMode>,
+    /// The file listing the changed lines, see `cargo clippy --diff-base`.
+    diff_base: Option<PathBuf>,
+    /// The lints and lint groups whose suggestions `cargo fix` can apply, see
+    /// `cargo clippy --fix-only`.
+    fixable: Option<Vec<String>>,
+    /// The directory to write the time spent in each lint pass to, see `cargo clippy --timings`.
+    timings: Option<PathBuf>,
+    /// Whether the panics of the lint passes are caught, see `cargo clippy --catch-panics`.
+    catch_panics: bool,
+    /// The directory to write the number of each lint emitted to, see `cargo clippy --summary`.
+    summary: Option<PathBuf>,
 }
 
 impl rustc_driver::Callbacks for ClippyCallbacks {
-    // JUSTIFICATION: necessary in clippy driver to set `mir_opt_level`
+    // JUSTIFICATION: necessary in clippy driver to set `mir_opt_level` and `lint_opts`
     #[allow(rustc::bad_opt_access)]
     fn config(&mut self, config: &mut interface::Config) {
+        // levels from the configuration file come first, so that the command line flags take precedence
+        config.opts.lint_opts.splice(0..0, clippy_lints::conf_lint_levels());
+        // `cargo fix` reads the suggestions of the JSON diagnostics, rustc's lints and errors included
+        if self.fixable.is_some() && matches!(config.opts.error_format, ErrorOutputType::Json { .. }) {
+            config.diagnostic_output = DiagnosticOutput::Raw(clippy_lints::fix_only_output());
+        }
+
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
+        let baseline = self.baseline.clone();
+        let diff_base = self.diff_base.clone();
+        let fixable = self.fixable.clone();
+        let timings = self.timings.is_some();
+        let track_ice = env::var_os("CLIPPY_ICE_DIR").is_some();
+        let catch_panics = self.catch_panics;
+        let summary = self.summary.is_some();
+        let baseline_file = match &baseline {
+            Some(clippy_lints::BaselineMode::Suppress(path, _)) => Some(path.clone()),
+            _ => None,
+        };
         config.parse_sess_created = Some(Box::new(move |parse_sess| {
             track_clippy_args(parse_sess, &clippy_args_var);
+            // lint the crate again when a configuration value is set, changed or unset by the
+            // environment
+            for var in clippy_lints::conf_env_vars() {
+                let value = env::var(&var).ok();
+                parse_sess
+                    .env_depinfo
+                    .get_mut()
+                    .insert((Symbol::intern(&var), value.as_deref().map(Symbol::intern)));
+            }
+            // lint the crate again when the baseline changes
+            if let Some(path) = &baseline_file {
+                parse_sess
+                    .file_depinfo
+                    .get_mut()
+                    .insert(Symbol::intern(&path.to_string_lossy()));
+            }
         }));
         config.register_lints = Some(Box::new(move |sess, lint_store| {
             // technically we're ~guaranteed that this is none but might as well call anything that
@@ -113,6 +162,29 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
             clippy_lints::register_plugins(lint_store, sess, &conf);
             clippy_lints::register_pre_expansion_lints(lint_store, sess, &conf);
             clippy_lints::register_renamed(lint_store);
+            clippy_lints::check_conf_lint_levels(sess, lint_store, &conf);
+            if let Some(names) = &fixable {
+                clippy_lints::restrict_fixes(sess, lint_store, names);
+            }
+            clippy_lints::register_path_overrides(lint_store, sess, &conf);
+            if timings {
+                clippy_lints::register_timings(lint_store);
+            }
+            if track_ice {
+                clippy_lints::register_ice_tracking(lint_store);
+            }
+            if catch_panics {
+                clippy_lints::register_catch_panics(lint_store);
+            }
+            if let Some(mode) = &baseline {
+                clippy_lints::register_baseline(sess, mode.clone());
+            }
+            if let Some(path) = &diff_base {
+                clippy_lints::register_diff_base(sess, path);
+            }
+            if summary {
+                clippy_lints::register_summary();
+            }
         }));
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
@@ -121,6 +193,47 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
         // use for Clippy.
         config.opts.unstable_opts.mir_opt_level = Some(0);
     }
+
+    fn after_expansion<'tcx>(
+        &mut self,
+        _compiler: &interface::Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> rustc_driver::Compilation {
+        if self.print_effective_config {
+            if let Ok(gcx) = queries.global_ctxt() {
+                gcx.peek_mut().enter(clippy_lints::report_effective_conf);
+            }
+        }
+        rustc_driver::Compilation::Continue
+    }
+
+    fn after_analysis<'tcx>(
+        &mut self,
+        compiler: &interface::Compiler,
+        _queries: &'tcx Queries<'tcx>,
+    ) -> rustc_driver::Compilation {
+        if self.baseline.is_some() {
+            clippy_lints::finish_baseline(compiler.session());
+        }
+        if let Some(dir) = &self.timings {
+            clippy_lints::finish_timings(compiler.session(), dir);
+        }
+        if let Some(dir) = &self.summary {
+            clippy_lints::finish_summary(compiler.session(), dir);
+        }
+        rustc_driver::Compilation::Continue
+    }
+}
+
+/// Returns the directory of the fragments and the path of the file passed to the commands run by
+/// `cargo-clippy` once Cargo is done.
+fn fragment_args<'a>(args: &'a [String], file: &str) -> (&'a Path, &'a Path) {
+    if let [dir, path, ..] = args {
+        (Path::new(dir), Path::new(path))
+    } else {
+        eprintln!("error: a directory and {} are expected", file);
+        exit(1);
+    }
 }
 
 fn display_help() {
@@ -163,6 +276,11 @@ static ICE_HOOK: LazyLock<Box<PanicCallback>> = LazyLock::new(|| {
 });
 
 fn report_clippy_ice(info: &panic::PanicInfo<'_>, bug_report_url: &str) {
+    // the panics of the lint passes are reported as warnings with `--catch-panics`
+    if clippy_lints::catch_lint_pass_panic(info) {
+        return;
+    }
+
     // Invoke our ICE handler, which prints the actual panic message and optionally a backtrace
     (*ICE_HOOK)(info);
 
@@ -207,6 +325,17 @@ fn report_clippy_ice(info: &panic::PanicInfo<'_>, bug_report_url: &str) {
     let num_frames = if backtrace { None } else { Some(2) };
 
     interface::try_print_query_stack(&handler, num_frames);
+
+    if let Some(dir) = env::var_os("CLIPPY_ICE_DIR") {
+        match clippy_lints::write_ice_bundle(Path::new(&dir), &info.to_string()) {
+            Ok(bundle) => handler.note_without_error(&format!(
+                "the arguments, configuration and code that caused the panic were written to `{}`, \
+                 please check that they can be shared before attaching them to the bug report",
+                bundle.display()
+            )),
+            Err(e) => handler.warn(&format!("could not write the bundle of the panic: {}", e)),
+        }
+    }
 }
 
 fn toolchain_path(home: Option<String>, toolchain: Option<String>) -> Option<PathBuf> {
@@ -294,6 +423,53 @@ pub fn main() {
             exit(0);
         }
 
+        // these don't compile anything, see `cargo clippy --help`
+        if orig_args.iter().any(|a| a == "--print-config-schema") {
+            clippy_lints::print_conf_schema();
+            exit(0);
+        }
+
+        if let Some(pos) = orig_args.iter().position(|a| a == "--check-config") {
+            let path = orig_args.get(pos + 1).map(Path::new);
+            exit(i32::from(!clippy_lints::check_conf_file(path)));
+        }
+
+        // these are run by `cargo-clippy` once Cargo is done, with the directory the fragments were
+        // written to by each crate and the path of the baseline, of the summary or of the timings
+        if let Some(pos) = orig_args.iter().position(|a| a == "--merge-baseline") {
+            let (dir, path) = fragment_args(&orig_args[pos + 1..], "a baseline file");
+            exit(i32::from(!clippy_lints::merge_baseline(dir, path)));
+        }
+
+        if let Some(pos) = orig_args.iter().position(|a| a == "--check-baseline") {
+            let (dir, path) = fragment_args(&orig_args[pos + 1..], "a baseline file");
+            exit(i32::from(!clippy_lints::check_baseline(dir, path)));
+        }
+
+        if let Some(pos) = orig_args.iter().position(|a| a == "--merge-summary") {
+            let (dir, path) = fragment_args(&orig_args[pos + 1..], "a summary file");
+            let base = orig_args.get(pos + 3).map(Path::new);
+            let merged = clippy_lints::merge_summary(dir, path, base);
+            exit(i32::from(!merged));
+        }
+
+        if let Some(pos) = orig_args.iter().position(|a| a == "--timings-report") {
+            let (dir, path) = fragment_args(&orig_args[pos + 1..], "an output file");
+            exit(i32::from(!clippy_lints::report_timings(dir, path)));
+        }
+
+        // run by `cargo-clippy --message-format=sarif` with the output of Cargo, and the workspace root
+        if let Some(pos) = orig_args.iter().position(|a| a == "--sarif") {
+            let root = orig_args.get(pos + 1).map_or(Path::new("."), Path::new);
+            let version_info = rustc_tools_util::get_version_info!();
+            let version = format!("{}.{}.{}", version_info.major, version_info.minor, version_info.patch);
+            if let Err(e) = clippy_lints::print_sarif(root, &version) {
+                eprintln!("error: could not write the SARIF log: {}", e);
+                exit(1);
+            }
+            exit(0);
+        }
+
         // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
         // We're invoking the compiler programmatically, so we ignore this/
         let wrapper_mode = orig_args.get(1).map(Path::new).and_then(Path::file_stem) == Some("rustc".as_ref());
@@ -317,6 +493,14 @@ pub fn main() {
         };
 
         let mut no_deps = false;
+        let mut print_effective_config = false;
+        let mut baseline = None;
+        let mut diff_base = None;
+        let mut fixable: Option<Vec<String>> = None;
+        let mut timings = None;
+        let mut catch_panics = false;
+        let mut summary = None;
+        let mut conf_values = Vec::new();
         let clippy_args_var = env::var("CLIPPY_ARGS").ok();
         let clippy_args = clippy_args_var
             .as_deref()
@@ -328,6 +512,48 @@ pub fn main() {
                     no_deps = true;
                     None
                 },
+                "--print-effective-config" => {
+                    print_effective_config = true;
+                    None
+                },
+                "--catch-panics" => {
+                    catch_panics = true;
+                    None
+                },
+                _ if s.starts_with("--write-baseline=") => {
+                    let dir = &s["--write-baseline=".len()..];
+                    baseline = Some(clippy_lints::BaselineMode::Write(dir.into()));
+                    None
+                },
+                _ if s.starts_with("--baseline=") => {
+                    let path = &s["--baseline=".len()..];
+                    let dir = env::var_os("CLIPPY_BASELINE_DIR").map(PathBuf::from);
+                    baseline = Some(clippy_lints::BaselineMode::Suppress(path.into(), dir));
+                    None
+                },
+                _ if s.starts_with("--diff-base=") => {
+                    diff_base = Some(PathBuf::from(&s["--diff-base=".len()..]));
+                    None
+                },
+                _ if s.starts_with("--summary=") => {
+                    summary = Some(PathBuf::from(&s["--summary=".len()..]));
+                    None
+                },
+                _ if s.starts_with("--config=") => {
+                    conf_values.push(s["--config=".len()..].to_string());
+                    None
+                },
+                _ if s.starts_with("--timings=") => {
+                    timings = Some(PathBuf::from(&s["--timings=".len()..]));
+                    None
+                },
+                _ if s.starts_with("--fix-only=") || s.starts_with("--fix-group=") => {
+                    let names = s.split_once('=').map_or("", |(_, names)| names);
+                    fixable
+                        .get_or_insert_with(Vec::new)
+                        .extend(names.split(',').filter(|name| !name.is_empty()).map(String::from));
+                    None
+                },
                 _ => Some(s.to_string()),
             })
             .chain(vec!["--cfg".into(), r#"feature="cargo-clippy""#.into()])
@@ -345,7 +571,21 @@ pub fn main() {
         let clippy_enabled = !cap_lints_allow && (!no_deps || in_primary_package);
         if clippy_enabled {
             args.extend(clippy_args);
-            rustc_driver::RunCompiler::new(&args, &mut ClippyCallbacks { clippy_args_var }).run()
+            clippy_lints::set_conf_cli_values(conf_values);
+            rustc_driver::RunCompiler::new(
+                &args,
+                &mut ClippyCallbacks {
+                    clippy_args_var,
+                    print_effective_config,
+                    baseline,
+                    diff_base,
+                    fixable,
+                    timings,
+                    catch_panics,
+                    summary,
+                },
+            )
+            .run()
         } else {
             rustc_driver::RunCompiler::new(&args, &mut RustcCallbacks { clippy_args_var }).run()
         }
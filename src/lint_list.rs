@@ -0,0 +1,245 @@
+//! Lists, searches and explains the lints, see `cargo clippy --list-lints` and
+//! `cargo clippy --explain`.
+
+use crate::applicability::APPLICABILITY;
+use crate::docs::{LintDoc, LINTS};
+use std::fmt::Write;
+
+/// The groups of the lints, in the order of the lint list.
+const GROUPS: [&str; 9] = [
+    "correctness",
+    "suspicious",
+    "style",
+    "complexity",
+    "perf",
+    "pedantic",
+    "restriction",
+    "nursery",
+    "cargo",
+];
+
+fn default_level(group: &str) -> &'static str {
+    match group {
+        "correctness" => "deny",
+        "suspicious" | "style" | "complexity" | "perf" => "warn",
+        _ => "allow",
+    }
+}
+
+/// Normalizes a lint name passed on the command line, like `clippy::Needless-Return`.
+fn normalize(name: &str) -> String {
+    let name = name.to_ascii_lowercase().replace('-', "_");
+    name.strip_prefix("clippy::").unwrap_or(&name).to_string()
+}
+
+/// Returns how applicable the suggestions of `lint` are, as found by the metadata collector.
+fn applicability(lint: &LintDoc) -> &'static str {
+    APPLICABILITY
+        .binary_search_by(|&(name, _)| name.cmp(lint.name))
+        .map_or("Unresolved", |index| APPLICABILITY[index].1)
+}
+
+fn find(name: &str) -> Option<&'static LintDoc> {
+    LINTS
+        .binary_search_by(|lint| lint.name.cmp(name))
+        .ok()
+        .map(|index| &LINTS[index])
+}
+
+/// Prints the documentation of the lint `name`. Returns `false` if there is no such lint, after
+/// printing the lints with a similar name.
+pub fn explain(name: &str) -> bool {
+    let name = normalize(name);
+    let lint = if let Some(lint) = find(&name) {
+        lint
+    } else {
+        unknown_lint(&name);
+        return false;
+    };
+    println!("clippy::{}", lint.name);
+    println!(
+        "group: {} ({} by default), added in {}",
+        lint.group,
+        default_level(lint.group),
+        lint.version
+    );
+    println!("applicability: {}", applicability(lint));
+    if !lint.config.is_empty() {
+        let keys: Vec<_> = lint.config.iter().map(|key| format!("`{}`", key)).collect();
+        println!("configuration: {}", keys.join(", "));
+    }
+    println!();
+    println!("{}", lint.docs);
+    true
+}
+
+fn unknown_lint(name: &str) {
+    eprintln!("error: unknown lint `clippy::{}`", name);
+    let suggestions = suggestions(name);
+    if !suggestions.is_empty() {
+        let names: Vec<_> = suggestions.iter().map(|lint| format!("`clippy::{}`", lint)).collect();
+        eprintln!("help: did you mean {}?", names.join(" or "));
+    }
+}
+
+/// Returns the names of up to three lints with a name similar to `name`.
+fn suggestions(name: &str) -> Vec<&'static str> {
+    let max_distance = (name.len() / 3).max(1);
+    let mut similar: Vec<_> = LINTS
+        .iter()
+        .map(|lint| (edit_distance(name, lint.name), lint.name))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .collect();
+    similar.sort_unstable();
+    if similar.is_empty() {
+        // a part of the name, like `return` for `needless_return`
+        similar.extend(
+            LINTS
+                .iter()
+                .filter(|lint| lint.name.contains(name) || name.contains(lint.name))
+                .map(|lint| (0, lint.name)),
+        );
+    }
+    similar.into_iter().take(3).map(|(_, name)| name).collect()
+}
+
+/// Returns the Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = previous + usize::from(a_char != b_char);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Prints the lints whose name, description or documentation contains `term`, ignoring the case.
+/// Returns `false` if there are none.
+pub fn search(term: &str) -> bool {
+    let term = term.to_lowercase();
+    let found: Vec<_> = LINTS
+        .iter()
+        .filter(|lint| {
+            lint.name.contains(&term.replace('-', "_"))
+                || lint.desc.to_lowercase().contains(&term)
+                || lint.docs.to_lowercase().contains(&term)
+        })
+        .collect();
+    if found.is_empty() {
+        eprintln!("error: no lint matches `{}`", term);
+        return false;
+    }
+    for lint in found {
+        println!("{}", summary(lint));
+    }
+    true
+}
+
+fn summary(lint: &LintDoc) -> String {
+    format!(
+        "clippy::{} ({}, {} by default, added in {}, {}): {}",
+        lint.name,
+        lint.group,
+        default_level(lint.group),
+        lint.version,
+        applicability(lint),
+        lint.desc
+    )
+}
+
+/// Prints the lints, or the ones of `group`, one per line or as a JSON array with the same fields
+/// as the lint list. Returns `false` if there is no such group.
+pub fn list(group: Option<&str>, json: bool) -> bool {
+    let group = group.map(normalize);
+    if let Some(group) = &group {
+        if !GROUPS.contains(&group.as_str()) {
+            eprintln!("error: unknown lint group `clippy::{}`", group);
+            eprintln!("help: the lint groups are {}", GROUPS.join(", "));
+            return false;
+        }
+    }
+    let lints = LINTS
+        .iter()
+        .filter(|lint| group.as_ref().map_or(true, |group| lint.group == group));
+
+    if json {
+        let lints: Vec<_> = lints.map(to_json).collect();
+        println!("[\n{}\n]", lints.join(",\n"));
+    } else {
+        for lint in lints {
+            println!("{}", summary(lint));
+        }
+    }
+    true
+}
+
+fn to_json(lint: &LintDoc) -> String {
+    let config: Vec<_> = lint.config.iter().map(|key| json_string(key)).collect();
+    format!(
+        r#"  {{"id": {}, "group": {}, "level": {}, "version": {}, "applicability": {}, "desc": {}, "config": [{}], "docs": {}}}"#,
+        json_string(lint.name),
+        json_string(lint.group),
+        json_string(default_level(lint.group)),
+        json_string(lint.version),
+        json_string(applicability(lint)),
+        json_string(lint.desc),
+        config.join(", "),
+        json_string(lint.docs)
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            },
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{applicability, edit_distance, find, json_string, suggestions, APPLICABILITY, LINTS};
+
+    #[test]
+    fn similar_names() {
+        assert_eq!(edit_distance("needless_retrun", "needless_return"), 2);
+        assert_eq!(suggestions("needles_return")[0], "needless_return");
+        assert_eq!(suggestions("and_return"), ["let_and_return"]);
+    }
+
+    #[test]
+    fn applicability_of_every_lint() {
+        assert!(APPLICABILITY.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for lint in LINTS {
+            assert!(
+                APPLICABILITY.iter().any(|&(name, _)| name == lint.name),
+                "no applicability for `{}`, run `cargo collect-metadata`",
+                lint.name
+            );
+        }
+        assert_eq!(applicability(find("needless_return").unwrap()), "MachineApplicable");
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(json_string("a \"b\"\n\\"), r#""a \"b\"\n\\""#);
+    }
+}
@@ -4,10 +4,14 @@
 
 use rustc_tools_util::VersionInfo;
 use std::env;
-use std::path::PathBuf;
-use std::process::{self, Command};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
 
+mod applicability;
+mod diff_base;
 mod docs;
+mod lint_list;
 
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
@@ -17,9 +21,28 @@ Usage:
 Common options:
     --no-deps                Run Clippy only on the given crate, without linting the dependencies
     --fix                    Automatically apply lint suggestions. This flag implies `--no-deps`
+    --fix-only LINTS         Like `--fix`, but only apply the suggestions of the comma-separated LINTS
+    --fix-group GROUPS       Like `--fix`, but only apply the suggestions of the lints of the comma-separated GROUPS
     -h, --help               Print this message
     -V, --version            Print version info and exit
     --explain LINT           Print the documentation for a given lint
+    --explain --search TERM  Print the lints whose documentation contains TERM
+    --list-lints             Print all the lints, with `--group GROUP` only the lints of GROUP, and with
+                             `--format json` as JSON
+    --print-config-schema    Print the JSON Schema of the configuration file `clippy.toml`
+    --check-config [PATH]    Check the configuration file, or the one at PATH, without building the package
+    --print-effective-config Print the configuration and the lint levels used for each linted crate
+    --config KEY=VALUE       Set the configuration value KEY to the TOML VALUE, overriding `clippy.toml` and the
+                             `CLIPPY_CONF_<KEY>` environment variable. Keys with a `.` are passed to Cargo
+    --write-baseline FILE    Write the lints emitted for the package to the baseline FILE
+    --baseline FILE          Don't emit the lints found in the baseline FILE, and report its entries that no longer occur
+    --diff-base REV          Only emit the lints on the lines changed since the git revision REV
+    --message-format=sarif   Print the diagnostics as a SARIF log
+    --catch-panics           Report the panics of the lints as warnings, and keep running the other lints
+    --summary FILE           Write the number of each lint emitted in each crate to FILE, as JSON, and compare it
+                             to the previous FILE, or to the summary passed with `--summary-base FILE`
+    --timings                Report the time spent in each lint pass and in each crate, and write it to
+                             `clippy-timings.json` in the target directory
 
 Other options are the same as `cargo check`.
 
@@ -58,11 +81,56 @@ pub fn main() {
     }
 
     if let Some(pos) = env::args().position(|a| a == "--explain") {
-        if let Some(mut lint) = env::args().nth(pos + 1) {
-            lint.make_ascii_lowercase();
-            docs::explain(&lint.strip_prefix("clippy::").unwrap_or(&lint).replace('-', "_"));
-        } else {
-            show_help();
+        let found = match env::args().nth(pos + 1).as_deref() {
+            Some("--search") => {
+                if let Some(term) = env::args().nth(pos + 2) {
+                    lint_list::search(&term)
+                } else {
+                    show_help();
+                    true
+                }
+            },
+            Some(lint) => lint_list::explain(lint),
+            None => {
+                show_help();
+                true
+            },
+        };
+        if !found {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if env::args().any(|a| a == "--list-lints") {
+        let args: Vec<String> = env::args().collect();
+        let group = arg_value(&args, "--group");
+        let json = match arg_value(&args, "--format") {
+            None | Some("text") => false,
+            Some("json") => true,
+            Some(format) => {
+                eprintln!("error: unknown format `{}`, expected `text` or `json`", format);
+                process::exit(1);
+            },
+        };
+        if !lint_list::list(group, json) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    if env::args().any(|a| a == "--print-config-schema") {
+        if let Err(code) = run_driver(&["--print-config-schema".to_string()]) {
+            process::exit(code);
+        }
+        return;
+    }
+
+    if let Some(pos) = env::args().position(|a| a == "--check-config") {
+        let mut args = vec!["--check-config".to_string()];
+        args.extend(env::args().nth(pos + 1).filter(|a| !a.starts_with('-')));
+        if let Err(code) = run_driver(&args) {
+            process::exit(code);
         }
         return;
     }
@@ -72,13 +140,121 @@ pub fn main() {
     }
 }
 
+/// Returns the value of the option `name` in `args`, passed as `--name value` or `--name=value`.
+fn arg_value<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == name {
+            return args.next().map(String::as_str);
+        }
+        if let Some(value) = arg.strip_prefix(name).and_then(|arg| arg.strip_prefix('=')) {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Runs `clippy-driver` directly, for the options that don't build the package.
+fn run_driver(args: &[String]) -> Result<(), i32> {
+    let exit_status = Command::new(ClippyCmd::path())
+        .args(args)
+        .status()
+        .expect("could not run clippy-driver");
+
+    if exit_status.success() {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+/// The baseline passed with `--write-baseline` or `--baseline`.
+#[derive(Clone, Debug, PartialEq)]
+struct Baseline {
+    write: bool,
+    path: PathBuf,
+}
+
+impl Baseline {
+    /// Writes the baseline from the lints recorded in `dir`, or reports the entries of the baseline
+    /// that no crate suppressed.
+    fn finish(&self, dir: &Path) -> Result<(), i32> {
+        let command = if self.write {
+            "--merge-baseline"
+        } else {
+            "--check-baseline"
+        };
+        run_driver(&[
+            command.to_string(),
+            dir.display().to_string(),
+            self.path.display().to_string(),
+        ])
+    }
+}
+
+/// Returns the directory the drivers write what they record for `cargo-clippy` to, like the lints
+/// they find for the baseline. It is unique to this run, so that Cargo lints all the crates again
+/// when it is passed to the drivers.
+fn fragment_dir(kind: &str) -> PathBuf {
+    env::temp_dir().join(format!("clippy-{}-{}", kind, process::id()))
+}
+
+/// Returns the JSON file the timings are written to, in the target directory.
+fn timings_file() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| workspace_root().join("target"), PathBuf::from)
+        .join("clippy-timings.json")
+}
+
+/// Returns whether the value of `--config` sets a Clippy configuration value, as `KEY=VALUE`,
+/// rather than a Cargo one, whose keys are dotted like `build.jobs=4`.
+fn is_clippy_config(value: &str) -> bool {
+    value
+        .split_once('=')
+        .map_or(false, |(key, _)| !key.is_empty() && !key.contains('.'))
+}
+
+/// The options of `cargo-clippy` that take a value, as `--option VALUE` or `--option=VALUE`.
+const OPTIONS_WITH_VALUE: [&str; 7] = [
+    "--write-baseline",
+    "--baseline",
+    "--diff-base",
+    "--fix-only",
+    "--fix-group",
+    "--summary",
+    "--summary-base",
+];
+
+/// Returns the option of [`OPTIONS_WITH_VALUE`] that `arg` is, along with its value if it is
+/// passed in the same argument.
+fn option_with_value(arg: &str) -> Option<(&'static str, Option<String>)> {
+    OPTIONS_WITH_VALUE.into_iter().find_map(|option| {
+        if arg == option {
+            Some((option, None))
+        } else {
+            let value = arg.strip_prefix(option)?.strip_prefix('=')?;
+            Some((option, Some(value.to_string())))
+        }
+    })
+}
+
 struct ClippyCmd {
     cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: Vec<String>,
+    baseline: Option<Baseline>,
+    /// The git revision passed with `--diff-base`.
+    diff_base: Option<String>,
+    /// Whether `--message-format=sarif` was passed.
+    sarif: bool,
+    /// Whether `--timings` was passed.
+    timings: bool,
+    /// The file to write the summary of the lints to, and the summary to compare it to.
+    summary: Option<(PathBuf, Option<PathBuf>)>,
 }
 
 impl ClippyCmd {
+    #[allow(clippy::too_many_lines)]
     fn new<I>(mut old_args: I) -> Self
     where
         I: Iterator<Item = String>,
@@ -86,8 +262,40 @@ impl ClippyCmd {
         let mut cargo_subcommand = "check";
         let mut args = vec![];
         let mut clippy_args: Vec<String> = vec![];
+        let mut baseline = None;
+        let mut diff_base = None;
+        let mut sarif = false;
+        let mut timings = false;
+        let mut summary = None;
+        let mut summary_base = None;
+
+        while let Some(arg) = old_args.next() {
+            if let Some((option, value)) = option_with_value(&arg) {
+                let value = if let Some(value) = value.or_else(|| old_args.next()).filter(|value| !value.is_empty()) {
+                    value
+                } else {
+                    eprintln!("error: `{}` expects a value", option);
+                    process::exit(1);
+                };
+                match option {
+                    "--diff-base" => diff_base = Some(value),
+                    "--summary" => summary = Some(env::current_dir().unwrap_or_default().join(value)),
+                    "--summary-base" => summary_base = Some(env::current_dir().unwrap_or_default().join(value)),
+                    "--fix-only" | "--fix-group" => {
+                        cargo_subcommand = "fix";
+                        clippy_args.push(format!("{}={}", option, value));
+                    },
+                    _ => {
+                        baseline = Some(Baseline {
+                            write: option == "--write-baseline",
+                            // the drivers run in the directory of each package
+                            path: env::current_dir().unwrap_or_default().join(value),
+                        });
+                    },
+                }
+                continue;
+            }
 
-        for arg in old_args.by_ref() {
             match arg.as_str() {
                 "--fix" => {
                     cargo_subcommand = "fix";
@@ -97,6 +305,53 @@ impl ClippyCmd {
                     clippy_args.push("--no-deps".into());
                     continue;
                 },
+                "--print-effective-config" => {
+                    clippy_args.push("--print-effective-config".into());
+                    continue;
+                },
+                // the values of Cargo's `--config` are passed to Cargo
+                "--config" => {
+                    if let Some(value) = old_args.next() {
+                        if is_clippy_config(&value) {
+                            clippy_args.push(format!("--config={}", value));
+                        } else {
+                            args.push(arg);
+                            args.push(value);
+                        }
+                        continue;
+                    }
+                },
+                _ if arg.strip_prefix("--config=").map_or(false, is_clippy_config) => {
+                    clippy_args.push(arg);
+                    continue;
+                },
+                "--catch-panics" => {
+                    clippy_args.push("--catch-panics".into());
+                    continue;
+                },
+                // `--timings=html` is still passed to Cargo
+                "--timings" => {
+                    timings = true;
+                    continue;
+                },
+                // Cargo prints the JSON diagnostics, which are converted to SARIF once it is done
+                "--message-format=sarif" => {
+                    sarif = true;
+                    args.push("--message-format=json".into());
+                    continue;
+                },
+                "--message-format" => {
+                    if let Some(format) = old_args.next() {
+                        if format == "sarif" {
+                            sarif = true;
+                            args.push("--message-format=json".into());
+                        } else {
+                            args.push(arg);
+                            args.push(format);
+                        }
+                        continue;
+                    }
+                },
                 "--" => break,
                 _ => {},
             }
@@ -105,6 +360,10 @@ impl ClippyCmd {
         }
 
         clippy_args.append(&mut (old_args.collect()));
+        if summary.is_none() && summary_base.is_some() {
+            eprintln!("error: `--summary-base` requires `--summary`");
+            process::exit(1);
+        }
         if cargo_subcommand == "fix" && !clippy_args.iter().any(|arg| arg == "--no-deps") {
             clippy_args.push("--no-deps".into());
         }
@@ -113,6 +372,11 @@ impl ClippyCmd {
             cargo_subcommand,
             args,
             clippy_args,
+            baseline,
+            diff_base,
+            sarif,
+            timings,
+            summary: summary.map(|summary| (summary, summary_base)),
         }
     }
 
@@ -128,8 +392,22 @@ impl ClippyCmd {
         path
     }
 
-    fn into_std_cmd(self) -> Command {
+    fn into_std_cmd(mut self, baseline_dir: Option<&Path>) -> Command {
         let mut cmd = Command::new("cargo");
+        // the driver takes a single argument, and the fragment directory of a baseline that is only
+        // read isn't tracked, so that Cargo doesn't lint the fresh crates again
+        match (&self.baseline, baseline_dir) {
+            (Some(baseline), Some(dir)) if baseline.write => {
+                self.clippy_args.push(format!("--write-baseline={}", dir.display()));
+            },
+            (Some(baseline), dir) => {
+                self.clippy_args.push(format!("--baseline={}", baseline.path.display()));
+                if let Some(dir) = dir {
+                    cmd.env("CLIPPY_BASELINE_DIR", dir);
+                }
+            },
+            (None, _) => {},
+        }
         let clippy_args: String = self
             .clippy_args
             .iter()
@@ -149,22 +427,122 @@ impl ClippyCmd {
     }
 }
 
+/// Returns the root directory of the workspace, which the paths of the diagnostics are relative to.
+fn workspace_root() -> PathBuf {
+    Command::new("cargo")
+        .args(["locate-project", "--workspace", "--message-format", "plain"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| {
+            let manifest = PathBuf::from(String::from_utf8(output.stdout).ok()?.trim_end());
+            Some(manifest.parent()?.to_path_buf())
+        })
+        .unwrap_or_else(|| env::current_dir().unwrap_or_default())
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
-    let cmd = ClippyCmd::new(old_args);
+    let mut cmd = ClippyCmd::new(old_args);
+    let diff_file = match &cmd.diff_base {
+        Some(rev) => match diff_base::write_changed_lines(rev) {
+            Ok(path) => Some(path),
+            Err(e) => {
+                eprintln!("error: could not compute the lines changed since `{}`: {}", rev, e);
+                return Err(1);
+            },
+        },
+        None => None,
+    };
+    if let Some(path) = &diff_file {
+        cmd.clippy_args.push(format!("--diff-base={}", path.display()));
+    }
+    let baseline = cmd.baseline.clone();
+    let baseline_dir = baseline.as_ref().map(|_| fragment_dir("baseline"));
+    if let Some(dir) = &baseline_dir {
+        fs::create_dir_all(dir).expect("could not create the directory of the baseline fragments");
+    }
 
-    let mut cmd = cmd.into_std_cmd();
+    let timings_dir = cmd.timings.then(|| fragment_dir("timings"));
+    if let Some(dir) = &timings_dir {
+        fs::create_dir_all(dir).expect("could not create the directory of the timings");
+        cmd.clippy_args.push(format!("--timings={}", dir.display()));
+    }
+
+    let summary = cmd.summary.clone();
+    let summary_dir = summary.as_ref().map(|_| fragment_dir("summary"));
+    if let Some(dir) = &summary_dir {
+        fs::create_dir_all(dir).expect("could not create the directory of the lint counts");
+        cmd.clippy_args.push(format!("--summary={}", dir.display()));
+    }
 
-    let exit_status = cmd
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+    let sarif = cmd.sarif;
+
+    let mut cmd = cmd.into_std_cmd(baseline_dir.as_deref());
+
+    let exit_status = if sarif {
+        cmd.stdout(Stdio::piped());
+        let mut cargo = cmd.spawn().expect("could not run cargo");
+        let converted = Command::new(ClippyCmd::path())
+            .arg("--sarif")
+            .arg(workspace_root())
+            .stdin(cargo.stdout.take().unwrap())
+            .status()
+            .expect("could not run clippy-driver");
+        let exit_status = cargo.wait().expect("failed to wait for cargo?");
+        if !converted.success() {
+            return Err(converted.code().unwrap_or(-1));
+        }
+        exit_status
+    } else {
+        cmd.spawn()
+            .expect("could not run cargo")
+            .wait()
+            .expect("failed to wait for cargo?")
+    };
+
+    let baseline_result = match (&baseline, &baseline_dir) {
+        (Some(baseline), Some(dir)) => {
+            let result = baseline.finish(dir);
+            fs::remove_dir_all(dir).ok();
+            result
+        },
+        _ => Ok(()),
+    };
+    let summary_result = match (&summary, &summary_dir) {
+        (Some((path, base)), Some(dir)) => {
+            let mut args = vec![
+                "--merge-summary".to_string(),
+                dir.display().to_string(),
+                path.display().to_string(),
+            ];
+            args.extend(base.iter().map(|base| base.display().to_string()));
+            let result = run_driver(&args);
+            fs::remove_dir_all(dir).ok();
+            result
+        },
+        _ => Ok(()),
+    };
+    let timings_result = match &timings_dir {
+        Some(dir) => {
+            let result = run_driver(&[
+                "--timings-report".to_string(),
+                dir.display().to_string(),
+                timings_file().display().to_string(),
+            ]);
+            fs::remove_dir_all(dir).ok();
+            result
+        },
+        None => Ok(()),
+    };
+    if let Some(path) = &diff_file {
+        fs::remove_file(path).ok();
+    }
 
     if exit_status.success() {
-        Ok(())
+        baseline_result.and(summary_result).and(timings_result)
     } else {
         Err(exit_status.code().unwrap_or(-1))
     }
@@ -198,6 +576,140 @@ mod tests {
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
+    #[test]
+    fn print_effective_config() {
+        let args = "cargo clippy --print-effective-config -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.args.iter().any(|arg| arg == "--print-effective-config"));
+        assert_eq!(cmd.clippy_args, ["--print-effective-config", "-D", "warnings"]);
+    }
+
+    #[test]
+    fn catch_panics() {
+        let args = "cargo clippy --catch-panics --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--all-targets"]);
+        assert_eq!(cmd.clippy_args, ["--catch-panics"]);
+    }
+
+    #[test]
+    fn baseline() {
+        let args = "cargo clippy --all-targets --write-baseline clippy-baseline.json -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        let baseline = cmd.baseline.unwrap();
+        assert!(baseline.write);
+        assert!(baseline.path.is_absolute());
+        assert!(baseline.path.ends_with("clippy-baseline.json"));
+        assert_eq!(cmd.args, ["cargo", "clippy", "--all-targets"]);
+        assert_eq!(cmd.clippy_args, ["-D", "warnings"]);
+
+        let args = "cargo clippy --baseline=clippy-baseline.json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.baseline.unwrap().write);
+        assert_eq!(cmd.args, ["cargo", "clippy"]);
+    }
+
+    #[test]
+    fn diff_base() {
+        let args = "cargo clippy --diff-base origin/master --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.diff_base.as_deref(), Some("origin/master"));
+        assert_eq!(cmd.args, ["cargo", "clippy", "--all-targets"]);
+
+        let args = "cargo clippy --diff-base=HEAD~2"
+            .split_whitespace()
+            .map(ToString::to_string);
+        assert_eq!(ClippyCmd::new(args).diff_base.as_deref(), Some("HEAD~2"));
+    }
+
+    #[test]
+    fn message_format_sarif() {
+        let args = "cargo clippy --message-format sarif -- -D warnings"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.sarif);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+
+        let args = "cargo clippy --message-format short"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(!cmd.sarif);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format", "short"]);
+    }
+
+    #[test]
+    fn fix_only() {
+        let args = "cargo clippy --fix-only clippy::needless_borrow,redundant_clone --fix-group=style --allow-dirty"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!("fix", cmd.cargo_subcommand);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--allow-dirty"]);
+        assert_eq!(
+            cmd.clippy_args,
+            [
+                "--fix-only=clippy::needless_borrow,redundant_clone",
+                "--fix-group=style",
+                "--no-deps"
+            ]
+        );
+    }
+
+    #[test]
+    fn summary() {
+        let args = "cargo clippy --summary target/summary.json --summary-base=main.json --workspace"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        let (path, base) = cmd.summary.unwrap();
+        assert!(path.is_absolute());
+        assert!(path.ends_with("target/summary.json"));
+        assert!(base.unwrap().ends_with("main.json"));
+        assert_eq!(cmd.args, ["cargo", "clippy", "--workspace"]);
+    }
+
+    #[test]
+    fn timings() {
+        let args = "cargo clippy --timings --timings=html"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.timings);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--timings=html"]);
+    }
+
+    #[test]
+    fn config() {
+        let args = "cargo clippy --config too-many-lines-threshold=50 --config=build.jobs=4 --config msrv=\"1.60\""
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--config=build.jobs=4"]);
+        assert_eq!(
+            cmd.clippy_args,
+            ["--config=too-many-lines-threshold=50", "--config=msrv=\"1.60\""]
+        );
+
+        let args = "cargo clippy --config .cargo/ci.toml"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--config", ".cargo/ci.toml"]);
+        assert!(cmd.clippy_args.is_empty());
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
@@ -0,0 +1,96 @@
+#![feature(once_cell)]
+
+use serde_json::json;
+use std::fs;
+use test_utils::{cargo_clippy, root, run, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+const NEEDLESS_RETURN: &str = "\
+warning: unneeded `return` statement
+ --> src/main.rs:2:5
+  |
+2 |     return x * 2;
+  |     ^^^^^^^^^^^^^ help: remove `return`: `x * 2`
+  |
+  = note: `#[warn(clippy::needless_return)]` on by default
+  = help: for further information visit https://rust-lang.github.io/rust-clippy/master/index.html#needless_return
+
+";
+
+#[test]
+fn baseline() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let cwd = root().join("tests/fixture");
+    let target_dir = root().join("target/baseline");
+    fs::create_dir_all(&target_dir).unwrap();
+    let baseline = target_dir.join("baseline.json");
+    let baseline_arg = baseline.to_str().unwrap();
+
+    // the lints are still emitted while writing the baseline
+    let (_, stderr) = run(
+        cargo_clippy(&cwd, "baseline").args(["--write-baseline", baseline_arg]),
+        true,
+    );
+    assert_eq!(
+        stderr,
+        format!(
+            "{}note: wrote 1 Clippy lint to the baseline `{}`\n\n",
+            NEEDLESS_RETURN, baseline_arg
+        )
+    );
+
+    let content: serde_json::Value = serde_json::from_str(&fs::read_to_string(&baseline).unwrap()).unwrap();
+    assert_eq!(
+        content,
+        json!({
+            "entries": [
+                {
+                    "lint": "clippy::needless_return",
+                    "file": "src/main.rs",
+                    "item": "double",
+                    "snippet": "return x * 2;",
+                    "count": 1
+                }
+            ]
+        })
+    );
+
+    let (_, stderr) = run(
+        cargo_clippy(&cwd, "baseline").args(["--baseline", baseline_arg, "--", "-D", "warnings"]),
+        true,
+    );
+    assert_eq!(stderr, "note: 1 Clippy lint was suppressed by the baseline\n\n");
+
+    // an entry that isn't found anymore is reported
+    let mut stale = content;
+    stale["entries"].as_array_mut().unwrap().insert(
+        0,
+        json!({
+            "lint": "clippy::needless_return",
+            "file": "src/main.rs",
+            "item": "main",
+            "snippet": "return;",
+            "count": 2
+        }),
+    );
+    fs::write(&baseline, serde_json::to_string_pretty(&stale).unwrap()).unwrap();
+    let (_, stderr) = run(cargo_clippy(&cwd, "baseline").args(["--baseline", baseline_arg]), true);
+    assert_eq!(
+        stderr,
+        format!(
+            "\
+note: 1 Clippy lint was suppressed by the baseline
+
+warning: 1 entry of the baseline `{0}` no longer occurs
+  |
+  = note: `clippy::needless_return` in `src/main.rs`, in `main`: `return;` (2 times)
+  = help: remove them, or run `cargo clippy --write-baseline {0}` to write the baseline again
+
+",
+            baseline_arg
+        )
+    );
+}
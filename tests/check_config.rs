@@ -0,0 +1,103 @@
+#![feature(once_cell)]
+
+use serde_json::json;
+use std::fs;
+use test_utils::{cargo_clippy, root, run, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+fn check_config(path: &str, success: bool) -> String {
+    let (stdout, stderr) = run(
+        cargo_clippy(root(), "check_config").args(["--check-config", path]),
+        success,
+    );
+    assert_eq!(stdout, "");
+    stderr
+}
+
+#[test]
+fn print_config_schema() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let (stdout, stderr) = run(cargo_clippy(root(), "check_config").arg("--print-config-schema"), true);
+    assert_eq!(stderr, "");
+
+    let schema: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(schema["$schema"], "http://json-schema.org/draft-07/schema#");
+    let properties = &schema["properties"];
+    assert_eq!(properties["too-many-arguments-threshold"]["default"], 7);
+    assert_eq!(
+        properties["too-many-arguments-threshold"]["description"],
+        "The maximum number of argument a function or method can have\n\nLints: too_many_arguments"
+    );
+    assert_eq!(properties["blacklisted-names"]["deprecated"], json!(true));
+    assert_eq!(properties["too-many-arguments-threshold"].get("deprecated"), None);
+}
+
+#[test]
+fn check_config_valid() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    assert_eq!(check_config("tests/check_config/valid/clippy.toml", true), "");
+}
+
+#[test]
+fn check_config_unknown_key() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    // the same errors as when linting a crate
+    let expected = fs::read_to_string("tests/ui-toml/toml_unknown_key/conf_unknown_key.stderr")
+        .unwrap()
+        .replace("$DIR", "tests/ui-toml/toml_unknown_key");
+    assert_eq!(
+        check_config("tests/ui-toml/toml_unknown_key/clippy.toml", false),
+        expected
+    );
+}
+
+#[test]
+fn check_config_deprecated_key() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    assert_eq!(
+        check_config("tests/ui-toml/conf_deprecated_key/clippy.toml", true),
+        "\
+warning: error reading Clippy's configuration file `tests/ui-toml/conf_deprecated_key/clippy.toml`: \
+deprecated field `cyclomatic-complexity-threshold`. Please use `cognitive-complexity-threshold` instead
+
+warning: error reading Clippy's configuration file `tests/ui-toml/conf_deprecated_key/clippy.toml`: \
+deprecated field `blacklisted-names`. Please use `disallowed-names` instead
+
+warning: 2 warnings emitted
+
+"
+    );
+}
+
+#[test]
+fn check_config_disabled_lints() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    assert_eq!(
+        check_config("tests/check_config/disabled_lints/clippy.toml", true),
+        "\
+warning: `too-many-arguments-threshold` is set, but none of the lints it configures are enabled
+  |
+  = note: it configures `clippy::too_many_arguments`
+  = help: enable them in the `[lints]` table, with lint attributes or on the command line
+
+warning: `max-fn-params-bools` is set, but none of the lints it configures are enabled
+  |
+  = note: it configures `clippy::fn_params_excessive_bools`
+  = help: enable them in the `[lints]` table, with lint attributes or on the command line
+
+warning: 2 warnings emitted
+
+"
+    );
+}
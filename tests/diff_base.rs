@@ -0,0 +1,91 @@
+#![feature(once_cell)]
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use test_utils::{cargo_clippy, root, run, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+const MAIN: &str = "\
+fn one() -> u32 {
+    return 1;
+}
+
+fn two() -> u32 {
+    return 2;
+}
+
+fn main() {
+    let _ = one() + two();
+}
+";
+
+fn git(cwd: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .current_dir(cwd)
+        .args(["-c", "user.name=clippy", "-c", "user.email=clippy@example.com"])
+        .args(args)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn diff_base() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    // the package is created in its own git repository
+    let cwd = root().join("target/diff_base/package");
+    if cwd.exists() {
+        fs::remove_dir_all(&cwd).unwrap();
+    }
+    fs::create_dir_all(cwd.join("src")).unwrap();
+    fs::write(
+        cwd.join("Cargo.toml"),
+        "[package]\nname = \"diff_base\"\nversion = \"0.1.0\"\nedition = \"2018\"\npublish = false\n\n[workspace]\n",
+    )
+    .unwrap();
+    fs::write(cwd.join(".gitignore"), "/target\n").unwrap();
+    fs::write(cwd.join("src/main.rs"), MAIN).unwrap();
+    git(&cwd, &["init", "--quiet"]);
+    git(&cwd, &["add", "."]);
+    git(&cwd, &["commit", "--quiet", "-m", "initial"]);
+
+    fs::write(cwd.join("src/new.rs"), "pub fn three() -> u32 {\n    return 3;\n}\n").unwrap();
+    fs::write(
+        cwd.join("src/main.rs"),
+        MAIN.replace("return 2;", "return 20;")
+            .replace("fn main", "mod new;\n\nfn main"),
+    )
+    .unwrap();
+
+    let (_, stderr) = run(
+        cargo_clippy(&cwd, "diff_base").args(["--diff-base", "HEAD", "--", "-A", "dead_code"]),
+        true,
+    );
+    // only the changed line of `src/main.rs` and the untracked file are linted
+    assert_eq!(
+        stderr,
+        "\
+warning: unneeded `return` statement
+ --> src/main.rs:6:5
+  |
+6 |     return 20;
+  |     ^^^^^^^^^^ help: remove `return`: `20`
+  |
+  = note: `#[warn(clippy::needless_return)]` on by default
+  = help: for further information visit https://rust-lang.github.io/rust-clippy/master/index.html#needless_return
+
+warning: unneeded `return` statement
+ --> src/new.rs:2:5
+  |
+2 |     return 3;
+  |     ^^^^^^^^^ help: remove `return`: `3`
+  |
+  = help: for further information visit https://rust-lang.github.io/rust-clippy/master/index.html#needless_return
+
+"
+    );
+}
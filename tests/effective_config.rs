@@ -0,0 +1,103 @@
+#![feature(once_cell)]
+
+use std::process::Command;
+use test_utils::{cargo_clippy, root, run, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+/// Runs `cargo clippy --print-effective-config` and returns the lines of the reported note, without
+/// the diagnostic gutter and indentation.
+fn effective_config(command: &mut Command) -> Vec<String> {
+    let (stdout, stderr) = run(command.arg("--print-effective-config"), true);
+    assert_eq!(stdout, "");
+    let lines: Vec<String> = stderr
+        .lines()
+        .skip_while(|line| !line.starts_with("note: effective Clippy configuration"))
+        .take_while(|line| !line.is_empty())
+        .filter(|line| line.trim() != "|")
+        .map(|line| line.trim().trim_start_matches("= note: ").to_string())
+        .collect();
+    assert!(!lines.is_empty(), "no effective configuration in:\n{}", stderr);
+    lines
+}
+
+/// Returns the line describing the configuration key or the lint `name`.
+fn entry<'a>(lines: &'a [String], name: &str) -> &'a str {
+    lines
+        .iter()
+        .find(|line| line.starts_with(&format!("{} = ", name)) || line.starts_with(&format!("{}: ", name)))
+        .unwrap_or_else(|| panic!("no entry for `{}` in:\n{}", name, lines.join("\n")))
+}
+
+#[test]
+fn print_effective_config() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let cwd = root().join("tests/effective_config");
+    let lines = effective_config(
+        cargo_clippy(&cwd, "effective_config")
+            .arg("--")
+            .args(["-A", "clippy::needless_return"]),
+    );
+
+    let conf_file = cwd.join("clippy.toml").canonicalize().unwrap();
+    assert_eq!(
+        lines[..3],
+        [
+            "note: effective Clippy configuration of crate `effective_config`".to_string(),
+            format!("configuration file: `{}`", conf_file.display()),
+            format!("looked up from the package directory `{}`", cwd.display()),
+        ]
+    );
+    assert_eq!(
+        entry(&lines, "too-many-arguments-threshold"),
+        format!("too-many-arguments-threshold = 4 (`{}`)", conf_file.display())
+    );
+    assert_eq!(
+        entry(&lines, "cognitive-complexity-threshold"),
+        "cognitive-complexity-threshold = 25 (default)"
+    );
+    assert_eq!(
+        entry(&lines, "clippy::absurd_extreme_comparisons"),
+        "clippy::absurd_extreme_comparisons: deny (default)"
+    );
+    assert_eq!(
+        entry(&lines, "clippy::too_many_arguments"),
+        format!(
+            "clippy::too_many_arguments: deny (`clippy::too_many_arguments = \"deny\"` in the `[lints]` table of `{}`)",
+            conf_file.display()
+        )
+    );
+    assert_eq!(
+        entry(&lines, "clippy::needless_return"),
+        "clippy::needless_return: allow (command line flag `--allow clippy::needless_return`)"
+    );
+    assert_eq!(
+        entry(&lines, "clippy::doc_markdown"),
+        "clippy::doc_markdown: warn (`#![warn(clippy::pedantic)]` at src/lib.rs:1:9: 1:25)"
+    );
+}
+
+#[test]
+fn overridden_values() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let lines = effective_config(
+        cargo_clippy(root().join("tests/effective_config"), "effective_config_overrides")
+            .env("CLIPPY_CONF_TOO_MANY_ARGUMENTS_THRESHOLD", "5")
+            .env("CLIPPY_CONF_COGNITIVE_COMPLEXITY_THRESHOLD", "30")
+            .args(["--config", "cognitive-complexity-threshold=40"]),
+    );
+
+    // the command line takes precedence over the environment, which takes precedence over the file
+    assert_eq!(
+        entry(&lines, "too-many-arguments-threshold"),
+        "too-many-arguments-threshold = 5 (`CLIPPY_CONF_TOO_MANY_ARGUMENTS_THRESHOLD`)"
+    );
+    assert_eq!(
+        entry(&lines, "cognitive-complexity-threshold"),
+        "cognitive-complexity-threshold = 40 (`--config cognitive-complexity-threshold=40`)"
+    );
+}
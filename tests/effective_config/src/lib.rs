@@ -0,0 +1,5 @@
+#![warn(clippy::pedantic)]
+
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
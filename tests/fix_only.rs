@@ -0,0 +1,72 @@
+#![feature(once_cell)]
+
+use std::fs;
+use test_utils::{cargo_clippy, root, run, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+const MAIN: &str = "\
+fn double(x: u32) -> u32 {
+    return x * 2;
+}
+
+fn main() {
+    let mut even = double(1) % 2 == 0;
+    if even == true {
+        println!(\"even\");
+    }
+}
+";
+
+#[test]
+fn fix_only() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    // the package is created in the target directory, as it is modified
+    let cwd = root().join("target/fix_only/package");
+    if cwd.exists() {
+        fs::remove_dir_all(&cwd).unwrap();
+    }
+    fs::create_dir_all(cwd.join("src")).unwrap();
+    fs::write(
+        cwd.join("Cargo.toml"),
+        "[package]\nname = \"fix_only\"\nversion = \"0.1.0\"\nedition = \"2018\"\npublish = false\n\n[workspace]\n",
+    )
+    .unwrap();
+    fs::write(cwd.join("src/main.rs"), MAIN).unwrap();
+
+    let (_, stderr) = run(
+        cargo_clippy(&cwd, "fix_only").args(["--fix-only", "clippy::needless_return", "--allow-no-vcs"]),
+        true,
+    );
+    assert_eq!(
+        fs::read_to_string(cwd.join("src/main.rs")).unwrap(),
+        MAIN.replace("return x * 2;", "x * 2")
+    );
+    // the lints that aren't fixed are still emitted, rustc's included
+    assert_eq!(
+        stderr,
+        "\
+warning: variable does not need to be mutable
+ --> src/main.rs:6:9
+  |
+6 |     let mut even = double(1) % 2 == 0;
+  |         ----^^^^
+  |         |
+  |         help: remove this `mut`
+  |
+  = note: `#[warn(unused_mut)]` on by default
+
+warning: equality checks against true are unnecessary
+ --> src/main.rs:7:8
+  |
+7 |     if even == true {
+  |        ^^^^^^^^^^^^ help: try simplifying it as shown: `even`
+  |
+  = note: `#[warn(clippy::bool_comparison)]` on by default
+  = help: for further information visit https://rust-lang.github.io/rust-clippy/master/index.html#bool_comparison
+
+"
+    );
+}
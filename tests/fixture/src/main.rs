@@ -0,0 +1,7 @@
+fn double(x: u32) -> u32 {
+    return x * 2;
+}
+
+fn main() {
+    let _ = double(1);
+}
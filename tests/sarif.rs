@@ -0,0 +1,77 @@
+#![feature(once_cell)]
+
+use serde_json::{json, Value};
+use test_utils::{cargo_clippy, root, run, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+#[test]
+fn message_format_sarif() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let cwd = root().join("tests/fixture");
+    let (stdout, _) = run(cargo_clippy(&cwd, "sarif").arg("--message-format=sarif"), true);
+
+    let log: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(log["$schema"], "https://json.schemastore.org/sarif-2.1.0.json");
+    assert_eq!(log["version"], "2.1.0");
+    let runs = log["runs"].as_array().unwrap();
+    assert_eq!(runs.len(), 1);
+    let run = &runs[0];
+
+    let driver = &run["tool"]["driver"];
+    assert_eq!(driver["name"], "clippy");
+    assert_eq!(driver["version"], env!("CARGO_PKG_VERSION"));
+    assert_eq!(driver["informationUri"], "https://github.com/rust-lang/rust-clippy");
+    assert_eq!(
+        run["originalUriBaseIds"],
+        json!({ "%SRCROOT%": { "uri": format!("file://{}/", cwd.canonicalize().unwrap().display()) } })
+    );
+    assert_eq!(run["columnKind"], "unicodeCodePoints");
+
+    // the rules of all the lints are included
+    let rules = driver["rules"].as_array().unwrap();
+    let rule_index = |id: &str| rules.iter().position(|rule| rule["id"] == id).unwrap();
+    let needless_return = rule_index("clippy::needless_return");
+    assert_eq!(
+        rules[needless_return],
+        json!({
+            "id": "clippy::needless_return",
+            "name": "needless_return",
+            "shortDescription": {
+                "text": "using a return statement like `return expr;` where an expression would suffice"
+            },
+            "helpUri": "https://rust-lang.github.io/rust-clippy/master/index.html#needless_return",
+            "defaultConfiguration": { "level": "warning" },
+            "properties": { "group": "style" }
+        })
+    );
+    let unwrap_used = &rules[rule_index("clippy::unwrap_used")];
+    assert_eq!(unwrap_used["defaultConfiguration"], json!({ "level": "none" }));
+    assert_eq!(unwrap_used["properties"], json!({ "group": "restriction" }));
+
+    // the machine applicable suggestion is converted to a fix
+    let location = json!({ "uri": "src/main.rs", "uriBaseId": "%SRCROOT%" });
+    let region = json!({ "startLine": 2, "startColumn": 5, "endLine": 2, "endColumn": 18 });
+    assert_eq!(
+        run["results"],
+        json!([{
+            "level": "warning",
+            "message": { "text": "unneeded `return` statement" },
+            "locations": [{ "physicalLocation": { "artifactLocation": location, "region": region } }],
+            "ruleId": "clippy::needless_return",
+            "ruleIndex": needless_return,
+            "fixes": [{
+                "description": { "text": "remove `return`" },
+                "artifactChanges": [{
+                    "artifactLocation": location,
+                    "replacements": [{
+                        "deletedRegion": region,
+                        "insertedContent": { "text": "x * 2" }
+                    }]
+                }]
+            }]
+        }])
+    );
+}
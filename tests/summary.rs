@@ -0,0 +1,73 @@
+#![feature(once_cell)]
+
+use serde_json::{json, Value};
+use std::fs;
+use test_utils::{cargo_clippy, root, run, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+#[test]
+fn summary() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let target_dir = root().join("target/summary");
+    let summary = target_dir.join("clippy-summary.json");
+    fs::create_dir_all(&target_dir).unwrap();
+    fs::write(
+        &summary,
+        r#"{"total": 2, "lints": {"clippy::needless_return": 2}, "crates": {"fixture (bin)": {"clippy::needless_return": 2}}}"#,
+    )
+    .unwrap();
+
+    let (_, stderr) = run(
+        cargo_clippy(root().join("tests/fixture"), "summary")
+            .arg("--summary")
+            .arg(&summary),
+        true,
+    );
+    assert_eq!(
+        stderr,
+        format!(
+            "\
+warning: unneeded `return` statement
+ --> src/main.rs:2:5
+  |
+2 |     return x * 2;
+  |     ^^^^^^^^^^^^^ help: remove `return`: `x * 2`
+  |
+  = note: `#[warn(clippy::needless_return)]` on by default
+  = help: for further information visit https://rust-lang.github.io/rust-clippy/master/index.html#needless_return
+
+| {:<50} | count | previous |
+| {} | ----- | -------- |
+| {:<50} |     1 |        2 |
+
+1 Clippy lint emitted in 1 crate, 2 before
+Wrote the summary to `{}`
+",
+            "lint",
+            "-".repeat(50),
+            "clippy::needless_return",
+            summary.display()
+        )
+    );
+
+    let summary: Value = serde_json::from_str(&fs::read_to_string(summary).unwrap()).unwrap();
+    assert_eq!(
+        summary,
+        json!({
+            "total": 1,
+            "lints": { "clippy::needless_return": 1 },
+            "crates": { "fixture (bin)": { "clippy::needless_return": 1 } },
+            "changes": [
+                {
+                    "crate": "fixture (bin)",
+                    "lint": "clippy::needless_return",
+                    "previous": 2,
+                    "current": 1
+                }
+            ]
+        })
+    );
+}
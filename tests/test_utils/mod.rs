@@ -1,6 +1,7 @@
 #![allow(dead_code)] // see https://github.com/rust-lang/rust/issues/46379
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::LazyLock;
 
 pub static CARGO_CLIPPY_PATH: LazyLock<PathBuf> = LazyLock::new(|| {
@@ -11,3 +12,54 @@ pub static CARGO_CLIPPY_PATH: LazyLock<PathBuf> = LazyLock::new(|| {
 });
 
 pub const IS_RUSTC_TEST_SUITE: bool = option_env!("RUSTC_TEST_SUITE").is_some();
+
+/// The root of the repository.
+pub fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// A `cargo clippy` command run in `dir`, building in the target directory `target/<target>` so
+/// that the tests don't share their builds.
+pub fn cargo_clippy(dir: impl AsRef<Path>, target: &str) -> Command {
+    let mut command = Command::new(&*CARGO_CLIPPY_PATH);
+    command
+        .current_dir(dir)
+        .env("CARGO_INCREMENTAL", "0")
+        .env("CARGO_TARGET_DIR", root().join("target").join(target))
+        .env_remove("CLIPPY_CONF_DIR")
+        .arg("clippy");
+    command
+}
+
+/// Runs `command`, checks that it succeeds or fails as `success` says, and returns its stdout and
+/// its stderr without the status lines of Cargo.
+pub fn run(command: &mut Command, success: bool) -> (String, String) {
+    let output = command.output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(
+        output.status.success(),
+        success,
+        "`{:?}` exited with {}, stderr:\n{}",
+        command,
+        output.status,
+        stderr
+    );
+    (stdout, without_cargo_status(&stderr))
+}
+
+fn without_cargo_status(stderr: &str) -> String {
+    stderr
+        .lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            let status = ["Blocking ", "Checking ", "Compiling ", "Finished ", "Fixed ", "Fresh "]
+                .iter()
+                .any(|status| line.starts_with(status));
+            // like "warning: `name` (lib) generated 2 warnings"
+            let generated = line.starts_with("warning: `") && line.contains(" generated ");
+            !status && !generated
+        })
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
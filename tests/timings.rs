@@ -0,0 +1,119 @@
+#![feature(once_cell)]
+
+use serde_json::Value;
+use std::fs;
+use test_utils::{cargo_clippy, root, run, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+/// Checks that `printed`, a number rounded to `decimals` decimals followed by `unit`, is `value`.
+fn assert_rounded(printed: &str, unit: char, decimals: i32, value: f64) {
+    let number: f64 = printed
+        .strip_suffix(unit)
+        .and_then(|number| number.parse().ok())
+        .unwrap_or_else(|| panic!("`{}` isn't a number followed by `{}`", printed, unit));
+    assert!(
+        (number - value).abs() <= 0.5 * 10f64.powi(-decimals) + 1e-9,
+        "`{}` isn't {} rounded",
+        printed,
+        value
+    );
+}
+
+/// Splits a line of the tables in its right-aligned columns of `widths` characters, separated by
+/// two spaces, followed by the last column.
+fn columns<'a>(line: &'a str, widths: &[usize]) -> Vec<&'a str> {
+    let mut columns = Vec::new();
+    let mut rest = line;
+    for &width in widths {
+        let (column, after) = rest.split_at(width);
+        let column = column.trim_start();
+        assert!(
+            !column.is_empty() && !column.contains(' '),
+            "misaligned line `{}`",
+            line
+        );
+        columns.push(column);
+        rest = after
+            .strip_prefix("  ")
+            .unwrap_or_else(|| panic!("misaligned line `{}`", line));
+    }
+    columns.push(rest);
+    columns
+}
+
+#[test]
+fn timings() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+    let report_path = root().join("target/timings/clippy-timings.json");
+    if report_path.exists() {
+        fs::remove_file(&report_path).unwrap();
+    }
+
+    let (_, stderr) = run(
+        cargo_clippy(root().join("tests/fixture"), "timings").arg("--timings"),
+        true,
+    );
+
+    let report: Value = serde_json::from_str(&fs::read_to_string(&report_path).unwrap()).unwrap();
+    let secs = report["secs"].as_f64().unwrap();
+    let passes = report["passes"].as_array().unwrap();
+    let crates = report["crates"].as_array().unwrap();
+    assert_eq!(crates.len(), 1);
+    assert_eq!(crates[0]["crate"], "fixture (bin)");
+    assert_eq!(crates[0]["secs"], report["secs"]);
+    // with a single crate, its passes are the passes of the report
+    assert_eq!(crates[0]["passes"], report["passes"]);
+    assert!(passes.iter().any(|pass| pass["pass"] == "Return"));
+    let pass_secs: Vec<f64> = passes.iter().map(|pass| pass["secs"].as_f64().unwrap()).collect();
+    assert!(pass_secs.windows(2).all(|w| w[0] >= w[1]), "the passes aren't sorted");
+    assert!(passes.len() > 20);
+
+    // the lints are still emitted, before the tables of the times of the report
+    let (lints, tables) = stderr
+        .split_once("Time spent in Clippy's lint passes: ")
+        .expect("no timings in the output");
+    assert_eq!(
+        lints,
+        "\
+warning: unneeded `return` statement
+ --> src/main.rs:2:5
+  |
+2 |     return x * 2;
+  |     ^^^^^^^^^^^^^ help: remove `return`: `x * 2`
+  |
+  = note: `#[warn(clippy::needless_return)]` on by default
+  = help: for further information visit https://rust-lang.github.io/rust-clippy/master/index.html#needless_return
+
+"
+    );
+
+    let mut rows = tables.lines();
+    assert_rounded(rows.next().unwrap(), 's', 3, secs);
+    assert_eq!(rows.next(), Some(""));
+    assert_eq!(rows.next(), Some("     time   share  pass"));
+    // the 20 slowest passes
+    for (pass, secs_of_pass) in passes.iter().zip(&pass_secs).take(20) {
+        let columns = columns(rows.next().unwrap(), &[9, 6]);
+        assert_rounded(columns[0], 's', 3, *secs_of_pass);
+        assert_rounded(columns[1], '%', 1, secs_of_pass * 100. / secs.max(f64::MIN_POSITIVE));
+        assert_eq!(columns[2], pass["pass"]);
+    }
+    assert_eq!(
+        rows.next().unwrap(),
+        format!("                   ... {} more", passes.len() - 20)
+    );
+    assert_eq!(rows.next(), Some(""));
+    assert_eq!(rows.next(), Some("     time  crate"));
+    let columns = columns(rows.next().unwrap(), &[9]);
+    assert_rounded(columns[0], 's', 3, secs);
+    assert_eq!(columns[1], "fixture (bin)");
+    assert_eq!(rows.next(), Some(""));
+    assert_eq!(
+        rows.next().unwrap(),
+        format!("Wrote the timings of all the passes to `{}`", report_path.display())
+    );
+    assert_eq!(rows.next(), None);
+}
@@ -0,0 +1,14 @@
+#![warn(clippy::disallowed_names)]
+
+// `too-many-arguments-threshold` is inherited from the parent configuration
+fn too_many(_: u8, _: u8, _: u8) {}
+
+fn main() {
+    // `toto` is disallowed by the parent configuration
+    let toto = 42;
+    // `tata` is disallowed by this configuration
+    let tata = 42;
+    // `foo` is part of the default configuration
+    let foo = 42;
+    too_many(toto, tata, foo);
+}
@@ -0,0 +1,8 @@
+// the last override sets the threshold back to 3, each function is only linted once
+fn three(_: u8, _: u8, _: u8) {}
+fn four(_: u8, _: u8, _: u8, _: u8) {}
+
+pub fn lints() {
+    three(1, 2, 3);
+    four(1, 2, 3, 4);
+}
@@ -0,0 +1,9 @@
+mod generated;
+
+// the threshold is 2 in the files of `src/legacy`
+fn three(_: u8, _: u8, _: u8) {}
+
+pub fn lints() {
+    three(1, 2, 3);
+    generated::lints();
+}
@@ -0,0 +1,11 @@
+// the threshold of `too_many_arguments` is 3 in all the files of `src`
+fn three(_: u8, _: u8, _: u8) {}
+fn four(_: u8, _: u8, _: u8, _: u8) {}
+
+fn main() {
+    three(1, 2, 3);
+    four(1, 2, 3, 4);
+    legacy::lints();
+}
+
+mod legacy;
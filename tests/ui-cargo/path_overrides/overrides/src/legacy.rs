@@ -0,0 +1,17 @@
+fn too_many(_: u8, _: u8, _: u8) {}
+
+pub fn lints() {
+    too_many(1, 2, 3);
+    // `dbg_macro` is allowed again by the last override
+    let _ = dbg!(42);
+    // the lint passes that don't read `too-many-arguments-threshold` are the ones of the crate
+    let x = Some(42);
+    let _ = x.unwrap();
+}
+
+// lint attributes take precedence over the overrides
+#[allow(clippy::unwrap_used)]
+pub fn allowed() {
+    let x = Some(42);
+    let _ = x.unwrap();
+}
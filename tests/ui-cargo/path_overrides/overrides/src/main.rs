@@ -0,0 +1,15 @@
+mod legacy;
+
+// the threshold of `too_many_arguments` is only lowered in `legacy.rs`
+fn too_many(_: u8, _: u8, _: u8) {}
+
+fn main() {
+    too_many(1, 2, 3);
+    legacy::lints();
+    legacy::allowed();
+    // `dbg_macro` warns in all the files of `src`
+    let _ = dbg!(42);
+    // `unwrap_used` only warns in `legacy.rs`
+    let x = Some(42);
+    let _ = x.unwrap();
+}
@@ -0,0 +1,25 @@
+// rustc-env:CLIPPY_ARGS=--catch-panics
+// rustc-env:RUST_BACKTRACE=0
+// normalize-stderr-test: "internal_lints.rs:\d*:\d*" -> "internal_lints.rs"
+// normalize-stderr-test: "', .*clippy_lints" -> "', clippy_lints"
+
+#![deny(clippy::internal)]
+#![allow(clippy::missing_clippy_version_attribute)]
+#![warn(clippy::needless_return)]
+
+// the panic of the lint pass is reported as a warning instead of an ICE
+fn it_looks_like_you_are_trying_to_kill_clippy() {}
+
+mod again {
+    // the lint pass that panicked is disabled, so it doesn't panic again
+    fn it_looks_like_you_are_trying_to_kill_clippy() {}
+}
+
+// the other lints still run
+fn double(x: u32) -> u32 {
+    return x * 2;
+}
+
+fn main() {
+    double(1);
+}
@@ -0,0 +1,16 @@
+// rustc-env:CLIPPY_CONF_TOO_MANY_LINES_THRESHOLD=2
+#![warn(clippy::too_many_lines)]
+
+// the threshold of `clippy.toml` is overridden by the environment
+fn too_many_lines() {
+    println!("a");
+    println!("b");
+    println!("c");
+}
+
+fn short() {
+    println!("a");
+    println!("b");
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+// rustc-env:CLIPPY_CONF_TOO_MANY_LINES_THRESHOLD="two"
+// rustc-env:CLIPPY_CONF_TOO_MANY_LINEZ=2
+
+fn main() {}
@@ -0,0 +1,54 @@
+#![warn(clippy::layer_violation)]
+#![allow(unused)]
+
+extern crate regex;
+
+mod api {
+    pub fn handle() {
+        crate::service::serve();
+    }
+}
+
+mod service {
+    pub struct Request {
+        pub id: u32,
+    }
+
+    impl Request {
+        pub fn new(id: u32) -> Self {
+            Self { id }
+        }
+
+        pub fn id(&self) -> u32 {
+            self.id
+        }
+    }
+
+    pub fn serve() {
+        let request = Request::new(1);
+        crate::storage::save(&request);
+    }
+}
+
+mod storage {
+    use crate::service::Request;
+
+    pub fn save(request: &Request) {
+        let _ = request.id();
+        let _ = regex::Regex::new("a");
+        crate::api::handle();
+        cache::put();
+    }
+
+    mod cache {
+        pub fn put() {
+            crate::service::serve();
+        }
+    }
+}
+
+fn main() {
+    // the crate root isn't in any layer
+    api::handle();
+    storage::save(&service::Request::new(2));
+}
@@ -0,0 +1,10 @@
+fn main() {
+    let x: Option<u8> = Some(1);
+    let _ = x.unwrap();
+    let _ = dbg!(x);
+    let _ = take(Vec::new());
+}
+
+fn take(v: Vec<u8>) -> usize {
+    v.len()
+}
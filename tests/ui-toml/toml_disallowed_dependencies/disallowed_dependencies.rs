@@ -0,0 +1,51 @@
+#![warn(
+    clippy::disallowed_crates,
+    clippy::disallowed_modules,
+    clippy::disallowed_trait_impls
+)]
+#![allow(unused)]
+
+extern crate regex;
+extern crate serde;
+extern crate tokio;
+
+use tokio::io::AsyncReadExt;
+
+mod storage {
+    pub mod internal {
+        pub fn flush() {}
+
+        pub fn flush_all() {
+            // the items of a disallowed module can use each other
+            flush();
+        }
+    }
+}
+
+mod secrets {
+    #[derive(serde::Serialize)]
+    pub struct ApiKey(String);
+
+    pub struct Token(String);
+
+    impl std::ops::Deref for Token {
+        type Target = str;
+
+        fn deref(&self) -> &str {
+            &self.0
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct Public(u32);
+
+fn main() {
+    let re = regex::Regex::new("a").unwrap();
+    re.is_match("a");
+
+    let _ = std::process::id();
+    let _ = std::env::args();
+
+    storage::internal::flush();
+}
@@ -0,0 +1,30 @@
+// aux-build:macros.rs
+
+#![warn(clippy::disallowed_macros)]
+#![allow(dead_code)]
+
+extern crate macros;
+
+macro_rules! wrapper {
+    () => {
+        println!("wrapped")
+    };
+}
+
+fn main() {
+    println!("one");
+    println!("two");
+    eprintln!("not disallowed");
+    wrapper!();
+
+    let _ = macros::expr!();
+    macros::stmt!();
+    let macros::pat!() = 1;
+    let _: macros::ty!() = "";
+}
+
+fn not_done() -> u32 {
+    todo!()
+}
+
+macros::item!();
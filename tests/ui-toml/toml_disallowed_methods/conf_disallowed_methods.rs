@@ -20,4 +20,20 @@ fn main() {
 
     let in_call = Box::new(f32::clamp);
     let in_method_call = ["^", "$"].into_iter().map(Regex::new);
+
+    let _ = std::process::Command::new("sh");
+    let _ = std::process::Command::new("git");
+    const SHELL: &str = "sh";
+    let _ = std::process::Command::new(SHELL);
+    let _ = std::fs::File::create("/etc/passwd");
+    let _ = std::fs::File::create("target/out");
+
+    use std::sync::atomic::{AtomicBool, Ordering};
+    let flag = AtomicBool::new(false);
+    let _ = flag.compare_exchange(false, true, Ordering::Relaxed, Ordering::Relaxed);
+    let _ = flag.compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire);
+    let _ = AtomicBool::compare_exchange(&flag, false, true, Ordering::SeqCst, Ordering::Relaxed);
+
+    let _ = std::time::Duration::from_secs(0);
+    let _ = std::time::Duration::from_secs(1);
 }
@@ -0,0 +1,26 @@
+#![warn(clippy::incompatible_msrv)]
+#![feature(custom_inner_attributes)]
+#![allow(unused)]
+#![clippy::msrv = "1.50.0"]
+
+use std::mem;
+use std::ops::ControlFlow;
+
+fn stable_items(mut v: Vec<u32>) -> usize {
+    mem::take(&mut v).len()
+}
+
+fn newer_items(a: u32, b: u32) -> u32 {
+    let _ = u32::abs_diff(a, b);
+    a.abs_diff(b)
+}
+
+fn newer_type(_: ControlFlow<()>) {}
+
+const FIRST: Option<&u8> = [1u8, 2].first();
+
+fn first_at_runtime() -> Option<u8> {
+    [1u8, 2].first().copied()
+}
+
+fn main() {}